@@ -0,0 +1,13 @@
+"""
+Runnable examples of the public extension API.
+
+Each module has a `run()` that returns a small summary dict, and runs
+standalone with `python -m examples.<name>`. `examples.tests` calls every
+`run()` in CI, so an example that drifts from the API fails the build.
+
+- verifier_loop: periodic ledger verification with inclusion proofs
+- custom_anchor_backend: plugging a new external anchor into AnchorQueue
+- queue_ingestion: feeding signals from a message queue through the guard
+- quorum_watch: blackout detection and forecasting on synthetic members
+- chaos_scenario: scripted failures walked through recovery
+"""
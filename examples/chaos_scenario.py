@@ -0,0 +1,70 @@
+"""
+Scripted chaos scenario.
+
+A fixed script of faults is injected into a running engine. After each
+one the halt is walked through its recovery playbook, with the fault
+repaired before resume, while the invariant monitor runs strict. The
+scenario passes if every fault halts, every halt recovers, and no
+invariant is ever violated.
+"""
+
+from __future__ import annotations
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.forensics import ForensicsBundle
+from sovereign_engine.extensions.hardening import InvariantMonitor
+from sovereign_engine.extensions.recovery import (
+    HaltLog,
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    sign_review,
+)
+
+STEWARDS = {"s1": b"example-s1", "s2": b"example-s2"}
+SCRIPT = [
+    ("router", "router_failure", "handler crash"),
+    ("audit", "audit_failure", "disk full"),
+    ("router", "authority_breach", "lane bypass"),
+    ("sensor-7", "sensor_glitch", "not in the constitution"),
+]
+
+
+def recover(engine, halt_log, playbook, component: str) -> RecoverySession:
+    session = RecoverySession(engine, playbook, STEWARDS, STEWARDS.get, halts=halt_log)
+    while session.next_action is not None:
+        action = session.next_action
+        if action == RecoveryAction.CAPTURE_FORENSICS:
+            bundle = ForensicsBundle.capture(engine, halt=halt_log.open_halt)
+            session.perform(action, bundle.bundle_hash)
+        elif action in (RecoveryAction.STEWARD_REVIEW, RecoveryAction.CONSTITUTIONAL_REVIEW):
+            session.perform(action, "scripted", {
+                s: sign_review(k, session.halt_id, action, "scripted") for s, k in STEWARDS.items()
+            })
+        else:
+            if action == RecoveryAction.RESUME:
+                engine.health.report_healthy(component)
+            session.perform(action)
+    return session
+
+
+def run() -> dict:
+    engine = SovereignEngine()
+    engine.boot()
+    halt_log = HaltLog(engine).attach()
+    monitor = InvariantMonitor(engine, strict=True).attach()
+    playbook = RecoveryPlaybook.from_constitution(engine.constitution)
+    codes = []
+    for component, failure_type, detail in SCRIPT:
+        engine.submit_and_process("query", "operational", "operator", {"before": failure_type})
+        engine.health.report_failure(component, failure_type, detail)
+        if not engine.is_halted:
+            raise RuntimeError(f"{failure_type} did not halt")
+        codes.append(recover(engine, halt_log, playbook, component).recovery_code)
+    violations = monitor.check()
+    monitor.detach()
+    return {"recovered": codes, "halted": engine.is_halted, "violations": len(violations)}
+
+
+if __name__ == "__main__":
+    print(run())
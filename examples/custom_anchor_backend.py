@@ -0,0 +1,46 @@
+"""
+Custom anchor backend.
+
+Any external system that can store a digest and later prove it did can
+anchor the ledger. This one is a notary that countersigns digests with
+its own key; a real deployment would call its API in `submit`.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Dict, List
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.anchoring import AnchorBackend, AnchorQueue
+from sovereign_engine.extensions.security.zero_trust_evidence import sign_hmac, verify_hmac
+
+
+class NotaryBackend(AnchorBackend):
+    name = "notary"
+
+    def __init__(self, notary_key: bytes):
+        self._key = notary_key
+        self.notarised: List[str] = []
+
+    def submit(self, digest: str) -> Dict[str, Any]:
+        self.notarised.append(digest)
+        return {"seq": len(self.notarised) - 1,
+                "countersignature": sign_hmac(self._key, {"digest": digest})}
+
+    def verify(self, digest: str, receipt: Dict[str, Any]) -> bool:
+        seq = receipt.get("seq", -1)
+        return (0 <= seq < len(self.notarised) and self.notarised[seq] == digest
+                and verify_hmac(self._key, {"digest": digest}, receipt["countersignature"]))
+
+
+def run() -> dict:
+    engine = SovereignEngine()
+    engine.boot()
+    queue = AnchorQueue(NotaryBackend(b"notary-key"), ledger=engine.ledger)
+    queue.enqueue_ledger_head(engine.ledger)
+    [receipt] = queue.flush()
+    return {"anchored": receipt.subject, "verified": queue.verify(receipt)}
+
+
+if __name__ == "__main__":
+    print(run())
@@ -0,0 +1,52 @@
+"""
+Signal ingestion from a message queue.
+
+Producers put plain dicts on a queue; a consumer turns them into signals
+and hands them to `guarded_process`, so a malformed message is contained
+and audited instead of taking the consumer down. `queue.Queue` stands in
+for the broker client.
+"""
+
+from __future__ import annotations
+
+import queue
+from typing import Any
+
+from sovereign_engine.core.phase1_signals import SignalValidationError
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.hardening import guarded_process
+
+
+def consume(engine, inbox: "queue.Queue[Any]") -> dict:
+    counts = {"processed": 0, "contained": 0, "rejected": 0}
+    while True:
+        try:
+            message = inbox.get_nowait()
+        except queue.Empty:
+            return counts
+        try:
+            signal = engine.create_signal(message["type"], message["domain"],
+                                          message["authority"], message.get("payload", {}))
+        except (KeyError, TypeError, SignalValidationError):
+            counts["rejected"] += 1
+            continue
+        result = guarded_process(engine, signal, message.get("context"))
+        counts["processed" if result.get("processed") else "contained"] += 1
+
+
+def run() -> dict:
+    engine = SovereignEngine()
+    engine.boot()
+    inbox: "queue.Queue[Any]" = queue.Queue()
+    for n in range(5):
+        inbox.put({"type": "query", "domain": "operational", "authority": "operator",
+                   "payload": {"n": n}})
+    inbox.put({"type": "query", "domain": "operational", "authority": "operator",
+               "context": "not-a-dict"})
+    inbox.put({"type": "teleport", "domain": "operational", "authority": "operator"})
+    counts = consume(engine, inbox)
+    return {**counts, "halted": engine.is_halted}
+
+
+if __name__ == "__main__":
+    print(run())
@@ -0,0 +1,52 @@
+"""
+Watching a synthetic council.
+
+Liveness challenges go out to every member each hour; a scripted outage
+takes one region offline. The blackout detector classifies each round
+and the forecaster turns the contact history into a quorum probability.
+"""
+
+from __future__ import annotations
+
+from sovereign_engine.extensions.quorum import (
+    BlackoutDetector,
+    ContactTranscript,
+    QuorumForecaster,
+    QuorumRoster,
+    sign_attempt,
+    sign_response,
+)
+
+MEMBERS = ["m1", "m2", "m3", "m4", "m5"]
+REGIONS = {"m1": "eu", "m2": "eu", "m3": "eu", "m4": "us", "m5": "us"}
+SECRETS = {m: f"example-{m}".encode() for m in MEMBERS}
+HOUR = 3600.0
+
+
+def run(hours: int = 6, outage_from: int = 3) -> dict:
+    clock = [0.0]
+    roster = QuorumRoster(MEMBERS, threshold=3, secret_resolver=SECRETS.get)
+    alerts = []
+    detector = BlackoutDetector(roster, window_s=HOUR, emit=alerts.append, clock=lambda: clock[0])
+    contacts = ContactTranscript(roster, SECRETS.get, response_window_s=HOUR / 2,
+                                 clock=lambda: clock[0])
+    kinds = []
+    for hour in range(hours):
+        clock[0] = hour * HOUR
+        for member in MEMBERS:
+            caller = "m4" if member != "m4" else "m5"
+            sig = sign_attempt(SECRETS[caller], member, "pager", caller, clock[0])
+            attempt = contacts.record_attempt(member, "pager", caller, clock[0], sig)
+            up = hour < outage_from or REGIONS[member] != "eu"
+            if up:
+                contacts.record_response(attempt.hash, "ok",
+                                         sign_response(SECRETS[member], attempt.hash, member, "ok"))
+            detector.record(member, f"c{hour}", delivered=up, responded=up)
+        kinds.append(detector.assess().kind.value)
+    clock[0] += HOUR
+    forecast = QuorumForecaster(roster, contacts, regions=REGIONS).forecast()
+    return {"kinds": kinds, "alerts": len(alerts), "forecast": round(forecast.probability, 3)}
+
+
+if __name__ == "__main__":
+    print(run())
@@ -0,0 +1,38 @@
+import unittest
+
+from examples import (
+    chaos_scenario,
+    custom_anchor_backend,
+    queue_ingestion,
+    quorum_watch,
+    verifier_loop,
+)
+
+
+class TestExamples(unittest.TestCase):
+    def test_verifier_loop(self):
+        self.assertTrue(verifier_loop.run(rounds=2)["all_passed"])
+
+    def test_custom_anchor_backend(self):
+        self.assertTrue(custom_anchor_backend.run()["verified"])
+
+    def test_queue_ingestion(self):
+        result = queue_ingestion.run()
+        self.assertEqual((result["processed"], result["contained"], result["rejected"]), (5, 1, 1))
+        self.assertFalse(result["halted"])
+
+    def test_quorum_watch(self):
+        result = quorum_watch.run()
+        self.assertEqual(result["kinds"][-1], "blackout")
+        self.assertEqual(result["alerts"], 1)
+        self.assertLess(result["forecast"], 0.5)
+
+    def test_chaos_scenario(self):
+        result = chaos_scenario.run()
+        self.assertEqual(len(result["recovered"]), len(chaos_scenario.SCRIPT))
+        self.assertFalse(result["halted"])
+        self.assertEqual(result["violations"], 0)
+
+
+if __name__ == "__main__":
+    unittest.main()
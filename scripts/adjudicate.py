@@ -0,0 +1,64 @@
+"""
+Offline review tool for forensics bundles.
+
+Run on the steward's own machine. `inspect` prints the bundle and its
+consistency checks; `sign` does the same, then writes a review signature
+file to be sent back over the governance transport.
+
+    python scripts/adjudicate.py inspect bundle.json
+    python scripts/adjudicate.py sign bundle.json --steward s1 --key-file s1.key \
+        --action constitutional_review --out s1.review.json
+
+Exits non-zero if the bundle is inconsistent; nothing is signed then.
+"""
+
+import argparse
+import sys
+from pathlib import Path
+
+# Fix path
+sys.path.insert(0, str(Path(__file__).resolve().parent.parent))
+
+from sovereign_engine.extensions.forensics import (
+    REVIEW_ACTIONS,
+    ForensicsBundle,
+    ForensicsError,
+    inspect_bundle,
+    render_summary,
+    sign_bundle_review,
+)
+
+
+def main(argv=None) -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[1])
+    sub = parser.add_subparsers(dest="command", required=True)
+    inspect = sub.add_parser("inspect", help="render and check a bundle")
+    inspect.add_argument("bundle")
+    sign = sub.add_parser("sign", help="check a bundle and sign a review of it")
+    sign.add_argument("bundle")
+    sign.add_argument("--steward", required=True)
+    sign.add_argument("--key-file", required=True, help="file holding the steward's secret")
+    sign.add_argument("--action", choices=REVIEW_ACTIONS, default="constitutional_review")
+    sign.add_argument("--out", required=True, help="where to write the signature file")
+    args = parser.parse_args(argv)
+
+    try:
+        bundle = ForensicsBundle.from_json(Path(args.bundle).read_text(encoding="utf-8"))
+    except (OSError, ValueError, TypeError, ForensicsError) as e:
+        print(f"Cannot load bundle: {e}")
+        return 2
+    print(render_summary(bundle))
+    if inspect_bundle(bundle):
+        return 1
+    if args.command == "inspect":
+        return 0
+
+    secret = Path(args.key_file).read_bytes().rstrip(b"\r\n")
+    review = sign_bundle_review(bundle, args.steward, secret, args.action)
+    Path(args.out).write_text(review.to_json() + "\n", encoding="utf-8")
+    print(f"{args.action} signed by {args.steward}; signature written to {args.out}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
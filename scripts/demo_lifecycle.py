@@ -0,0 +1,109 @@
+#!/usr/bin/env python3
+"""
+Walk one deployment through a full constitutional lifecycle.
+
+Genesis → streak → anchored head → halt → forensics → dual-key recovery
+→ resume → signed state report → museum query with proof. The same story
+is asserted step by step in
+sovereign_engine/extensions/facade/tests/test_lifecycle.py.
+"""
+
+import os
+import sys
+import tempfile
+import time
+
+# Add project root to path
+sys.path.append(os.getcwd())
+
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.facade import Sovereign
+from sovereign_engine.extensions.facade.prelude import FileAnchorBackend
+from sovereign_engine.extensions.genesis import REPO_ROOT, GenesisBuilder, key_commitment
+from sovereign_engine.extensions.museum import Museum, verify_inclusion
+from sovereign_engine.extensions.recovery import (
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    sign_review,
+)
+from sovereign_engine.extensions.security import compute_kernel_fingerprint
+from sovereign_engine.extensions.status import build_state_report, render_text
+
+MEMBERS = ["m1", "m2", "m3"]
+STEWARDS = ["s1", "s2"]
+SECRETS = {k: f"demo-{k}".encode() for k in MEMBERS + STEWARDS}
+
+
+def run_lifecycle(tmpdir: str):
+    print("1. Genesis")
+    genesis = (
+        GenesisBuilder()
+        .with_archetype("federated")
+        .with_quorum_members({m: key_commitment(SECRETS[m]) for m in MEMBERS})
+        .with_stewards({s: key_commitment(SECRETS[s]) for s in STEWARDS})
+        .with_kernel_fingerprint(compute_kernel_fingerprint(REPO_ROOT))
+        .build("s1", SECRETS["s1"])
+    )
+    sov = (Sovereign.builder()
+           .with_genesis(genesis, SECRETS.get)
+           .with_quorum(MEMBERS, SECRETS.get)
+           .with_anchor_backend(FileAnchorBackend(os.path.join(tmpdir, "anchors.log")))
+           .build())
+    vault = EvidenceVault(os.path.join(tmpdir, "vault"))
+    print(f"   genesis {genesis.genesis_hash[:16]}")
+
+    print("2. Streak of 100 routed signals")
+    for block in range(100):
+        sov.submit("command", "operational", "operator", {"block": block})
+    [receipt] = sov.anchor_head()
+    print(f"   anchored {receipt.subject} → {receipt.digest[:16]}")
+
+    print("3. Constitutional breach")
+    sov.engine.health.report_failure("router", "authority_breach", "operator lane bypassed")
+    print(f"   halted: {sov.is_halted} ({sov.engine.halt_ctrl.halt_reason})")
+
+    print("4. Forensics and recovery")
+    bundle = sov.capture_forensics()
+    vault.store_evidence("forensics", {"bundle_hash": bundle.bundle_hash})
+    sov.engine.health.report_healthy("router")   # the fault is repaired
+    session = RecoverySession(sov.engine,
+                              RecoveryPlaybook.from_constitution(sov.engine.constitution),
+                              STEWARDS, SECRETS.get)
+    while session.next_action is not None:
+        action = session.next_action
+        if action in (RecoveryAction.STEWARD_REVIEW, RecoveryAction.CONSTITUTIONAL_REVIEW):
+            session.perform(action, "reviewed", {
+                s: sign_review(SECRETS[s], session.halt_id, action, "reviewed") for s in STEWARDS
+            })
+        elif action == RecoveryAction.CAPTURE_FORENSICS:
+            session.perform(action, bundle.bundle_hash)
+        else:
+            session.perform(action)
+        print(f"   ✓ {action.value}")
+    print(f"   halted: {sov.is_halted}")
+
+    print("5. State report")
+    now = time.time()
+    report = build_state_report(sov.engine, genesis, now - 3600, now + 1, "s2", SECRETS["s2"],
+                                anchors=sov.anchors, vault=vault)
+    print(render_text(report))
+
+    print("6. Museum")
+    museum = Museum(sov.engine.ledger, sov.engine.halt_ctrl, vault)
+    proof = museum.entry_with_proof(50)
+    print(f"   entry 50 included under head: {verify_inclusion(proof, museum.head['head'])}")
+    print(f"   recovery steps on record: {len(museum.recovery_history(session.halt_id).items)}")
+    return report
+
+
+if __name__ == '__main__':
+    try:
+        with tempfile.TemporaryDirectory() as tmpdir:
+            run_lifecycle(tmpdir)
+        print("\n✅ Lifecycle completed")
+    except Exception as e:
+        print(f"❌ Lifecycle failed: {e}")
+        import traceback
+        traceback.print_exc()
+        sys.exit(1)
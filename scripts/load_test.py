@@ -0,0 +1,156 @@
+"""
+Sustained-load harness for the Sovereign Recursion Engine.
+
+validate_performance.py times single calls in a tight loop. This drives
+the engine open-loop at a fixed signal rate for a fixed duration, with a
+configurable payload size and signal mix, and reports what a capacity
+plan needs: achieved throughput, how far the schedule fell behind,
+latency percentiles against the routing ceiling, memory growth, and how
+many ledger entries and bytes each submitted signal cost.
+
+    python scripts/load_test.py --rate 200 --duration 30 --payload-bytes 2048 --out capacity.json
+    python scripts/load_test.py --rate 0 --duration 10          # as fast as possible
+
+Exits non-zero when p99 exceeds the ceiling or the engine halts.
+"""
+
+import argparse
+import json
+import os
+import statistics
+import sys
+import time
+import tracemalloc
+from typing import Dict, List
+
+# Fix path
+sys.path.insert(0, os.getcwd())
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+
+CONTRACT = "max_routing_latency_ms"
+
+# Signal mix: (type, domain, authority) in rotation.
+MIX = [
+    ("command", "operational", "operator"),
+    ("query", "operational", "operator"),
+    ("audit", "governance", "system"),
+    ("alert", "operational", "system"),
+]
+
+
+def percentile(samples: List[float], p: float) -> float:
+    return samples[min(len(samples) - 1, int(len(samples) * p))]
+
+
+def ledger_bytes(engine: SovereignEngine) -> int:
+    return sum(len(json.dumps(e.__dict__, sort_keys=True, default=str))
+               for e in engine.ledger.entries)
+
+
+def run_load(rate: float, duration_s: float, payload_bytes: int) -> Dict:
+    engine = SovereignEngine()
+    engine.boot()
+    ceiling_ms = engine.constitution.get_timing(CONTRACT)
+    blob = "x" * payload_bytes
+
+    tracemalloc.start()
+    mem_start = tracemalloc.get_traced_memory()[0]
+    entries_start, bytes_start = engine.ledger.length, ledger_bytes(engine)
+
+    latencies: List[float] = []
+    processed = 0
+    max_lag_ms = 0.0
+    interval = 1.0 / rate if rate > 0 else 0.0
+    start = time.perf_counter()
+    n = 0
+    while True:
+        due = start + n * interval
+        now = time.perf_counter()
+        if now - start >= duration_s:
+            break
+        if due > now:
+            time.sleep(due - now)
+        else:
+            max_lag_ms = max(max_lag_ms, (now - due) * 1000.0)
+        type_, domain, authority = MIX[n % len(MIX)]
+        t0 = time.perf_counter()
+        result = engine.submit_and_process(type_, domain, authority, {"seq": n, "blob": blob})
+        latencies.append((time.perf_counter() - t0) * 1000.0)
+        processed += bool(result.get("processed"))
+        n += 1
+        if engine.is_halted:
+            break
+    elapsed = time.perf_counter() - start
+
+    mem_end, mem_peak = tracemalloc.get_traced_memory()
+    tracemalloc.stop()
+    latencies.sort()
+    entries = engine.ledger.length - entries_start
+    written = ledger_bytes(engine) - bytes_start
+
+    return {
+        "config": {"rate": rate, "duration_s": duration_s, "payload_bytes": payload_bytes},
+        "submitted": n,
+        "processed": processed,
+        "throughput_per_s": n / elapsed if elapsed else 0.0,
+        "max_schedule_lag_ms": max_lag_ms,
+        "latency_ms": {
+            "p50": statistics.median(latencies) if latencies else 0.0,
+            "p99": percentile(latencies, 0.99) if latencies else 0.0,
+            "max": latencies[-1] if latencies else 0.0,
+            "ceiling": ceiling_ms,
+        },
+        "memory": {
+            "growth_bytes": mem_end - mem_start,
+            "peak_bytes": mem_peak,
+            "growth_per_signal_bytes": (mem_end - mem_start) / n if n else 0.0,
+        },
+        "ledger": {
+            "entries_per_signal": entries / n if n else 0.0,
+            "bytes_per_signal": written / n if n else 0.0,
+            "write_amplification": written / (n * payload_bytes) if n and payload_bytes else None,
+            "verified": engine.ledger.verify()["valid"],
+        },
+        "halted": engine.is_halted,
+    }
+
+
+def main(argv=None) -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[1])
+    parser.add_argument("--rate", type=float, default=100.0, help="signals per second; 0 = unpaced")
+    parser.add_argument("--duration", type=float, default=10.0, help="seconds")
+    parser.add_argument("--payload-bytes", type=int, default=512)
+    parser.add_argument("--out", help="write the capacity report JSON here")
+    args = parser.parse_args(argv)
+
+    print(f"Driving engine at {args.rate or 'max'} signals/s for {args.duration}s...")
+    report = run_load(args.rate, args.duration, args.payload_bytes)
+    lat = report["latency_ms"]
+    print(f"  submitted={report['submitted']} throughput={report['throughput_per_s']:.1f}/s "
+          f"lag_max={report['max_schedule_lag_ms']:.1f}ms")
+    print(f"  p50={lat['p50']:.3f}ms p99={lat['p99']:.3f}ms max={lat['max']:.3f}ms "
+          f"ceiling={lat['ceiling']}ms")
+    print(f"  memory growth={report['memory']['growth_bytes']}B "
+          f"ledger {report['ledger']['entries_per_signal']:.2f} entries/signal, "
+          f"{report['ledger']['bytes_per_signal']:.0f} B/signal")
+
+    if args.out:
+        with open(args.out, "w") as f:
+            json.dump({"captured_at": time.time(), **report}, f, indent=2, sort_keys=True)
+        print(f"Capacity report written to {args.out}")
+
+    problems = []
+    if lat["p99"] > lat["ceiling"]:
+        problems.append(f"p99 {lat['p99']:.3f}ms exceeds {CONTRACT}={lat['ceiling']}ms")
+    if report["halted"]:
+        problems.append("engine halted under load")
+    if not report["ledger"]["verified"]:
+        problems.append("ledger failed verification after load")
+    for p in problems:
+        print(f"CAPACITY: {p}")
+    return 1 if problems else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,27 @@
+from .acknowledgement import (
+    Advisory,
+    AdvisoryDesk,
+    AdvisoryError,
+    ack_payload,
+    sign_acknowledgement,
+)
+from .warning_ledger import (
+    SEVERITIES,
+    WarningEvent,
+    WarningLedger,
+    WarningLedgerError,
+    WarningState,
+)
+
+__all__ = [
+    "SEVERITIES",
+    "Advisory",
+    "AdvisoryDesk",
+    "AdvisoryError",
+    "WarningEvent",
+    "WarningLedger",
+    "WarningLedgerError",
+    "WarningState",
+    "ack_payload",
+    "sign_acknowledgement",
+]
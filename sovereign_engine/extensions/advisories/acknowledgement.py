@@ -0,0 +1,170 @@
+"""
+Advisory Desk - Warnings that someone has to sign for.
+S3-EXT-032: Operator acknowledgement bolt-on.
+
+Anomaly reports, blackout alerts and timing breaches are warnings, not
+halts: the engine keeps running and the warning is routed somewhere. If
+nobody is looking at that somewhere, it is as good as lost. The desk
+gives every warning an owner and a deadline. An operator acknowledges it
+with a signature; a warning still unacknowledged at its deadline is
+escalated to governance (the innovator lane), and one still open at
+twice the deadline goes to the emergency lane. Raising, acknowledging and
+escalating are all written to the audit ledger, and mirrored to a
+`WarningLedger` when one is attached.
+
+Dependency: Phase 1 (signals), Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass, field
+from typing import Callable, Dict, List, Optional
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+
+
+DEFAULT_ACK_DEADLINE_S = 4 * 3600.0
+
+# Escalation lanes by number of missed deadlines.
+ESCALATION_DOMAINS = {1: "governance", 2: "emergency"}
+
+
+class AdvisoryError(Exception):
+    pass
+
+
+def ack_payload(warning_id: str, operator_id: str) -> dict:
+    return {"warning_id": warning_id, "operator_id": operator_id, "action": "acknowledge"}
+
+
+def sign_acknowledgement(operator_id: str, secret: bytes, warning_id: str) -> str:
+    return sign_hmac(secret, ack_payload(warning_id, operator_id))
+
+
+@dataclass
+class Advisory:
+    warning_id: str
+    source: str
+    kind: str
+    detail: Dict
+    raised_at: float
+    deadline: float
+    acknowledged_by: Optional[str] = None
+    acknowledged_at: Optional[float] = None
+    escalation_level: int = 0
+    escalations: List[float] = field(default_factory=list)
+
+    @property
+    def is_open(self) -> bool:
+        return self.acknowledged_by is None
+
+
+class AdvisoryDesk:
+    """
+    INVARIANT: Only a registered operator's valid signature acknowledges.
+    INVARIANT: An open warning past its deadline is escalated, once per level.
+    """
+
+    def __init__(self, secret_resolver: Callable[[str], Optional[bytes]],
+                 ack_deadline_s: float = DEFAULT_ACK_DEADLINE_S,
+                 emit: Optional[Callable[[dict], object]] = None,
+                 ledger=None, warnings=None, clock: Callable[[], float] = time.time):
+        self._resolve = secret_resolver
+        self._deadline_s = ack_deadline_s
+        self._emit = emit
+        self._ledger = ledger
+        self._warnings = warnings
+        self._clock = clock
+        self._advisories: Dict[str, Advisory] = {}
+
+    def raise_warning(self, source: str, kind: str, detail: Optional[Dict] = None,
+                      severity: str = "warning") -> Advisory:
+        now = self._clock()
+        detail = dict(detail or {})
+        warning_id = sha256_hex(canonical_json({
+            "source": source, "kind": kind, "detail": detail, "raised_at": now,
+        }))
+        advisory = Advisory(warning_id, source, kind, detail, now, now + self._deadline_s)
+        self._advisories[warning_id] = advisory
+        self._record("warning_raised", advisory, canonical_json({"source": source, "kind": kind}))
+        if self._warnings is not None:
+            self._warnings.record(source, kind, severity, detail, warning_id=warning_id)
+        return advisory
+
+    def acknowledge(self, warning_id: str, operator_id: str, signature: str) -> Advisory:
+        advisory = self._advisories.get(warning_id)
+        if advisory is None:
+            raise AdvisoryError(f"Unknown warning: {warning_id}")
+        if not advisory.is_open:
+            raise AdvisoryError(f"Warning {warning_id} already acknowledged")
+        secret = self._resolve(operator_id)
+        if secret is None or not verify_hmac(secret, ack_payload(warning_id, operator_id), signature):
+            raise AdvisoryError(f"Invalid acknowledgement from {operator_id}")
+        advisory.acknowledged_by = operator_id
+        advisory.acknowledged_at = self._clock()
+        self._record("warning_acknowledged", advisory,
+                     canonical_json({"operator_id": operator_id, "signature": signature}))
+        if self._warnings is not None:
+            self._warnings.acknowledge(warning_id, operator_id)
+        return advisory
+
+    def sweep(self) -> List[Advisory]:
+        """Escalate open warnings whose next deadline has passed."""
+        now = self._clock()
+        escalated: List[Advisory] = []
+        for advisory in self._advisories.values():
+            if not advisory.is_open:
+                continue
+            level = min(len(ESCALATION_DOMAINS), int((now - advisory.raised_at) // self._deadline_s))
+            if level <= advisory.escalation_level:
+                continue
+            advisory.escalation_level = level
+            advisory.escalations.append(now)
+            entry = self._record("warning_escalated", advisory, str(level))
+            if self._warnings is not None:
+                link = f"audit:{entry.hash}" if entry else f"advisory_desk:level-{level}"
+                self._warnings.escalate(advisory.warning_id, level, link)
+            if self._emit:
+                self._emit({
+                    "type": "escalation",
+                    "domain": ESCALATION_DOMAINS[level],
+                    "authority": "system",
+                    "source": "advisory_desk",
+                    "payload": {
+                        "event": "warning_unacknowledged",
+                        "warning_id": advisory.warning_id,
+                        "kind": advisory.kind,
+                        "warning_source": advisory.source,
+                        "overdue_s": now - advisory.deadline,
+                        "level": level,
+                    },
+                })
+            escalated.append(advisory)
+        return escalated
+
+    def _record(self, outcome: str, advisory: Advisory, extra: str):
+        if self._ledger is None:
+            return None
+        return self._ledger.write({
+            "signal_type": "audit",
+            "route": "advisory_desk",
+            "handler": "advisory_desk",
+            "outcome": outcome,
+            "signal_id": advisory.warning_id,
+            "signal_domain": "governance",
+            "extra": extra,
+        })
+
+    def get(self, warning_id: str) -> Advisory:
+        if warning_id not in self._advisories:
+            raise AdvisoryError(f"Unknown warning: {warning_id}")
+        return self._advisories[warning_id]
+
+    @property
+    def open(self) -> List[Advisory]:
+        return [a for a in self._advisories.values() if a.is_open]
+
+    @property
+    def advisories(self) -> List[Advisory]:
+        return list(self._advisories.values())
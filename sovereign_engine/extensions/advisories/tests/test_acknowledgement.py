@@ -0,0 +1,63 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.advisories import (
+    AdvisoryDesk,
+    AdvisoryError,
+    sign_acknowledgement,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+OPERATORS = {"op1": b"secret-op1", "op2": b"secret-op2"}
+
+
+class TestAdvisoryDesk(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.results = []
+        self.desk = AdvisoryDesk(
+            OPERATORS.get, ack_deadline_s=3600, ledger=self.engine.ledger,
+            emit=lambda s: self.results.append(self.engine.submit_and_process(**s)),
+            clock=lambda: self.now,
+        )
+        self.warning = self.desk.raise_warning("observatory", "anomalous", {"metric": "cpu"})
+
+    def test_signed_acknowledgement_closes_the_warning(self):
+        sig = sign_acknowledgement("op1", OPERATORS["op1"], self.warning.warning_id)
+        self.desk.acknowledge(self.warning.warning_id, "op1", sig)
+        self.now += 10 * 3600
+        self.assertEqual(self.desk.sweep(), [])
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertEqual(outcomes[-2:], ["warning_raised", "warning_acknowledged"])
+
+    def test_forged_or_unknown_operator_cannot_acknowledge(self):
+        forged = sign_acknowledgement("op1", b"wrong", self.warning.warning_id)
+        with self.assertRaises(AdvisoryError):
+            self.desk.acknowledge(self.warning.warning_id, "op1", forged)
+        with self.assertRaises(AdvisoryError):
+            self.desk.acknowledge(self.warning.warning_id, "intruder", forged)
+        self.assertTrue(self.warning.is_open)
+
+    def test_overdue_warning_escalates_to_governance_then_emergency(self):
+        self.now += 3601
+        self.desk.sweep()
+        self.desk.sweep()
+        self.assertEqual(len(self.results), 1)
+        self.assertEqual(self.results[-1]["target"], "innovator")
+        self.now += 3600
+        self.desk.sweep()
+        self.now += 3600 * 5
+        self.desk.sweep()
+        self.assertEqual(len(self.results), 2)
+        self.assertEqual(self.results[-1]["target"], "steward")
+        self.assertEqual(self.warning.escalation_level, 2)
+
+
+if __name__ == "__main__":
+    unittest.main()
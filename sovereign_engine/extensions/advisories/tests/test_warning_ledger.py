@@ -0,0 +1,91 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.advisories import (
+    AdvisoryDesk,
+    WarningLedger,
+    WarningLedgerError,
+    sign_acknowledgement,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+OPERATORS = {"op1": b"secret-op1"}
+
+
+class TestWarningLedger(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.warnings = WarningLedger(clock=lambda: self.now)
+
+    def test_lifecycle_is_chained_and_linked(self):
+        w = self.warnings.record("health", "amber", "warning", {"component": "router"})
+        self.warnings.acknowledge(w.warning_id, "op1")
+        self.warnings.escalate(w.warning_id, 1, "signal:abc")
+        self.now += 60
+        self.warnings.resolve(w.warning_id, "router restarted", "audit:" + "d" * 64)
+
+        state = self.warnings.get(w.warning_id)
+        self.assertEqual(state.status, "resolved")
+        self.assertEqual(state.links, ["signal:abc", "audit:" + "d" * 64])
+        events = [e["event"] for e in self.warnings.evidence(w.warning_id)]
+        self.assertEqual(events, ["raised", "acknowledged", "escalated", "resolved"])
+        self.assertTrue(self.warnings.verify()["valid"])
+
+        with self.assertRaises(WarningLedgerError):
+            self.warnings.escalate(w.warning_id, 2, "signal:late")
+        with self.assertRaises(WarningLedgerError):
+            self.warnings.resolve(
+                self.warnings.record("health", "amber", "info").warning_id, "fixed", "",
+            )
+
+    def test_query_and_trend(self):
+        self.warnings.record("health", "amber", "info")
+        self.warnings.record("observatory", "anomalous", "critical")
+        self.now += 3600
+        self.warnings.record("observatory", "anomalous", "warning")
+
+        self.assertEqual(len(self.warnings.query(min_severity="warning")), 2)
+        self.assertEqual(len(self.warnings.query(source="observatory", status="open")), 2)
+        self.assertEqual(len(self.warnings.query(since=2000.0)), 1)
+        trend = self.warnings.trend(3600)
+        self.assertEqual([b for b, _ in trend], [0.0, 3600.0])
+        self.assertEqual(trend[0][1], {"info": 1, "warning": 0, "critical": 1})
+        with self.assertRaises(WarningLedgerError):
+            self.warnings.record("health", "amber", "amber")
+
+    def test_tampering_breaks_the_chain(self):
+        self.warnings.record("health", "amber", "info")
+        self.warnings.record("health", "amber", "critical")
+        event = self.warnings._events[0]
+        self.warnings._events[0] = event.__class__(
+            **{**event.__dict__, "detail": {"severity": "info", "forged": True}}
+        )
+        self.assertEqual(self.warnings.verify()["broken_at"], 0)
+
+
+class TestAdvisoryDeskMirroring(unittest.TestCase):
+    def test_desk_events_reach_the_warning_ledger(self):
+        now = [1000.0]
+        engine = SovereignEngine(CONFIG_PATH)
+        engine.boot()
+        warnings = WarningLedger(clock=lambda: now[0])
+        desk = AdvisoryDesk(OPERATORS.get, ack_deadline_s=3600, ledger=engine.ledger,
+                            warnings=warnings, clock=lambda: now[0])
+        ignored = desk.raise_warning("observatory", "anomalous", severity="critical")
+        seen = desk.raise_warning("health", "amber")
+        desk.acknowledge(seen.warning_id, "op1",
+                         sign_acknowledgement("op1", OPERATORS["op1"], seen.warning_id))
+        now[0] += 3601
+        desk.sweep()
+
+        state = warnings.get(ignored.warning_id)
+        self.assertEqual((state.severity, state.escalation_level), ("critical", 1))
+        self.assertEqual(state.links, [f"audit:{engine.ledger.last_hash}"])
+        self.assertEqual(warnings.get(seen.warning_id).status, "acknowledged")
+
+
+if __name__ == "__main__":
+    unittest.main()
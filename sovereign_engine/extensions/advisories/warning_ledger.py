@@ -0,0 +1,208 @@
+"""
+Warning Ledger - Warnings kept as history, not return values.
+
+Halts have the audit ledger; warnings had nothing. An AMBER health
+reading or an anomaly report was returned to whoever asked and then gone,
+so nobody could later show that a halt had been preceded by three days of
+ignored warnings, or that a warning was closed by the fix that followed
+it. This ledger is a separate hash chain of warning events — raised,
+acknowledged, escalated, resolved — each carrying its severity and, for
+escalation and resolution, a link to the later event responsible (an
+audit entry hash, a halt ID, a signal). Current state is folded from the
+events, so the chain is the only source of truth.
+
+Queries filter by severity, source, kind and status; `trend` buckets
+raised warnings over time; `evidence` returns a warning's events with
+their hashes for adjudication.
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Callable, Dict, List, Optional, Tuple
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+
+
+SEVERITIES = ("info", "warning", "critical")
+EVENTS = ("raised", "acknowledged", "escalated", "resolved")
+GENESIS_HASH = "0" * 64
+
+
+class WarningLedgerError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class WarningEvent:
+    index: int
+    warning_id: str
+    event: str
+    at: float
+    detail: Dict
+    link: Optional[str]
+    previous_hash: str
+    hash: str = ""
+
+    def hashed_fields(self) -> dict:
+        data = asdict(self)
+        data.pop("hash")
+        return data
+
+
+@dataclass
+class WarningState:
+    warning_id: str
+    source: str
+    kind: str
+    severity: str
+    raised_at: float
+    acknowledged_by: Optional[str] = None
+    escalation_level: int = 0
+    resolved_at: Optional[float] = None
+    resolution: Optional[str] = None
+    links: List[str] = field(default_factory=list)
+
+    @property
+    def status(self) -> str:
+        if self.resolved_at is not None:
+            return "resolved"
+        return "acknowledged" if self.acknowledged_by else "open"
+
+
+class WarningLedger:
+    """
+    INVARIANT: Append-only; every event is chained to the one before it.
+    INVARIANT: A resolved warning takes no further events.
+    INVARIANT: Escalation and resolution name the event responsible.
+    """
+
+    def __init__(self, clock: Callable[[], float] = time.time):
+        self._clock = clock
+        self._events: List[WarningEvent] = []
+        self._states: Dict[str, WarningState] = {}
+
+    # ──────────────────────────────────────────────────────────────
+    # Writes
+    # ──────────────────────────────────────────────────────────────
+
+    def record(self, source: str, kind: str, severity: str,
+               detail: Optional[Dict] = None, warning_id: Optional[str] = None) -> WarningState:
+        if severity not in SEVERITIES:
+            raise WarningLedgerError(f"Unknown severity {severity!r}; expected one of {SEVERITIES}")
+        now = self._clock()
+        detail = dict(detail or {})
+        warning_id = warning_id or sha256_hex(canonical_json({
+            "source": source, "kind": kind, "severity": severity,
+            "detail": detail, "raised_at": now,
+        }))
+        if warning_id in self._states:
+            raise WarningLedgerError(f"Warning {warning_id} already recorded")
+        self._states[warning_id] = WarningState(warning_id, source, kind, severity, now)
+        self._append(warning_id, "raised", now,
+                     {"source": source, "kind": kind, "severity": severity, "detail": detail})
+        return self._states[warning_id]
+
+    def acknowledge(self, warning_id: str, operator_id: str) -> WarningState:
+        state = self._live(warning_id)
+        if state.acknowledged_by is not None:
+            raise WarningLedgerError(f"Warning {warning_id} already acknowledged")
+        state.acknowledged_by = operator_id
+        self._append(warning_id, "acknowledged", self._clock(), {"operator_id": operator_id})
+        return state
+
+    def escalate(self, warning_id: str, level: int, link: str) -> WarningState:
+        state = self._live(warning_id)
+        if level <= state.escalation_level:
+            raise WarningLedgerError(f"Warning {warning_id} already at level {state.escalation_level}")
+        state.escalation_level = level
+        self._link(state, "escalated", {"level": level}, link)
+        return state
+
+    def resolve(self, warning_id: str, resolution: str, link: str) -> WarningState:
+        state = self._live(warning_id)
+        now = self._link(state, "resolved", {"resolution": resolution}, link)
+        state.resolved_at = now
+        state.resolution = resolution
+        return state
+
+    def _live(self, warning_id: str) -> WarningState:
+        state = self.get(warning_id)
+        if state.resolved_at is not None:
+            raise WarningLedgerError(f"Warning {warning_id} is resolved")
+        return state
+
+    def _link(self, state: WarningState, event: str, detail: Dict, link: str) -> float:
+        if not link:
+            raise WarningLedgerError(f"{event} requires a link to the responsible event")
+        now = self._clock()
+        state.links.append(link)
+        self._append(state.warning_id, event, now, detail, link)
+        return now
+
+    def _append(self, warning_id: str, event: str, at: float, detail: Dict,
+                link: Optional[str] = None):
+        previous = self._events[-1].hash if self._events else GENESIS_HASH
+        unhashed = WarningEvent(len(self._events), warning_id, event, at, detail, link, previous)
+        self._events.append(WarningEvent(
+            **{**asdict(unhashed), "hash": sha256_hex(canonical_json(unhashed.hashed_fields()))}
+        ))
+
+    # ──────────────────────────────────────────────────────────────
+    # Reads
+    # ──────────────────────────────────────────────────────────────
+
+    def get(self, warning_id: str) -> WarningState:
+        if warning_id not in self._states:
+            raise WarningLedgerError(f"Unknown warning: {warning_id}")
+        return self._states[warning_id]
+
+    def query(self, min_severity: Optional[str] = None, source: Optional[str] = None,
+              kind: Optional[str] = None, status: Optional[str] = None,
+              since: Optional[float] = None, until: Optional[float] = None) -> List[WarningState]:
+        floor = SEVERITIES.index(min_severity) if min_severity else 0
+        return [
+            s for s in self._states.values()
+            if SEVERITIES.index(s.severity) >= floor
+            and (source is None or s.source == source)
+            and (kind is None or s.kind == kind)
+            and (status is None or s.status == status)
+            and (since is None or s.raised_at >= since)
+            and (until is None or s.raised_at < until)
+        ]
+
+    def trend(self, bucket_s: float, since: Optional[float] = None,
+              until: Optional[float] = None) -> List[Tuple[float, Dict[str, int]]]:
+        """Raised warnings per time bucket, counted by severity."""
+        if bucket_s <= 0:
+            raise WarningLedgerError("bucket_s must be positive")
+        buckets: Dict[float, Dict[str, int]] = {}
+        for s in self.query(since=since, until=until):
+            start = (s.raised_at // bucket_s) * bucket_s
+            counts = buckets.setdefault(start, {sev: 0 for sev in SEVERITIES})
+            counts[s.severity] += 1
+        return sorted(buckets.items())
+
+    def evidence(self, warning_id: str) -> List[dict]:
+        """A warning's events, in order, as chained records."""
+        self.get(warning_id)
+        return [asdict(e) for e in self._events if e.warning_id == warning_id]
+
+    def verify(self) -> dict:
+        prev = GENESIS_HASH
+        for i, event in enumerate(self._events):
+            if event.previous_hash != prev:
+                return {"valid": False, "broken_at": i, "reason": "previous_hash mismatch"}
+            if event.hash != sha256_hex(canonical_json(event.hashed_fields())):
+                return {"valid": False, "broken_at": i, "reason": "event hash mismatch"}
+            prev = event.hash
+        return {"valid": True, "broken_at": None, "total_events": len(self._events)}
+
+    @property
+    def events(self) -> List[WarningEvent]:
+        return list(self._events)
+
+    @property
+    def head(self) -> str:
+        return self._events[-1].hash if self._events else GENESIS_HASH
@@ -0,0 +1,21 @@
+from .forbidden import (
+    AMENDMENT_KIND,
+    FORBIDDEN_RULE,
+    AmendmentDesk,
+    AmendmentProposal,
+    RuleChangeForbidden,
+    forbid_amendment_signals,
+    is_amendment,
+    reject,
+)
+
+__all__ = [
+    "AMENDMENT_KIND",
+    "FORBIDDEN_RULE",
+    "AmendmentDesk",
+    "AmendmentProposal",
+    "RuleChangeForbidden",
+    "forbid_amendment_signals",
+    "is_amendment",
+    "reject",
+]
@@ -0,0 +1,136 @@
+"""
+Amendment Desk - Runtime rule changes are refused, and the refusal is evidence.
+S3-EXT-030: Constitutional pressure bolt-on.
+
+The constitution is validated once at boot and is immutable from then on.
+Changing a threshold is a Season-level amendment done out of band (a new
+constitution, a new genesis record; see SEASONS.md §7), never something a
+running engine can be talked into. Until now that was true only because
+no code path existed. This module makes it explicit: an amendment
+proposal has exactly one possible outcome, a RuleChangeForbidden receipt,
+and every attempt is written to the audit ledger and raised as a
+governance alert. Repeated attempts are pressure, and pressure is worth
+knowing about.
+
+Amendments arriving as ordinary signals are stopped at the legality gate
+by the same rule, so there is no side door.
+
+Dependency: Phase 0 (constitution), Phase 3 (legality), Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import time
+from collections import Counter
+from dataclasses import asdict, dataclass
+from typing import Any, Callable, Dict, List, Tuple
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+from ..storage.versioning import profile_hash
+
+
+AMENDMENT_KIND = "constitutional_amendment"
+FORBIDDEN_RULE = "runtime_amendment_forbidden"
+FORBIDDEN_REASON = (
+    "Constitutional rules cannot change at runtime; "
+    "amendments are made out of band with a new genesis record"
+)
+
+
+@dataclass(frozen=True)
+class AmendmentProposal:
+    proposer: str
+    target: str                 # dotted path, e.g. "timing_contracts.halt_response_max_ms"
+    proposed_value: Any
+    rationale: str = ""
+
+    def to_payload(self) -> Dict[str, Any]:
+        return {"kind": AMENDMENT_KIND, **asdict(self)}
+
+    @property
+    def hash(self) -> str:
+        return sha256_hex(canonical_json(self.to_payload()))
+
+
+@dataclass(frozen=True)
+class RuleChangeForbidden:
+    proposal_hash: str
+    proposer: str
+    target: str
+    constitution_version: str
+    constitution_hash: str
+    reason: str
+    received_at: float
+
+    @property
+    def hash(self) -> str:
+        return sha256_hex(canonical_json(asdict(self)))
+
+
+def reject(proposal: AmendmentProposal, constitution,
+           received_at: float) -> RuleChangeForbidden:
+    """The only handler an amendment has. There is no accepting branch."""
+    return RuleChangeForbidden(
+        proposal_hash=proposal.hash,
+        proposer=proposal.proposer,
+        target=proposal.target,
+        constitution_version=constitution.get("meta")["version"],
+        constitution_hash=profile_hash(constitution),
+        reason=FORBIDDEN_REASON,
+        received_at=received_at,
+    )
+
+
+def is_amendment(payload: Any) -> bool:
+    return isinstance(payload, dict) and payload.get("kind") == AMENDMENT_KIND
+
+
+def forbid_amendment_signals(signal, context) -> Tuple[bool, str]:
+    """Legality rule: an amendment payload never passes the gate."""
+    if is_amendment(signal.payload):
+        return False, FORBIDDEN_REASON
+    return True, ""
+
+
+class AmendmentDesk:
+    """
+    Receives amendment proposals for a running engine.
+
+    INVARIANT: Every proposal yields a RuleChangeForbidden receipt.
+    INVARIANT: Every receipt is audited and alerted; none is silent.
+    """
+
+    def __init__(self, engine, clock: Callable[[], float] = time.time):
+        self._engine = engine
+        self._clock = clock
+        self._receipts: List[RuleChangeForbidden] = []
+        engine.gate.add_rule(FORBIDDEN_RULE, forbid_amendment_signals)
+
+    def submit(self, proposal: AmendmentProposal) -> RuleChangeForbidden:
+        receipt = reject(proposal, self._engine.constitution, self._clock())
+        self._receipts.append(receipt)
+        self._engine.ledger.write({
+            "signal_type": "audit",
+            "route": "amendment_desk",
+            "handler": "amendment_desk",
+            "outcome": "rule_change_forbidden",
+            "signal_id": receipt.proposal_hash,
+            "signal_domain": "constitutional",
+            "extra": canonical_json(asdict(receipt)),
+        })
+        self._engine.submit_and_process("alert", "governance", "system", {
+            "event": "amendment_pressure",
+            "proposal_hash": receipt.proposal_hash,
+            "proposer": proposal.proposer,
+            "target": proposal.target,
+            "attempts_by_proposer": self.pressure()[proposal.proposer],
+        }, source="amendment_desk")
+        return receipt
+
+    def pressure(self) -> Dict[str, int]:
+        """Rejected attempts per proposer."""
+        return dict(Counter(r.proposer for r in self._receipts))
+
+    @property
+    def receipts(self) -> List[RuleChangeForbidden]:
+        return list(self._receipts)
@@ -0,0 +1,48 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.amendment import (
+    FORBIDDEN_RULE,
+    AmendmentDesk,
+    AmendmentProposal,
+)
+from sovereign_engine.extensions.storage import profile_hash
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class TestAmendmentDesk(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.desk = AmendmentDesk(self.engine, clock=lambda: 1000.0)
+        self.proposal = AmendmentProposal(
+            "operator-7", "timing_contracts.halt_response_max_ms", 60000, "too strict")
+
+    def test_every_proposal_is_refused_with_a_receipt(self):
+        before = profile_hash(self.engine.constitution)
+        receipt = self.desk.submit(self.proposal)
+        self.assertEqual(receipt.proposal_hash, self.proposal.hash)
+        self.assertEqual(receipt.constitution_hash, before)
+        self.assertEqual(profile_hash(self.engine.constitution), before)
+
+    def test_refusal_is_ledgered_and_alerted_with_pressure(self):
+        self.desk.submit(self.proposal)
+        self.desk.submit(AmendmentProposal("operator-7", "archetypes", {}, "again"))
+        entries = self.engine.ledger.entries
+        self.assertEqual([e.outcome for e in entries].count("rule_change_forbidden"), 2)
+        self.assertEqual(entries[-1].route, "innovator")
+        self.assertEqual(self.desk.pressure(), {"operator-7": 2})
+
+    def test_amendment_signal_is_contained_at_the_gate(self):
+        result = self.engine.submit_and_process(
+            "command", "constitutional", "steward", self.proposal.to_payload())
+        self.assertFalse(result["processed"])
+        self.assertIn(FORBIDDEN_RULE, [v["rule"] for v in result["violations"]])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,21 @@
+from .anchor import (
+    AnchorBackend,
+    AnchorError,
+    AnchorQueue,
+    AnchorReceipt,
+    AnchorStats,
+    FileAnchorBackend,
+    HttpAnchorBackend,
+    PendingAnchor,
+)
+
+__all__ = [
+    "AnchorBackend",
+    "AnchorError",
+    "AnchorQueue",
+    "AnchorReceipt",
+    "AnchorStats",
+    "FileAnchorBackend",
+    "HttpAnchorBackend",
+    "PendingAnchor",
+]
@@ -0,0 +1,295 @@
+"""
+Anchoring - External timestamping of governance history.
+S3-EXT-019: Anchoring bolt-on.
+
+The audit ledger proves its own internal consistency, but not that its
+history existed at a given time — a steward could rebuild the whole chain.
+Anchoring posts digests (ledger heads, forensics bundle hashes) to an
+external backend and keeps the receipts, so a third party can check the
+history against something this system does not control.
+
+Backends are pluggable. Submission is queued with bounded retry and
+exponential backoff; every successful anchor is written back to the audit
+ledger as a receipt entry. A digest that is finally abandoned is ledgered
+and raised as an operational alert, since history that silently stops
+being anchored looks the same as history that was never anchored.
+
+Dependency: Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import json
+import time
+import urllib.request
+from abc import ABC, abstractmethod
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any, Callable, Dict, List, Optional
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+from ..storage.versioning import (
+    MIGRATIONS,
+    UNBOUND_PROFILE,
+    VersionStamp,
+    check_stamp,
+    current_stamp,
+)
+
+
+ARTIFACT = "anchor_file"
+
+
+class AnchorError(Exception):
+    """Raised by backends when a digest could not be anchored."""
+    pass
+
+
+class AnchorBackend(ABC):
+    """External system that can timestamp a digest and prove it later."""
+
+    name: str = "abstract"
+
+    @abstractmethod
+    def submit(self, digest: str) -> Dict[str, Any]:
+        """Anchor a digest. Returns backend-specific receipt data."""
+
+    def verify(self, digest: str, receipt: Dict[str, Any]) -> bool:
+        """Check a receipt. Backends without local verification return False."""
+        return False
+
+
+class FileAnchorBackend(AnchorBackend):
+    """
+    Append-only file of digests. For air-gapped deployments where the
+    anchor file is shipped to write-once media on a schedule.
+
+    A new file opens with a version stamp line. A file written before
+    stamping is format 0 and stays unstamped: it is append-only, and a
+    header would move every line a receipt points at.
+    """
+
+    name = "file"
+
+    def __init__(self, path: str | Path, constitution_hash: str = UNBOUND_PROFILE,
+                 accept_profile_change: bool = False):
+        self.path = Path(path)
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        self.path.touch(exist_ok=True)
+        with self.path.open("r", encoding="utf-8") as f:
+            first = f.readline()
+        if not first:
+            stamp = current_stamp(ARTIFACT, constitution_hash)
+            self.path.write_text(canonical_json({"stamp": stamp.to_dict()}) + "\n",
+                                 encoding="utf-8")
+            return
+        header = json.loads(first)
+        stamp = VersionStamp.from_dict(header["stamp"]) if "stamp" in header else None
+        problems = check_stamp(stamp, ARTIFACT, constitution_hash, accept_profile_change)
+        if problems:
+            raise AnchorError(f"{self.path}: " + "; ".join(problems))
+        MIGRATIONS.upgrade(ARTIFACT, stamp, None)
+
+    def submit(self, digest: str) -> Dict[str, Any]:
+        line = canonical_json({"digest": digest, "timestamp": time.time()})
+        try:
+            with self.path.open("r", encoding="utf-8") as f:
+                line_no = sum(1 for _ in f)
+            with self.path.open("a", encoding="utf-8") as f:
+                f.write(line + "\n")
+        except OSError as e:
+            raise AnchorError(f"{self.path}: {e}") from e
+        return {"path": str(self.path), "line": line_no, "line_hash": sha256_hex(line)}
+
+    def verify(self, digest: str, receipt: Dict[str, Any]) -> bool:
+        lines = self.path.read_text(encoding="utf-8").splitlines()
+        idx = receipt.get("line", -1)
+        if not 0 <= idx < len(lines):
+            return False
+        line = lines[idx]
+        return (sha256_hex(line) == receipt.get("line_hash")
+                and json.loads(line).get("digest") == digest)
+
+
+class HttpAnchorBackend(AnchorBackend):
+    """
+    POSTs {"digest": ...} to an anchoring gateway (blockchain RPC relay,
+    timestamping service front-end) and stores the JSON response as receipt.
+    """
+
+    name = "http"
+
+    def __init__(self, url: str, timeout_s: float = 10.0):
+        self.url = url
+        self.timeout_s = timeout_s
+
+    def submit(self, digest: str) -> Dict[str, Any]:
+        body = json.dumps({"digest": digest}).encode("utf-8")
+        req = urllib.request.Request(
+            self.url, data=body, headers={"Content-Type": "application/json"}, method="POST"
+        )
+        try:
+            with urllib.request.urlopen(req, timeout=self.timeout_s) as resp:
+                return json.loads(resp.read().decode("utf-8") or "{}")
+        except Exception as e:
+            raise AnchorError(f"{self.url}: {e}") from e
+
+
+@dataclass(frozen=True)
+class AnchorReceipt:
+    digest: str
+    subject: str
+    backend: str
+    receipt: Dict[str, Any]
+    anchored_at: float
+
+
+@dataclass
+class PendingAnchor:
+    digest: str
+    subject: str
+    attempts: int = 0
+    next_attempt_at: float = 0.0
+    last_error: Optional[str] = None
+
+
+@dataclass
+class AnchorStats:
+    queued: int = 0
+    anchored: int = 0
+    retried: int = 0
+    abandoned: int = 0
+
+
+class AnchorQueue:
+    """
+    Queue of digests awaiting anchoring on one backend.
+
+    INVARIANT: A receipt is only recorded after the backend accepted the digest.
+    INVARIANT: Abandoned digests are ledgered and alerted, never dropped silently.
+    INVARIANT: A digest leaves the queue as soon as it is anchored or abandoned.
+    """
+
+    def __init__(self, backend: AnchorBackend, ledger=None,
+                 max_attempts: int = 5, base_backoff_s: float = 30.0,
+                 clock: Callable[[], float] = time.time,
+                 emit: Optional[Callable[[dict], object]] = None):
+        self._backend = backend
+        self._ledger = ledger
+        self._emit = emit
+        self._max_attempts = max_attempts
+        self._base_backoff_s = base_backoff_s
+        self._clock = clock
+        self._pending: List[PendingAnchor] = []
+        self._abandoned: List[PendingAnchor] = []
+        self._receipts: List[AnchorReceipt] = []
+        self._stats = AnchorStats()
+
+    def enqueue(self, digest: str, subject: str) -> PendingAnchor:
+        item = PendingAnchor(digest=digest, subject=subject, next_attempt_at=self._clock())
+        self._pending.append(item)
+        self._stats.queued += 1
+        return item
+
+    def enqueue_ledger_head(self, ledger) -> PendingAnchor:
+        """Anchor the current head of an audit ledger."""
+        return self.enqueue(ledger.last_hash, f"ledger:{ledger.length}")
+
+    def flush(self) -> List[AnchorReceipt]:
+        """Attempt every due item once. Returns receipts produced by this flush."""
+        now = self._clock()
+        produced: List[AnchorReceipt] = []
+
+        # Items are removed one at a time, so an unexpected error part-way
+        # through a batch never leaves an anchored digest queued again.
+        for item in list(self._pending):
+            if item.next_attempt_at > now:
+                continue
+
+            item.attempts += 1
+            try:
+                data = self._backend.submit(item.digest)
+            except AnchorError as e:
+                item.last_error = str(e)
+                if item.attempts >= self._max_attempts:
+                    self._pending.remove(item)
+                    self._abandon(item)
+                else:
+                    item.next_attempt_at = now + self._base_backoff_s * (2 ** (item.attempts - 1))
+                    self._stats.retried += 1
+                continue
+
+            self._pending.remove(item)
+            receipt = AnchorReceipt(
+                digest=item.digest,
+                subject=item.subject,
+                backend=self._backend.name,
+                receipt=data,
+                anchored_at=now,
+            )
+            self._record(receipt)
+            produced.append(receipt)
+
+        return produced
+
+    def verify(self, receipt: AnchorReceipt) -> bool:
+        return self._backend.verify(receipt.digest, receipt.receipt)
+
+    def _record(self, receipt: AnchorReceipt):
+        self._receipts.append(receipt)
+        self._stats.anchored += 1
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "anchoring",
+                "handler": receipt.backend,
+                "outcome": "anchored",
+                "signal_id": receipt.digest,
+                "signal_domain": "governance",
+                "extra": canonical_json(asdict(receipt)),
+            })
+
+    def _abandon(self, item: PendingAnchor):
+        self._abandoned.append(item)
+        self._stats.abandoned += 1
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "anchoring",
+                "handler": self._backend.name,
+                "outcome": "anchor_abandoned",
+                "signal_id": item.digest,
+                "signal_domain": "operational",
+                "extra": item.last_error,
+            })
+        if self._emit:
+            self._emit({
+                "type": "alert",
+                "domain": "operational",
+                "authority": "system",
+                "source": "anchoring",
+                "payload": {
+                    "event": "anchor_abandoned",
+                    "backend": self._backend.name,
+                    "digest": item.digest,
+                    "subject": item.subject,
+                    "attempts": item.attempts,
+                    "error": item.last_error,
+                },
+            })
+
+    @property
+    def pending(self) -> List[PendingAnchor]:
+        return list(self._pending)
+
+    @property
+    def abandoned(self) -> List[PendingAnchor]:
+        return list(self._abandoned)
+
+    @property
+    def receipts(self) -> List[AnchorReceipt]:
+        return list(self._receipts)
+
+    @property
+    def stats(self) -> AnchorStats:
+        return self._stats
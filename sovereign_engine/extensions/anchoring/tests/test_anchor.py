@@ -0,0 +1,149 @@
+import os
+import tempfile
+import unittest
+from pathlib import Path
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.anchoring import (
+    AnchorBackend,
+    AnchorError,
+    AnchorQueue,
+    FileAnchorBackend,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class FlakyBackend(AnchorBackend):
+    name = "flaky"
+
+    def __init__(self, failures):
+        self.failures = failures
+
+    def submit(self, digest):
+        if self.failures > 0:
+            self.failures -= 1
+            raise AnchorError("gateway unavailable")
+        return {"tx": "0x" + digest[:8]}
+
+
+class CrashingBackend(AnchorBackend):
+    """Accepts the first digest, then fails with an error it does not wrap."""
+    name = "crashing"
+
+    def __init__(self):
+        self.submitted = []
+
+    def submit(self, digest):
+        if self.submitted:
+            raise RuntimeError("backend bug")
+        self.submitted.append(digest)
+        return {}
+
+
+class TestAnchorQueue(unittest.TestCase):
+    def setUp(self):
+        self.tmp = tempfile.TemporaryDirectory()
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+
+    def tearDown(self):
+        self.tmp.cleanup()
+
+    def test_ledger_head_anchored_and_receipt_ledgered(self):
+        backend = FileAnchorBackend(Path(self.tmp.name) / "anchors.jsonl")
+        queue = AnchorQueue(backend, ledger=self.engine.ledger)
+        head = self.engine.ledger.last_hash
+        queue.enqueue_ledger_head(self.engine.ledger)
+
+        receipts = queue.flush()
+        self.assertEqual(len(receipts), 1)
+        self.assertEqual(receipts[0].digest, head)
+        self.assertTrue(queue.verify(receipts[0]))
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "anchored")
+        self.assertTrue(self.engine.ledger.verify()["valid"])
+
+    def test_retry_with_backoff_then_abandon(self):
+        now = [1000.0]
+        queue = AnchorQueue(FlakyBackend(failures=10), max_attempts=3,
+                            base_backoff_s=10.0, clock=lambda: now[0])
+        queue.enqueue("ab" * 32, "bundle")
+
+        self.assertEqual(queue.flush(), [])
+        self.assertEqual(queue.pending[0].next_attempt_at, 1010.0)
+        self.assertEqual(queue.flush(), [])  # not yet due
+        self.assertEqual(queue.pending[0].attempts, 1)
+
+        now[0] = 1010.0
+        queue.flush()
+        now[0] = 1030.0
+        queue.flush()
+        self.assertEqual(queue.pending, [])
+        self.assertEqual(len(queue.abandoned), 1)
+        self.assertEqual(queue.stats.abandoned, 1)
+
+    def test_abandoned_digest_is_ledgered_and_alerted(self):
+        alerts = []
+        queue = AnchorQueue(FlakyBackend(failures=1), ledger=self.engine.ledger,
+                            max_attempts=1, emit=alerts.append)
+        queue.enqueue("ef" * 32, "bundle")
+        queue.flush()
+
+        last = self.engine.ledger.entries[-1]
+        self.assertEqual((last.outcome, last.signal_id), ("anchor_abandoned", "ef" * 32))
+        self.assertEqual(alerts[0]["payload"]["event"], "anchor_abandoned")
+        self.assertEqual(alerts[0]["payload"]["error"], "gateway unavailable")
+
+    def test_file_backend_io_error_is_retried(self):
+        path = Path(self.tmp.name) / "anchors.jsonl"
+        backend = FileAnchorBackend(path)
+        path.unlink()
+        path.mkdir()  # opening a directory as a file raises OSError
+        queue = AnchorQueue(backend, clock=lambda: 0.0)
+        queue.enqueue("12" * 32, "bundle")
+
+        self.assertEqual(queue.flush(), [])
+        self.assertEqual(queue.pending[0].attempts, 1)
+        self.assertIn(str(path), queue.pending[0].last_error)
+
+    def test_file_backend_is_stamped_and_keeps_legacy_files_unstamped(self):
+        path = Path(self.tmp.name) / "anchors.jsonl"
+        backend = FileAnchorBackend(path, constitution_hash="ab" * 32)
+        receipt = backend.submit("12" * 32)
+        self.assertEqual(receipt["line"], 1)
+        self.assertTrue(FileAnchorBackend(path).verify("12" * 32, receipt))
+        with self.assertRaises(AnchorError):
+            FileAnchorBackend(path, constitution_hash="cd" * 32)
+
+        legacy = Path(self.tmp.name) / "legacy.jsonl"
+        legacy.write_text('{"digest":"34","timestamp":0}\n', encoding="utf-8")
+        FileAnchorBackend(legacy, constitution_hash="ab" * 32)
+        self.assertEqual(len(legacy.read_text(encoding="utf-8").splitlines()), 1)
+
+    def test_anchored_item_leaves_queue_before_a_later_failure(self):
+        backend = CrashingBackend()
+        queue = AnchorQueue(backend)
+        queue.enqueue("aa" * 32, "first")
+        queue.enqueue("bb" * 32, "second")
+
+        with self.assertRaises(RuntimeError):
+            queue.flush()
+        self.assertEqual([p.digest for p in queue.pending], ["bb" * 32])
+        self.assertEqual(queue.stats.anchored, 1)
+
+    def test_recovers_after_transient_failure(self):
+        now = [0.0]
+        queue = AnchorQueue(FlakyBackend(failures=1), base_backoff_s=5.0,
+                            clock=lambda: now[0])
+        queue.enqueue("cd" * 32, "bundle")
+        queue.flush()
+        now[0] = 5.0
+        receipts = queue.flush()
+        self.assertEqual(receipts[0].receipt["tx"], "0xcdcdcdcd")
+        self.assertEqual(queue.stats.retried, 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
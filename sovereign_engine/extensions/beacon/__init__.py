@@ -0,0 +1,33 @@
+from .ceremony import (
+    BeaconError,
+    BeaconValue,
+    DrandBeacon,
+    EpochCeremony,
+    EpochCommitment,
+    EpochSeed,
+    LocalBeacon,
+    RandomnessBeacon,
+    fixed_round_schedule,
+)
+from .transcript import (
+    SELECTION_LABELS,
+    RandomnessTranscript,
+    TranscriptRecorder,
+    verify_transcript,
+)
+
+__all__ = [
+    "SELECTION_LABELS",
+    "BeaconError",
+    "BeaconValue",
+    "DrandBeacon",
+    "EpochCeremony",
+    "EpochCommitment",
+    "EpochSeed",
+    "LocalBeacon",
+    "RandomnessBeacon",
+    "RandomnessTranscript",
+    "TranscriptRecorder",
+    "fixed_round_schedule",
+    "verify_transcript",
+]
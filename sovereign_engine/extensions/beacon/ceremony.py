@@ -0,0 +1,267 @@
+"""
+Epoch Ceremony - External randomness for governance selections.
+S3-EXT-021: Randomness beacon bolt-on.
+
+Rotation order, jury selection and challenge nonces must not be
+predictable by a patient insider. At each epoch boundary the ceremony
+mixes a public beacon round (drand or similar) with the audit ledger head:
+the beacon operator cannot know the ledger head in advance, and the
+operators cannot choose the beacon value. Every derivation is a pure
+function of the recorded seed, so auditors can recompute it.
+
+The mix is commit-then-reveal. `commit` ledgers the current head together
+with a round the beacon has not yet published, and `run` later mixes that
+round with that committed head only. Ledger writes made once the round is
+out cannot move the seed, so the head cannot be ground against a known
+beacon value.
+
+Nor can the operators shop for a round: beacon rounds must strictly
+increase across ceremonies (read back from the ledger, so a restart does
+not reset them), and with a round schedule the round is fixed by the
+epoch and anything else is refused.
+
+Dependency: Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import json
+import time
+import urllib.request
+from abc import ABC, abstractmethod
+from dataclasses import dataclass
+from typing import Callable, List, Optional, Sequence, Tuple
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+
+
+class BeaconError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class BeaconValue:
+    round: int
+    randomness: str      # hex
+    source: str
+
+
+class RandomnessBeacon(ABC):
+    """Public source of unpredictable, verifiable randomness by round."""
+
+    @abstractmethod
+    def fetch(self, round: int) -> BeaconValue:
+        """Return the beacon output for a round. Raises BeaconError."""
+
+    @abstractmethod
+    def latest_round(self) -> int:
+        """Highest round published so far. Raises BeaconError."""
+
+
+class LocalBeacon(RandomnessBeacon):
+    """
+    Deterministic hash-chain beacon. For tests and isolated deployments
+    only — whoever holds the seed can predict every round.
+    """
+
+    def __init__(self, seed: str, latest_round: int = 0):
+        self._seed = seed
+        self._latest = latest_round
+
+    def fetch(self, round: int) -> BeaconValue:
+        return BeaconValue(round, sha256_hex(f"{self._seed}:{round}"), "local")
+
+    def latest_round(self) -> int:
+        return self._latest
+
+    def publish_through(self, round: int) -> None:
+        """Advance the simulated publication clock."""
+        self._latest = max(self._latest, round)
+
+
+class DrandBeacon(RandomnessBeacon):
+    """drand HTTP API (`{base_url}/public/{round}` and `/public/latest`)."""
+
+    def __init__(self, base_url: str, timeout_s: float = 10.0):
+        self.base_url = base_url.rstrip("/")
+        self.timeout_s = timeout_s
+
+    def _get(self, path: str) -> dict:
+        url = f"{self.base_url}/public/{path}"
+        try:
+            with urllib.request.urlopen(url, timeout=self.timeout_s) as resp:
+                data = json.loads(resp.read().decode("utf-8"))
+        except Exception as e:
+            raise BeaconError(f"{url}: {e}") from e
+        if "randomness" not in data:
+            raise BeaconError(f"{url}: response has no randomness field")
+        return data
+
+    def fetch(self, round: int) -> BeaconValue:
+        data = self._get(str(round))
+        return BeaconValue(int(data.get("round", round)), data["randomness"], self.base_url)
+
+    def latest_round(self) -> int:
+        data = self._get("latest")
+        if "round" not in data:
+            raise BeaconError(f"{self.base_url}/public/latest: response has no round field")
+        return int(data["round"])
+
+
+@dataclass(frozen=True)
+class EpochCommitment:
+    epoch: int
+    round: int
+    ledger_head: str
+
+
+@dataclass(frozen=True)
+class EpochSeed:
+    epoch: int
+    beacon: BeaconValue
+    ledger_head: str
+    seed: str
+    timestamp: float
+
+    def derive(self, label: str, *parts: str) -> str:
+        """Domain-separated derivation. Same inputs, same output, always."""
+        return sha256_hex(canonical_json([self.seed, label, list(parts)]))
+
+    def rotation_order(self, members: Sequence[str]) -> List[str]:
+        return sorted(members, key=lambda m: self.derive("rotation", m))
+
+    def select_jury(self, candidates: Sequence[str], size: int) -> List[str]:
+        if size > len(candidates):
+            raise BeaconError(f"Jury of {size} requested from {len(candidates)} candidates")
+        ranked = sorted(candidates, key=lambda c: self.derive("jury", c))
+        return ranked[:size]
+
+    def challenge_nonce(self, label: str) -> str:
+        return self.derive("challenge", label)
+
+
+def fixed_round_schedule(first_round: int, rounds_per_epoch: int) -> Callable[[int], int]:
+    """Round schedule for a beacon with a fixed period: epoch N uses one round."""
+    if rounds_per_epoch < 1:
+        raise BeaconError("rounds_per_epoch must be at least 1")
+    return lambda epoch: first_round + epoch * rounds_per_epoch
+
+
+class EpochCeremony:
+    """
+    Runs the epoch-boundary mix and keeps the seed history.
+
+    INVARIANT: Epochs advance strictly; an epoch's seed is never replaced.
+    INVARIANT: Beacon rounds advance strictly, and follow the schedule if one is set.
+    INVARIANT: A round is committed before it is published, and an epoch once.
+    INVARIANT: A seed mixes only the ledger head committed with its round.
+    """
+
+    def __init__(self, beacon: RandomnessBeacon, ledger,
+                 round_schedule: Optional[Callable[[int], int]] = None):
+        self._beacon = beacon
+        self._ledger = ledger
+        self._round_schedule = round_schedule
+        self._seeds: List[EpochSeed] = []
+
+    def _entries(self, outcome: str):
+        for entry in reversed(self._ledger.entries):
+            if entry.handler == "beacon" and entry.outcome == outcome:
+                yield json.loads(entry.extra)
+
+    def _last_seeded(self) -> Optional[Tuple[int, int]]:
+        for data in self._entries("epoch_seeded"):
+            return data["epoch"], data["round"]
+        return None
+
+    def _commitment(self, epoch: int) -> Optional[EpochCommitment]:
+        for data in self._entries("epoch_committed"):
+            if data["epoch"] == epoch:
+                return EpochCommitment(data["epoch"], data["round"], data["ledger_head"])
+        return None
+
+    def commit(self, epoch: int, beacon_round: int) -> EpochCommitment:
+        """Ledger the current head against a round the beacon has not published yet."""
+        last = self._last_seeded()
+        if last is not None and epoch <= last[0]:
+            raise BeaconError(f"Epoch {epoch} not after last ceremony epoch {last[0]}")
+        if self._commitment(epoch) is not None:
+            raise BeaconError(f"Epoch {epoch} is already committed")
+        if self._round_schedule is not None and beacon_round != self._round_schedule(epoch):
+            raise BeaconError(
+                f"Epoch {epoch} is scheduled for round {self._round_schedule(epoch)}, "
+                f"not {beacon_round}"
+            )
+        last_round = next((d["round"] for d in self._entries("epoch_committed")), None)
+        if last_round is not None and beacon_round <= last_round:
+            raise BeaconError(f"Beacon round {beacon_round} not after last round {last_round}")
+        published = self._beacon.latest_round()
+        if beacon_round <= published:
+            raise BeaconError(
+                f"Beacon round {beacon_round} is already published (latest {published})"
+            )
+        commitment = EpochCommitment(epoch, beacon_round, self._ledger.last_hash)
+        self._ledger.write({
+            "signal_type": "audit",
+            "route": "epoch_ceremony",
+            "handler": "beacon",
+            "outcome": "epoch_committed",
+            "signal_id": sha256_hex(canonical_json([epoch, beacon_round, commitment.ledger_head])),
+            "signal_domain": "governance",
+            "extra": canonical_json({
+                "epoch": epoch, "round": beacon_round, "ledger_head": commitment.ledger_head,
+            }),
+        })
+        return commitment
+
+    def run(self, epoch: int) -> EpochSeed:
+        """Reveal: fetch the committed round and mix it with the committed head."""
+        last = self._last_seeded()
+        if last is not None and epoch <= last[0]:
+            raise BeaconError(f"Epoch {epoch} not after last ceremony epoch {last[0]}")
+        commitment = self._commitment(epoch)
+        if commitment is None:
+            raise BeaconError(f"Epoch {epoch} has no commitment")
+        if commitment.round > self._beacon.latest_round():
+            raise BeaconError(f"Beacon round {commitment.round} is not published yet")
+        value = self._beacon.fetch(commitment.round)
+        if value.round != commitment.round:
+            raise BeaconError(f"Beacon returned round {value.round}, not {commitment.round}")
+        head = commitment.ledger_head
+        seed = EpochSeed(
+            epoch=epoch,
+            beacon=value,
+            ledger_head=head,
+            seed=self.recompute(epoch, value, head),
+            timestamp=time.time(),
+        )
+        self._ledger.write({
+            "signal_type": "audit",
+            "route": "epoch_ceremony",
+            "handler": "beacon",
+            "outcome": "epoch_seeded",
+            "signal_id": seed.seed,
+            "signal_domain": "governance",
+            "extra": canonical_json({
+                "epoch": epoch, "round": value.round,
+                "randomness": value.randomness, "source": value.source,
+                "ledger_head": head,
+            }),
+        })
+        self._seeds.append(seed)
+        return seed
+
+    @staticmethod
+    def recompute(epoch: int, beacon: BeaconValue, ledger_head: str) -> str:
+        """Auditor-side recomputation of an epoch seed."""
+        return sha256_hex(canonical_json([epoch, beacon.round, beacon.randomness, ledger_head]))
+
+    @property
+    def current(self) -> EpochSeed:
+        if not self._seeds:
+            raise BeaconError("No epoch ceremony has run")
+        return self._seeds[-1]
+
+    @property
+    def history(self) -> List[EpochSeed]:
+        return list(self._seeds)
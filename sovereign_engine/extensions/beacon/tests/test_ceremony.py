@@ -0,0 +1,97 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.beacon import (
+    BeaconError,
+    EpochCeremony,
+    LocalBeacon,
+    fixed_round_schedule,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+MEMBERS = [f"m{i}" for i in range(1, 8)]
+
+
+class TestEpochCeremony(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.beacon = LocalBeacon("test")
+        self.ceremony = EpochCeremony(self.beacon, self.engine.ledger)
+
+    def _seed(self, epoch, beacon_round, ceremony=None):
+        ceremony = ceremony or self.ceremony
+        ceremony.commit(epoch, beacon_round)
+        self.beacon.publish_through(beacon_round)
+        return ceremony.run(epoch)
+
+    def test_seed_is_ledgered_and_recomputable(self):
+        head = self.engine.ledger.last_hash
+        seed = self._seed(1, 100)
+        self.assertEqual(seed.ledger_head, head)
+        self.assertEqual(self.engine.ledger.entries[-1].signal_id, seed.seed)
+        self.assertEqual(EpochCeremony.recompute(1, seed.beacon, head), seed.seed)
+
+    def test_derivations_are_deterministic_and_separated(self):
+        seed = self._seed(1, 100)
+        order = seed.rotation_order(MEMBERS)
+        self.assertEqual(sorted(order), sorted(MEMBERS))
+        self.assertEqual(order, seed.rotation_order(list(reversed(MEMBERS))))
+        self.assertEqual(len(seed.select_jury(MEMBERS, 3)), 3)
+        self.assertNotEqual(seed.challenge_nonce("a"), seed.challenge_nonce("b"))
+        with self.assertRaises(BeaconError):
+            seed.select_jury(MEMBERS, 8)
+
+    def test_ledger_head_changes_the_seed(self):
+        first = self._seed(1, 100)
+        self.engine.submit_and_process("query", "operational", "operator", {"n": 1})
+        other = EpochCeremony.recompute(1, first.beacon, self.engine.ledger.last_hash)
+        self.assertNotEqual(first.seed, other)
+
+    def test_head_cannot_be_ground_after_publication(self):
+        committed = self.ceremony.commit(1, 100)
+        self.beacon.publish_through(100)
+        for n in range(3):
+            self.engine.submit_and_process("query", "operational", "operator", {"n": n})
+        seed = self.ceremony.run(1)
+        self.assertEqual(seed.ledger_head, committed.ledger_head)
+        self.assertEqual(
+            EpochCeremony.recompute(1, seed.beacon, committed.ledger_head), seed.seed
+        )
+
+    def test_published_rounds_cannot_be_committed(self):
+        self.beacon.publish_through(100)
+        with self.assertRaises(BeaconError):
+            self.ceremony.commit(1, 100)
+        self.ceremony.commit(1, 101)
+        with self.assertRaises(BeaconError):
+            self.ceremony.run(1)
+        with self.assertRaises(BeaconError):
+            self.ceremony.commit(1, 102)
+        with self.assertRaises(BeaconError):
+            self.ceremony.run(2)
+
+    def test_epochs_only_advance(self):
+        self._seed(2, 100)
+        with self.assertRaises(BeaconError):
+            self.ceremony.commit(2, 101)
+        with self.assertRaises(BeaconError):
+            self.ceremony.run(2)
+
+    def test_rounds_cannot_be_shopped_for(self):
+        self._seed(1, 100)
+        restarted = EpochCeremony(self.beacon, self.engine.ledger)
+        with self.assertRaises(BeaconError):
+            restarted.commit(2, 100)
+        scheduled = EpochCeremony(self.beacon, self.engine.ledger,
+                                  round_schedule=fixed_round_schedule(90, 10))
+        with self.assertRaises(BeaconError):
+            scheduled.commit(3, 125)
+        self.assertEqual(self._seed(3, 120, scheduled).beacon.round, 120)
+
+
+if __name__ == "__main__":
+    unittest.main()
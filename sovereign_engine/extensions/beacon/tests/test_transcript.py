@@ -0,0 +1,70 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.beacon import (
+    BeaconError,
+    EpochCeremony,
+    LocalBeacon,
+    RandomnessTranscript,
+    TranscriptRecorder,
+    verify_transcript,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+MEMBERS = [f"m{i}" for i in range(1, 8)]
+
+
+class TestRandomnessTranscript(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        beacon = LocalBeacon("test")
+        ceremony = EpochCeremony(beacon, self.engine.ledger)
+        ceremony.commit(epoch=1, beacon_round=100)
+        beacon.publish_through(100)
+        self.seed = ceremony.run(epoch=1)
+        self.recorder = TranscriptRecorder(ceremony, self.engine.ledger)
+
+    def test_selections_are_ledgered_and_re_derivable(self):
+        jury = self.recorder.jury("jury-1", MEMBERS, 3)
+        self.assertEqual(list(jury.selection), self.seed.select_jury(MEMBERS, 3))
+        rotation = self.recorder.rotation("rot-1", MEMBERS)
+        self.assertEqual(list(rotation.selection), self.seed.rotation_order(MEMBERS))
+        sample = self.recorder.sample("audit-7", MEMBERS, 2, "archive_audit")
+
+        self.assertEqual(self.engine.ledger.entries[-1].signal_id, sample.hash)
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "sample_selected")
+        for t in (jury, rotation, sample):
+            wire = RandomnessTranscript.from_dict(t.to_dict())
+            self.assertEqual(wire.hash, t.hash)
+            self.assertEqual(
+                verify_transcript(wire, self.engine.ledger, LocalBeacon("test")), []
+            )
+
+    def test_altered_transcripts_fail_verification(self):
+        jury = self.recorder.jury("jury-1", MEMBERS, 3)
+        data = jury.to_dict()
+        picked = RandomnessTranscript.from_dict({**data, "selection": MEMBERS[:3]})
+        self.assertIn("selection does not follow from the derived keys",
+                      verify_transcript(picked))
+        reseeded = RandomnessTranscript.from_dict({**data, "ledger_head": "0" * 64})
+        self.assertTrue(verify_transcript(reseeded))
+        self.assertIn("transcript not found in ledger",
+                      verify_transcript(picked, self.engine.ledger))
+        self.assertTrue(verify_transcript(jury, beacon=LocalBeacon("other")))
+
+    def test_decisions_are_transcribed_once(self):
+        self.recorder.jury("jury-1", MEMBERS, 3)
+        with self.assertRaises(BeaconError):
+            self.recorder.jury("jury-1", MEMBERS, 3)
+        with self.assertRaises(BeaconError):
+            self.recorder.jury("jury-2", MEMBERS, 8)
+        with self.assertRaises(BeaconError):
+            self.recorder.sample("s", ["a", "a"], 1, "dup")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,161 @@
+"""
+Randomness Transcripts - Every randomized decision shows its working.
+
+An epoch seed is recomputable, but a selection made from it is only
+provably fair if an auditor can also see which derivation was used, over
+which candidates, and what came out. A transcript records all of it: the
+beacon round and value, the ledger head it was mixed with, the derivation
+label, the candidate keys and the selection. Its hash is the ledgered
+signal_id, so the audit chain commits to the full transcript and
+`verify_transcript` can re-derive the outcome from first principles.
+"""
+
+from __future__ import annotations
+
+from dataclasses import asdict, dataclass
+from typing import Dict, List, Optional, Sequence, Tuple
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+from .ceremony import BeaconError, BeaconValue, EpochCeremony, EpochSeed, RandomnessBeacon
+
+
+# Selection kind → derivation label used by EpochSeed for that kind.
+SELECTION_LABELS = {"rotation": "rotation", "jury": "jury", "sample": "sample"}
+
+
+@dataclass(frozen=True)
+class RandomnessTranscript:
+    decision_id: str
+    kind: str
+    epoch: int
+    beacon: BeaconValue
+    ledger_head: str
+    seed: str
+    label: str
+    context: Tuple[str, ...]
+    keys: Tuple[Tuple[str, str], ...]     # (candidate, derived key), input order
+    size: int
+    selection: Tuple[str, ...]
+
+    @property
+    def hash(self) -> str:
+        return sha256_hex(canonical_json(self.to_dict()))
+
+    def to_dict(self) -> dict:
+        data = asdict(self)
+        data["context"] = list(self.context)
+        data["keys"] = [list(k) for k in self.keys]
+        data["selection"] = list(self.selection)
+        return data
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "RandomnessTranscript":
+        return cls(**{
+            **data,
+            "beacon": BeaconValue(**data["beacon"]),
+            "context": tuple(data["context"]),
+            "keys": tuple(tuple(k) for k in data["keys"]),
+            "selection": tuple(data["selection"]),
+        })
+
+
+def _select(seed: EpochSeed, label: str, context: Sequence[str],
+            candidates: Sequence[str], size: int):
+    keys = tuple((c, seed.derive(label, *context, c)) for c in candidates)
+    ranked = sorted(keys, key=lambda k: (k[1], k[0]))
+    return keys, tuple(c for c, _ in ranked[:size])
+
+
+class TranscriptRecorder:
+    """
+    Makes randomized selections from the current epoch seed and ledgers them.
+
+    INVARIANT: No selection is returned without its transcript in the ledger.
+    INVARIANT: A decision ID is transcribed once.
+    """
+
+    def __init__(self, ceremony: EpochCeremony, ledger):
+        self._ceremony = ceremony
+        self._ledger = ledger
+        self._transcripts: Dict[str, RandomnessTranscript] = {}
+
+    def rotation(self, decision_id: str, members: Sequence[str]) -> RandomnessTranscript:
+        return self._record(decision_id, "rotation", members, len(members))
+
+    def jury(self, decision_id: str, candidates: Sequence[str], size: int) -> RandomnessTranscript:
+        return self._record(decision_id, "jury", candidates, size)
+
+    def sample(self, decision_id: str, population: Sequence[str], count: int,
+               purpose: str) -> RandomnessTranscript:
+        return self._record(decision_id, "sample", population, count, (purpose,))
+
+    def _record(self, decision_id: str, kind: str, candidates: Sequence[str], size: int,
+                context: Tuple[str, ...] = ()) -> RandomnessTranscript:
+        if decision_id in self._transcripts:
+            raise BeaconError(f"Decision {decision_id} already has a transcript")
+        if len(set(candidates)) != len(candidates):
+            raise BeaconError("Candidates must be distinct")
+        if not 0 < size <= len(candidates):
+            raise BeaconError(f"Cannot select {size} from {len(candidates)} candidates")
+        seed = self._ceremony.current
+        label = SELECTION_LABELS[kind]
+        keys, selection = _select(seed, label, context, candidates, size)
+        transcript = RandomnessTranscript(
+            decision_id=decision_id, kind=kind, epoch=seed.epoch, beacon=seed.beacon,
+            ledger_head=seed.ledger_head, seed=seed.seed, label=label,
+            context=tuple(context), keys=keys, size=size, selection=selection,
+        )
+        self._ledger.write({
+            "signal_type": "audit",
+            "route": "randomized_decision",
+            "handler": "beacon",
+            "outcome": f"{kind}_selected",
+            "signal_id": transcript.hash,
+            "signal_domain": "governance",
+            "extra": canonical_json(transcript.to_dict()),
+        })
+        self._transcripts[decision_id] = transcript
+        return transcript
+
+    def get(self, decision_id: str) -> Optional[RandomnessTranscript]:
+        return self._transcripts.get(decision_id)
+
+    @property
+    def transcripts(self) -> List[RandomnessTranscript]:
+        return list(self._transcripts.values())
+
+
+def verify_transcript(transcript: RandomnessTranscript, ledger=None,
+                      beacon: Optional[RandomnessBeacon] = None) -> List[str]:
+    """
+    Re-derive a transcript from its inputs. Returns problems; empty means fair.
+
+    With a ledger, also checks that the transcript hash was committed. With
+    a beacon, also refetches the round and compares the published value.
+    """
+    t = transcript
+    problems: List[str] = []
+    if EpochCeremony.recompute(t.epoch, t.beacon, t.ledger_head) != t.seed:
+        problems.append("seed does not recompute from beacon and ledger head")
+    if SELECTION_LABELS.get(t.kind) != t.label:
+        problems.append(f"label {t.label!r} is not the derivation for {t.kind}")
+
+    seed = EpochSeed(t.epoch, t.beacon, t.ledger_head, t.seed, 0.0)
+    candidates = [c for c, _ in t.keys]
+    keys, selection = _select(seed, t.label, t.context, candidates, t.size)
+    if keys != t.keys:
+        problems.append("derived keys do not match")
+    if selection != t.selection:
+        problems.append("selection does not follow from the derived keys")
+
+    if ledger is not None and not any(e.signal_id == t.hash for e in ledger.entries):
+        problems.append("transcript not found in ledger")
+    if beacon is not None:
+        try:
+            published = beacon.fetch(t.beacon.round)
+        except BeaconError as e:
+            problems.append(f"beacon unavailable: {e}")
+        else:
+            if published.randomness != t.beacon.randomness:
+                problems.append(f"beacon round {t.beacon.round} value does not match")
+    return problems
@@ -0,0 +1,297 @@
+"""
+Notify - Governance events delivered to humans.
+Part of Connector Pack.
+
+Halts and escalated warnings are useless if they only exist in the
+ledger. A Notifier watches an engine — every halt, plus any ledger
+outcome named in its routing table — and hands each event to the
+adapters routed for that event type. Delivery is queued with bounded
+retry and exponential backoff. A delivery that is finally abandoned is
+itself ledgered and raised as an operational alert, since a silent
+notification path is an infrastructure failure.
+
+With a RedactionPolicy attached, summaries and details are redacted when
+queued, so no adapter ever holds the unredacted event.
+
+Nothing is sent unless a Notifier is constructed and wired; adapters use
+the standard library only.
+"""
+
+from __future__ import annotations
+
+import json
+import smtplib
+import time
+import urllib.parse
+import urllib.request
+from abc import ABC, abstractmethod
+from dataclasses import dataclass, field
+from email.message import EmailMessage
+from typing import Any, Callable, Dict, List, Optional
+
+from ..redaction import MASK, RedactionPolicy
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+
+
+HALT_EVENT = "halt"
+
+
+class DeliveryError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class Notification:
+    event_type: str
+    summary: str
+    detail: Dict[str, Any]
+    at: float
+
+    @property
+    def id(self) -> str:
+        return sha256_hex(canonical_json([self.event_type, self.summary, self.detail, self.at]))
+
+
+class NotificationAdapter(ABC):
+    name: str = "abstract"
+
+    @abstractmethod
+    def deliver(self, notification: Notification):
+        """Deliver or raise DeliveryError."""
+
+
+class WebhookAdapter(NotificationAdapter):
+    """POSTs the notification as JSON."""
+
+    name = "webhook"
+
+    def __init__(self, url: str, timeout_s: float = 10.0):
+        self.url = url
+        self.timeout_s = timeout_s
+
+    def deliver(self, notification: Notification):
+        body = json.dumps({
+            "id": notification.id, "event_type": notification.event_type,
+            "summary": notification.summary, "detail": notification.detail,
+            "at": notification.at,
+        }).encode("utf-8")
+        req = urllib.request.Request(
+            self.url, data=body, headers={"Content-Type": "application/json"}, method="POST"
+        )
+        try:
+            with urllib.request.urlopen(req, timeout=self.timeout_s):
+                pass
+        except Exception as e:
+            raise DeliveryError(f"{self.url}: {e}") from e
+
+
+class SmtpAdapter(NotificationAdapter):
+    name = "smtp"
+
+    def __init__(self, host: str, sender: str, recipients: List[str], port: int = 25,
+                 username: Optional[str] = None, password: Optional[str] = None,
+                 starttls: bool = True, timeout_s: float = 10.0):
+        self.host, self.port = host, port
+        self.sender, self.recipients = sender, list(recipients)
+        self.username, self.password = username, password
+        self.starttls = starttls
+        self.timeout_s = timeout_s
+
+    def deliver(self, notification: Notification):
+        msg = EmailMessage()
+        msg["Subject"] = f"[sovereign] {notification.event_type}: {notification.summary}"
+        msg["From"] = self.sender
+        msg["To"] = ", ".join(self.recipients)
+        msg.set_content(json.dumps(notification.detail, indent=2, sort_keys=True))
+        try:
+            with smtplib.SMTP(self.host, self.port, timeout=self.timeout_s) as smtp:
+                if self.starttls:
+                    smtp.starttls()
+                if self.username:
+                    smtp.login(self.username, self.password or "")
+                smtp.send_message(msg)
+        except Exception as e:
+            raise DeliveryError(f"smtp://{self.host}:{self.port}: {e}") from e
+
+
+class MatrixAdapter(NotificationAdapter):
+    """Sends an m.text message to one room via the client-server API."""
+
+    name = "matrix"
+
+    def __init__(self, homeserver: str, room_id: str, access_token: str,
+                 timeout_s: float = 10.0):
+        self.homeserver = homeserver.rstrip("/")
+        self.room_id = room_id
+        self.access_token = access_token
+        self.timeout_s = timeout_s
+
+    def deliver(self, notification: Notification):
+        room = urllib.parse.quote(self.room_id, safe="")
+        url = (f"{self.homeserver}/_matrix/client/v3/rooms/{room}"
+               f"/send/m.room.message/{notification.id}")
+        body = json.dumps({
+            "msgtype": "m.text",
+            "body": f"{notification.event_type}: {notification.summary}",
+        }).encode("utf-8")
+        req = urllib.request.Request(url, data=body, method="PUT", headers={
+            "Content-Type": "application/json",
+            "Authorization": f"Bearer {self.access_token}",
+        })
+        try:
+            with urllib.request.urlopen(req, timeout=self.timeout_s):
+                pass
+        except Exception as e:
+            raise DeliveryError(f"{self.homeserver}: {e}") from e
+
+
+@dataclass
+class PendingDelivery:
+    notification: Notification
+    adapter: str
+    attempts: int = 0
+    next_attempt_at: float = 0.0
+    last_error: Optional[str] = None
+
+
+@dataclass
+class NotifyStats:
+    queued: int = 0
+    delivered: int = 0
+    retried: int = 0
+    abandoned: int = 0
+    unrouted: int = 0
+
+
+class Notifier:
+    """
+    INVARIANT: Every routed event is queued once per routed adapter.
+    INVARIANT: Abandoned deliveries are ledgered and alerted, never dropped silently.
+    """
+
+    def __init__(self, adapters: List[NotificationAdapter], routes: Dict[str, List[str]],
+                 ledger=None, emit: Optional[Callable[[dict], object]] = None,
+                 max_attempts: int = 5, base_backoff_s: float = 30.0,
+                 redaction: Optional[RedactionPolicy] = None,
+                 clock: Callable[[], float] = time.time):
+        self._adapters = {a.name: a for a in adapters}
+        unknown = {n for names in routes.values() for n in names} - set(self._adapters)
+        if unknown:
+            raise DeliveryError(f"Routes name unknown adapters: {sorted(unknown)}")
+        self._routes = {k: list(v) for k, v in routes.items()}
+        self._ledger = ledger
+        self._emit = emit
+        self._max_attempts = max_attempts
+        self._base_backoff_s = base_backoff_s
+        self._redaction = redaction
+        self._clock = clock
+        self._pending: List[PendingDelivery] = []
+        self._abandoned: List[PendingDelivery] = []
+        self._stats = NotifyStats()
+
+    def notify(self, event_type: str, summary: str,
+               detail: Optional[Dict[str, Any]] = None) -> Optional[Notification]:
+        adapters = self._routes.get(event_type, self._routes.get("*", []))
+        if not adapters:
+            self._stats.unrouted += 1
+            return None
+        detail = dict(detail or {})
+        if self._redaction is not None:
+            summary = self._redaction.redact_field("summary", summary) or MASK
+            detail = self._redaction.redact(detail)
+        notification = Notification(event_type, summary, detail, self._clock())
+        for name in adapters:
+            self._pending.append(PendingDelivery(notification, name,
+                                                 next_attempt_at=notification.at))
+            self._stats.queued += 1
+        return notification
+
+    def watch(self, engine) -> "Notifier":
+        """Notify on every halt and on ledger outcomes that have a route."""
+        halt, write = engine.halt_ctrl.halt, engine.ledger.write
+
+        def notifying_halt(reason, source="unknown"):
+            halt(reason, source)
+            # The reason names whatever failed; it travels as a field the
+            # redaction policy governs, never in the summary.
+            self.notify(HALT_EVENT, f"halt raised by {source}",
+                        {"source": source, "reason": reason})
+
+        def notifying_write(record):
+            entry = write(record)
+            if entry.outcome in self._routes and entry.outcome != HALT_EVENT:
+                self.notify(entry.outcome, f"{entry.route}: {entry.outcome}", {
+                    "index": entry.index, "signal_id": entry.signal_id,
+                    "domain": entry.signal_domain,
+                })
+            return entry
+
+        engine.halt_ctrl.halt = notifying_halt
+        engine.ledger.write = notifying_write
+        return self
+
+    def flush(self) -> int:
+        """Attempt every due delivery once. Returns deliveries made."""
+        now = self._clock()
+        delivered = 0
+        still_pending: List[PendingDelivery] = []
+        for item in self._pending:
+            if item.next_attempt_at > now:
+                still_pending.append(item)
+                continue
+            item.attempts += 1
+            try:
+                self._adapters[item.adapter].deliver(item.notification)
+            except DeliveryError as e:
+                item.last_error = str(e)
+                if item.attempts >= self._max_attempts:
+                    self._abandon(item)
+                else:
+                    item.next_attempt_at = now + self._base_backoff_s * (2 ** (item.attempts - 1))
+                    self._stats.retried += 1
+                    still_pending.append(item)
+                continue
+            delivered += 1
+            self._stats.delivered += 1
+        self._pending = still_pending
+        return delivered
+
+    def _abandon(self, item: PendingDelivery):
+        self._abandoned.append(item)
+        self._stats.abandoned += 1
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "notify",
+                "handler": item.adapter,
+                "outcome": "notification_abandoned",
+                "signal_id": item.notification.id,
+                "signal_domain": "operational",
+                "extra": item.last_error,
+            })
+        if self._emit:
+            self._emit({
+                "type": "alert",
+                "domain": "operational",
+                "authority": "system",
+                "source": "notifier",
+                "payload": {
+                    "event": "notification_delivery_failed",
+                    "adapter": item.adapter,
+                    "event_type": item.notification.event_type,
+                    "attempts": item.attempts,
+                    "error": item.last_error,
+                },
+            })
+
+    @property
+    def pending(self) -> List[PendingDelivery]:
+        return list(self._pending)
+
+    @property
+    def abandoned(self) -> List[PendingDelivery]:
+        return list(self._abandoned)
+
+    @property
+    def stats(self) -> NotifyStats:
+        return self._stats
@@ -8,8 +8,9 @@ import requests
 from typing import Dict, Optional
 
 class SlackConnector:
-    def __init__(self, webhook_url: Optional[str] = None):
+    def __init__(self, webhook_url: Optional[str] = None, redaction=None):
         self.webhook_url = webhook_url or self._get_webhook_from_env()
+        self.redaction = redaction
     
     def _get_webhook_from_env(self):
         import os
@@ -21,6 +22,10 @@ class SlackConnector:
         if not self.webhook_url:
             print("No Slack webhook configured")
             return False
+
+        if self.redaction is not None:
+            message = self.redaction.redact_field("summary", message) or "[redacted]"
+            context = self.redaction.redact(context) if context else context
         
         colors = {
             'info': '#36a64f',
@@ -0,0 +1,132 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.connectors.notify import (
+    DeliveryError,
+    NotificationAdapter,
+    Notifier,
+    WebhookAdapter,
+)
+from sovereign_engine.extensions.genesis import key_commitment
+from sovereign_engine.extensions.keys import KeyDirectory, approve_revocation
+from sovereign_engine.extensions.quorum import MemberState, QuorumRoster, attest
+from sovereign_engine.extensions.redaction import RedactionPolicy
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class RecordingAdapter(NotificationAdapter):
+    def __init__(self, name, failing=False):
+        self.name = name
+        self.failing = failing
+        self.delivered = []
+
+    def deliver(self, notification):
+        if self.failing:
+            raise DeliveryError(f"{self.name} down")
+        self.delivered.append(notification)
+
+
+class TestNotifier(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.pager = RecordingAdapter("pager")
+        self.mail = RecordingAdapter("mail", failing=True)
+        self.results = []
+        self.notifier = Notifier(
+            [self.pager, self.mail],
+            {"halt": ["pager", "mail"], "warning_escalated": ["pager"]},
+            ledger=self.engine.ledger,
+            emit=lambda s: self.results.append(self.engine.submit_and_process(**s)),
+            max_attempts=2, base_backoff_s=10, clock=lambda: self.now,
+        ).watch(self.engine)
+
+    def test_halts_and_routed_outcomes_are_delivered(self):
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self.engine.ledger.write({"signal_type": "audit", "route": "advisory_desk",
+                                  "handler": "advisory_desk", "outcome": "warning_escalated"})
+        self.engine.ledger.write({"signal_type": "audit", "route": "x",
+                                  "handler": "x", "outcome": "unrouted"})
+        self.assertEqual(self.notifier.flush(), 2)
+        self.assertEqual([n.event_type for n in self.pager.delivered],
+                         ["halt", "warning_escalated"])
+
+    def test_failing_adapter_backs_off_then_is_abandoned_loudly(self):
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self.notifier.flush()
+        self.assertEqual(len(self.notifier.pending), 1)
+        self.now += 5
+        self.notifier.flush()
+        self.assertEqual(self.notifier.stats.abandoned, 0)
+        self.now += 10
+        self.notifier.flush()
+        self.assertEqual(self.notifier.stats.abandoned, 1)
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertIn("notification_abandoned", outcomes)
+
+    def test_routes_must_name_known_adapters(self):
+        with self.assertRaises(DeliveryError):
+            Notifier([self.pager], {"halt": ["sms"]})
+
+    def test_webhook_failure_is_a_delivery_error(self):
+        notifier = Notifier([WebhookAdapter("http://127.0.0.1:9/hook", timeout_s=0.5)],
+                            {"*": ["webhook"]}, max_attempts=1)
+        notifier.notify("anything", "test")
+        notifier.flush()
+        self.assertEqual(notifier.stats.abandoned, 1)
+
+    def test_redaction_applies_to_export_not_to_the_ledger(self):
+        policy = RedactionPolicy(key=b"export-key", actions={
+            "public": "keep", "internal": "mask", "sensitive": "pseudo", "secret": "drop"})
+        notifier = Notifier([self.pager], {"member_lapsed": ["pager"]}, redaction=policy,
+                            clock=lambda: self.now).watch(self.engine)
+        self.engine.ledger.write({"signal_type": "audit", "route": "quorum",
+                                  "handler": "roster", "outcome": "member_lapsed",
+                                  "signal_id": "m3", "extra": "m3 missed attestation"})
+        notifier.notify("member_lapsed", "m3 missed attestation",
+                        {"member_id": "m3", "signature": "ab" * 32, "count": 2})
+        notifier.flush()
+        sent = self.pager.delivered[-1]
+        self.assertEqual(sent.summary, "[redacted]")
+        self.assertEqual(sent.detail["member_id"], policy.pseudonym("m3"))
+        self.assertNotIn("signature", sent.detail)
+        self.assertEqual(sent.detail["count"], 2)
+        self.assertEqual(self.engine.ledger.entries[-1].extra, "m3 missed attestation")
+
+    def test_default_policy_hides_subjects_of_watched_events(self):
+        policy = RedactionPolicy(key=b"export-key")
+        routes = {e: ["pager"] for e in ("halt", "member_sabbatical", "key_revoked")}
+        notifier = Notifier([self.pager], routes, redaction=policy,
+                            clock=lambda: self.now).watch(self.engine)
+        secrets = {k: k.encode() for k in ("m1", "m2", "m3", "s1", "alice")}
+        roster = QuorumRoster(["m1", "m2", "m3"], threshold=2, secret_resolver=secrets.get,
+                              attestations_required=1, ledger=self.engine.ledger)
+        roster.transition("m3", MemberState.SABBATICAL, [attest(
+            "m1", secrets["m1"], "m3", MemberState.ACTIVE, MemberState.SABBATICAL, "leave")],
+            "leave")
+        keys = KeyDirectory(secrets.get, revocation_threshold=1, ledger=self.engine.ledger)
+        keys.register("s1", "steward", key_commitment(secrets["s1"]))
+        keys.register("alice", "operator", key_commitment(secrets["alice"]))
+        keys.revoke("alice", "operator", "left", {
+            "s1": approve_revocation("s1", secrets["s1"], "alice", "operator", "left")})
+        self.engine.halt_ctrl.halt("member m3 missed attestation", "quorum")
+        notifier.flush()
+
+        sent = {n.event_type: n for n in self.pager.delivered}
+        self.assertEqual(sent["member_sabbatical"].detail["signal_id"], policy.pseudonym("m3"))
+        self.assertEqual(sent["key_revoked"].detail["signal_id"],
+                         policy.pseudonym("operator:alice"))
+        halt = sent["halt"]
+        self.assertNotIn("m3", halt.summary)
+        self.assertEqual(halt.detail["reason"], policy.pseudonym("member m3 missed attestation"))
+        for n in self.pager.delivered:
+            self.assertNotIn("alice", repr(n))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,21 @@
+from .sealed import (
+    Disclosure,
+    DisclosureError,
+    DisclosureRequest,
+    SealedRationaleStore,
+    approve_disclosure,
+    rationale_commitment,
+    seal,
+    unseal,
+)
+
+__all__ = [
+    "Disclosure",
+    "DisclosureError",
+    "DisclosureRequest",
+    "SealedRationaleStore",
+    "approve_disclosure",
+    "rationale_commitment",
+    "seal",
+    "unseal",
+]
@@ -0,0 +1,228 @@
+"""
+Selective Disclosure - Sealed audit rationale with quorum-gated reveal.
+S3-EXT-022: Audit privacy bolt-on.
+
+Audit findings must be honest, but a detailed rationale ("the gate misses
+X when Y") is also an exploit recipe. Rationale is therefore split: the
+public ledger carries only a salted hash commitment, while the text is
+sealed in an envelope. Revealing it requires approval by a quorum of
+active members, and every request, approval and disclosure is ledgered.
+Once disclosed, anyone can check the text against the public commitment.
+
+Sealing uses HMAC-SHA256 as a PRF in counter mode with an encrypt-then-MAC
+tag — stdlib only, per the zero-dependency rule. The keystream and the tag
+use separate subkeys derived from the vault key, never the key itself.
+Envelopes are written through a Storage backend when one is given, so a
+sealed rationale outlives the process that sealed it. Each stored
+envelope carries a version stamp, checked when the store is reopened;
+envelopes stored before stamping read as format 0.
+
+Dependency: Phase 4 (audit), S3-EXT-003 (quorum roster), S3-EXT-033 (storage)
+"""
+
+from __future__ import annotations
+
+import hashlib
+import hmac
+import os
+import time
+import uuid
+from dataclasses import dataclass, field
+from typing import Callable, Dict, List, Optional, Tuple
+
+from ..quorum import MemberState
+from ..storage import Storage
+from ..storage.versioning import (
+    MIGRATIONS,
+    UNBOUND_PROFILE,
+    VersionStamp,
+    check_stamp,
+    current_stamp,
+)
+from ..security.zero_trust_evidence import sha256_hex, sign_hmac, verify_hmac
+
+
+NAMESPACE = "sealed_rationale"
+ARTIFACT = "sealed_rationale"
+
+
+class DisclosureError(Exception):
+    pass
+
+
+def _subkeys(key: bytes) -> Tuple[bytes, bytes]:
+    """(encryption key, MAC key), each an HMAC of the vault key."""
+    return (hmac.new(key, b"enc", hashlib.sha256).digest(),
+            hmac.new(key, b"mac", hashlib.sha256).digest())
+
+
+def _keystream(key: bytes, nonce: bytes, length: int) -> bytes:
+    out = bytearray()
+    counter = 0
+    while len(out) < length:
+        out += hmac.new(key, nonce + counter.to_bytes(8, "big"), hashlib.sha256).digest()
+        counter += 1
+    return bytes(out[:length])
+
+
+def seal(key: bytes, plaintext: bytes) -> bytes:
+    """nonce(16) || ciphertext || tag(32)"""
+    enc_key, mac_key = _subkeys(key)
+    nonce = os.urandom(16)
+    ct = bytes(a ^ b for a, b in zip(plaintext, _keystream(enc_key, nonce, len(plaintext))))
+    tag = hmac.new(mac_key, nonce + ct, hashlib.sha256).digest()
+    return nonce + ct + tag
+
+
+def unseal(key: bytes, envelope: bytes) -> bytes:
+    if len(envelope) < 48:
+        raise DisclosureError("Envelope truncated")
+    enc_key, mac_key = _subkeys(key)
+    nonce, ct, tag = envelope[:16], envelope[16:-32], envelope[-32:]
+    expected = hmac.new(mac_key, nonce + ct, hashlib.sha256).digest()
+    if not hmac.compare_digest(tag, expected):
+        raise DisclosureError("Envelope authentication failed")
+    return bytes(a ^ b for a, b in zip(ct, _keystream(enc_key, nonce, len(ct))))
+
+
+def rationale_commitment(rationale: str, salt: str) -> str:
+    return sha256_hex(f"{salt}:{rationale}")
+
+
+@dataclass(frozen=True)
+class Disclosure:
+    commitment: str
+    rationale: str
+    salt: str
+
+    def verify(self) -> bool:
+        """Anyone can check a revealed rationale against the public ledger."""
+        return rationale_commitment(self.rationale, self.salt) == self.commitment
+
+
+@dataclass
+class DisclosureRequest:
+    request_id: str
+    commitment: str
+    requester: str
+    purpose: str
+    approvals: List[str] = field(default_factory=list)
+    disclosed: bool = False
+    timestamp: float = field(default_factory=time.time)
+
+    def approval_payload(self) -> dict:
+        return {
+            "request_id": self.request_id,
+            "commitment": self.commitment,
+            "purpose": self.purpose,
+        }
+
+
+class SealedRationaleStore:
+    """
+    Envelope store for sensitive rationale.
+
+    INVARIANT: The ledger never sees plaintext rationale.
+    INVARIANT: No disclosure without approvals from a quorum of active members.
+    INVARIANT: Every seal, request, approval and disclosure is ledgered.
+    """
+
+    def __init__(self, vault_key: bytes, roster, secret_resolver: Callable[[str], bytes],
+                 ledger, storage: Optional[Storage] = None,
+                 constitution_hash: str = UNBOUND_PROFILE,
+                 accept_profile_change: bool = False):
+        self._key = bytes(vault_key)
+        self._roster = roster
+        self._secret_resolver = secret_resolver
+        self._ledger = ledger
+        self._storage = storage
+        self._constitution_hash = constitution_hash
+        self._envelopes: Dict[str, bytes] = {}
+        self._requests: Dict[str, DisclosureRequest] = {}
+        if storage is not None:
+            for commitment, stored in storage.iterate(NAMESPACE):
+                raw_stamp = stored.get("stamp") if isinstance(stored, dict) else None
+                stamp = VersionStamp.from_dict(raw_stamp) if raw_stamp else None
+                problems = check_stamp(stamp, ARTIFACT, constitution_hash, accept_profile_change)
+                if problems:
+                    raise DisclosureError(f"Envelope {commitment[:12]}…: " + "; ".join(problems))
+                stored = MIGRATIONS.upgrade(ARTIFACT, stamp, stored)
+                self._envelopes[commitment] = bytes.fromhex(stored["envelope"])
+
+    def seal(self, subject: str, rationale: str) -> str:
+        """Seal a rationale. Returns the public commitment."""
+        salt = os.urandom(16).hex()
+        commitment = rationale_commitment(rationale, salt)
+        envelope = seal(self._key, f"{salt}:{rationale}".encode("utf-8"))
+        if self._storage is not None:
+            self._storage.put(NAMESPACE, commitment, {
+                "envelope": envelope.hex(),
+                "stamp": current_stamp(ARTIFACT, self._constitution_hash).to_dict(),
+            })
+        self._envelopes[commitment] = envelope
+        self._write("rationale_sealed", commitment, subject)
+        return commitment
+
+    def request_disclosure(self, commitment: str, requester: str, purpose: str) -> DisclosureRequest:
+        if commitment not in self._envelopes:
+            raise DisclosureError(f"No sealed rationale for commitment {commitment[:12]}…")
+        req = DisclosureRequest(
+            request_id=str(uuid.uuid4()), commitment=commitment,
+            requester=requester, purpose=purpose,
+        )
+        self._requests[req.request_id] = req
+        self._write("disclosure_requested", commitment, f"{requester}: {purpose}")
+        return req
+
+    def approve(self, request_id: str, member_id: str, signature: str) -> DisclosureRequest:
+        req = self._get(request_id)
+        if self._roster.state_of(member_id) != MemberState.ACTIVE:
+            raise DisclosureError(f"{member_id} is not an active member")
+        if member_id in req.approvals:
+            return req
+        if not verify_hmac(self._secret_resolver(member_id), req.approval_payload(), signature):
+            raise DisclosureError(f"Invalid approval signature from {member_id}")
+        req.approvals.append(member_id)
+        self._write("disclosure_approved", req.commitment, member_id)
+        return req
+
+    def disclose(self, request_id: str) -> Disclosure:
+        req = self._get(request_id)
+        # Re-check at disclosure time: an approver may have left since.
+        live = [m for m in req.approvals if self._roster.state_of(m) == MemberState.ACTIVE]
+        if len(live) < self._roster.threshold:
+            raise DisclosureError(
+                f"Disclosure needs {self._roster.threshold} active approvals, has {len(live)}"
+            )
+        salt, rationale = unseal(self._key, self._envelopes[req.commitment]).decode("utf-8").split(":", 1)
+        disclosure = Disclosure(commitment=req.commitment, rationale=rationale, salt=salt)
+        if not disclosure.verify():
+            raise DisclosureError("Sealed rationale does not match its commitment")
+        req.disclosed = True
+        self._write("rationale_disclosed", req.commitment, f"{req.requester}: {req.purpose}")
+        return disclosure
+
+    def _get(self, request_id: str) -> DisclosureRequest:
+        if request_id not in self._requests:
+            raise DisclosureError(f"Unknown disclosure request: {request_id}")
+        return self._requests[request_id]
+
+    def _write(self, outcome: str, commitment: str, extra: str):
+        self._ledger.write({
+            "signal_type": "audit",
+            "route": "disclosure",
+            "handler": "sealed_rationale",
+            "outcome": outcome,
+            "signal_id": commitment,
+            "signal_domain": "governance",
+            "extra": extra,
+        })
+
+    @property
+    def commitments(self) -> List[str]:
+        return list(self._envelopes)
+
+
+def approve_disclosure(request: DisclosureRequest, secret: bytes) -> str:
+    """Member-side signature approving a disclosure request."""
+    return sign_hmac(bytes(secret), request.approval_payload())
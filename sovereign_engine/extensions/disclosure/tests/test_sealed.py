@@ -0,0 +1,112 @@
+import hashlib
+import hmac
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.disclosure import (
+    DisclosureError,
+    SealedRationaleStore,
+    approve_disclosure,
+    seal,
+    unseal,
+)
+from sovereign_engine.extensions.quorum import MemberState, QuorumRoster, attest
+from sovereign_engine.extensions.storage import MemoryStorage, profile_hash
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+MEMBERS = ["m1", "m2", "m3", "m4"]
+SECRETS = {m: f"secret-{m}".encode() for m in MEMBERS}
+RATIONALE = "Legality gate misses replayed escalations when source is spoofed"
+
+
+class TestSealedRationale(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.roster = QuorumRoster(MEMBERS, threshold=2, secret_resolver=SECRETS.get)
+        self.store = SealedRationaleStore(b"vault-key", self.roster, SECRETS.get,
+                                          self.engine.ledger)
+
+    def test_ledger_holds_commitment_not_plaintext(self):
+        commitment = self.store.seal("audit-7", RATIONALE)
+        self.assertEqual(self.engine.ledger.entries[-1].signal_id, commitment)
+        for e in self.engine.ledger.entries:
+            self.assertNotIn("spoofed", e.extra or "")
+
+    def test_quorum_approval_reveals_verifiable_rationale(self):
+        commitment = self.store.seal("audit-7", RATIONALE)
+        req = self.store.request_disclosure(commitment, "auditor", "adjudication")
+        self.store.approve(req.request_id, "m1", approve_disclosure(req, SECRETS["m1"]))
+        with self.assertRaises(DisclosureError):
+            self.store.disclose(req.request_id)
+        self.store.approve(req.request_id, "m2", approve_disclosure(req, SECRETS["m2"]))
+
+        disclosure = self.store.disclose(req.request_id)
+        self.assertEqual(disclosure.rationale, RATIONALE)
+        self.assertTrue(disclosure.verify())
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertEqual(outcomes.count("disclosure_approved"), 2)
+        self.assertIn("rationale_disclosed", outcomes)
+
+    def test_departed_approver_no_longer_counts(self):
+        commitment = self.store.seal("audit-7", RATIONALE)
+        req = self.store.request_disclosure(commitment, "auditor", "adjudication")
+        for m in ["m1", "m2"]:
+            self.store.approve(req.request_id, m, approve_disclosure(req, SECRETS[m]))
+        atts = [attest(a, SECRETS[a], "m2", MemberState.ACTIVE, MemberState.REMOVED, "")
+                for a in ["m3", "m4"]]
+        self.roster.transition("m2", MemberState.REMOVED, atts)
+        with self.assertRaises(DisclosureError):
+            self.store.disclose(req.request_id)
+
+    def test_envelope_tamper_detected(self):
+        env = bytearray(seal(b"k", b"secret text"))
+        self.assertEqual(unseal(b"k", bytes(env)), b"secret text")
+        env[20] ^= 1
+        with self.assertRaises(DisclosureError):
+            unseal(b"k", bytes(env))
+
+    def test_tag_uses_a_derived_mac_key(self):
+        env = seal(b"k", b"secret text")
+        mac_key = hmac.new(b"k", b"mac", hashlib.sha256).digest()
+        self.assertEqual(env[-32:], hmac.new(mac_key, env[:-32], hashlib.sha256).digest())
+
+    def test_envelopes_survive_a_new_store(self):
+        storage = MemoryStorage()
+        store = SealedRationaleStore(b"vault-key", self.roster, SECRETS.get,
+                                     self.engine.ledger, storage=storage)
+        commitment = store.seal("audit-7", RATIONALE)
+
+        reopened = SealedRationaleStore(b"vault-key", self.roster, SECRETS.get,
+                                        self.engine.ledger, storage=storage)
+        req = reopened.request_disclosure(commitment, "auditor", "adjudication")
+        for m in ["m1", "m2"]:
+            reopened.approve(req.request_id, m, approve_disclosure(req, SECRETS[m]))
+        self.assertEqual(reopened.disclose(req.request_id).rationale, RATIONALE)
+
+    def test_stored_envelopes_are_stamped_and_legacy_ones_migrate(self):
+        storage = MemoryStorage()
+        profile = profile_hash(self.engine.constitution)
+        store = SealedRationaleStore(b"vault-key", self.roster, SECRETS.get, self.engine.ledger,
+                                     storage=storage, constitution_hash=profile)
+        commitment = store.seal("audit-7", RATIONALE)
+        self.assertEqual(storage.get("sealed_rationale", commitment)["stamp"]["constitution_hash"],
+                         profile)
+        with self.assertRaises(DisclosureError):
+            SealedRationaleStore(b"vault-key", self.roster, SECRETS.get, self.engine.ledger,
+                                 storage=storage, constitution_hash="0" * 64)
+
+        legacy = MemoryStorage()
+        legacy.put("sealed_rationale", commitment,
+                   storage.get("sealed_rationale", commitment)["envelope"])
+        reopened = SealedRationaleStore(b"vault-key", self.roster, SECRETS.get,
+                                        self.engine.ledger, storage=legacy,
+                                        constitution_hash=profile)
+        self.assertEqual(reopened.commitments, [commitment])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,7 @@
+from .verifier import DryRunError, DryRunVerdict, dry_run
+
+__all__ = [
+    "DryRunError",
+    "DryRunVerdict",
+    "dry_run",
+]
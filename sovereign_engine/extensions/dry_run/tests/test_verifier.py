@@ -0,0 +1,55 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.dry_run import dry_run
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class TestDryRun(unittest.TestCase):
+    def setUp(self):
+        self.calls = []
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot(handlers={
+            "operator": lambda sig: self.calls.append(sig.id) or {"outcome": "done"},
+        })
+
+    def _snapshot(self):
+        e = self.engine
+        return (
+            e.ledger.length, dict(e.engine_stats), vars(e.gate.stats).copy(),
+            vars(e.router.stats).copy(), len(e.gate.containment_log),
+        )
+
+    def test_legal_signal_reports_route_without_running_handler(self):
+        sig = self.engine.create_signal("command", "operational", "operator", {"x": 1})
+        before = self._snapshot()
+        verdict = dry_run(self.engine, sig)
+        self.assertTrue(verdict.would_pass)
+        self.assertEqual(verdict.target, "operator")
+        self.assertFalse(verdict.authoritative)
+        self.assertEqual(self._snapshot(), before)
+        self.assertEqual(self.calls, [])
+        self.assertFalse(sig.routed)
+
+    def test_illegal_signal_reports_violations_without_containment(self):
+        sig = self.engine.create_signal("escalation", "operational", "operator", {})
+        before = self._snapshot()
+        verdict = dry_run(self.engine, sig)
+        self.assertFalse(verdict.legal)
+        self.assertIn("silent_authority_escalation", [v["rule"] for v in verdict.violations])
+        self.assertEqual(self._snapshot(), before)
+
+    def test_halt_signal_predicts_halt_without_halting(self):
+        sig = self.engine.create_signal("halt", "constitutional", "steward", {}, source="ops")
+        verdict = dry_run(self.engine, sig)
+        self.assertTrue(verdict.would_halt)
+        self.assertFalse(self.engine.is_halted)
+        self.assertFalse(self.engine.router.is_halted)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,119 @@
+"""
+Dry Run - Read-only "would this pass?" evaluation of a signal.
+S3-EXT-023: Operator sanity bolt-on.
+
+Operators want to know whether a signal would clear the legality gate and
+where the router would send it, without the answer itself changing
+anything. A dry run replays the gate and routing rules against shadow
+copies: no gate or router stats move, no containment event is logged, no
+handler runs, nothing is written to the ledger and no halt is raised. The
+verdict is marked non-authoritative — it is advice, never a decision.
+
+Custom legality rules are re-evaluated against the shadow gate, so they
+must be pure for the dry run to be side-effect free. Handler failures
+cannot be predicted; a verdict of "routed" assumes the handler succeeds.
+
+Dependency: Phase 2 (router), Phase 3 (legality), Phase 8 (engine)
+"""
+
+from __future__ import annotations
+
+import copy
+from dataclasses import dataclass, field
+from typing import Dict, List, Optional
+
+from ...core.phase1_signals import Signal
+from ...core.phase2_router import AuthorityHandler, Router
+from ...core.phase3_legality import LegalityGate
+
+
+class DryRunError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class DryRunVerdict:
+    signal_id: str
+    legal: bool
+    violations: List[Dict[str, str]] = field(default_factory=list)
+    action: Optional[str] = None     # routed | escalated | halt | system_halt | rejected
+    target: Optional[str] = None
+    reason: Optional[str] = None
+    authoritative: bool = False
+
+    @property
+    def would_pass(self) -> bool:
+        return self.legal and self.action in ("routed", "escalated", "system_processed")
+
+    @property
+    def would_halt(self) -> bool:
+        return self.action in ("halt", "system_halt")
+
+    def to_dict(self) -> dict:
+        return {
+            "signal_id": self.signal_id,
+            "legal": self.legal,
+            "violations": list(self.violations),
+            "action": self.action,
+            "target": self.target,
+            "reason": self.reason,
+            "authoritative": self.authoritative,
+            "would_pass": self.would_pass,
+        }
+
+
+def _shadow_gate(engine) -> LegalityGate:
+    gate = LegalityGate(engine.constitution, engine.factory)
+    # The kernel exposes no accessor for custom rules and is locked; read
+    # them without mutating the live gate.
+    for name, fn in engine.gate._custom_rules:
+        gate.add_rule(name, fn)
+    return gate
+
+
+def _shadow_router(engine) -> Router:
+    router = Router(engine.constitution)
+    for level, live in engine.router._handlers.items():
+        shadow = AuthorityHandler(level, live.jurisdiction)
+        shadow.set_handler(lambda sig: {"outcome": "dry_run"})
+        if not live.is_active:
+            shadow.deactivate()
+        router.register_handler(level, shadow)
+    if engine.router.is_halted:
+        router.halt("live router halted")
+    return router
+
+
+def dry_run(engine, signal: Signal, context: Optional[dict] = None) -> DryRunVerdict:
+    """
+    Evaluate a signal through legality and routing without side effects.
+
+    INVARIANT: Engine, gate, router, handlers and ledger are unchanged.
+    INVARIANT: The verdict is never authoritative.
+    """
+    if not engine.is_booted:
+        raise DryRunError("Engine not booted")
+
+    if engine.is_halted and signal.type != "halt":
+        return DryRunVerdict(
+            signal_id=signal.id, legal=False, action="rejected",
+            reason="Engine halted",
+        )
+
+    shadow = copy.deepcopy(signal)
+    legality = _shadow_gate(engine).check(shadow, dict(context or {}))
+    violations = [{"rule": v.rule, "reason": v.reason} for v in legality.violations]
+    if not legality.legal:
+        return DryRunVerdict(
+            signal_id=signal.id, legal=False, violations=violations,
+            reason="Terminated at legality gate",
+        )
+
+    decision = _shadow_router(engine).route(shadow)
+    return DryRunVerdict(
+        signal_id=signal.id,
+        legal=True,
+        action=decision.action,
+        target=decision.target,
+        reason=decision.reason,
+    )
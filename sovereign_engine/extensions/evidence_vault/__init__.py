@@ -1,6 +1,20 @@
 """
 Evidence Vault - Immutable evidence storage with hash manifests.
 S3-EXT-004: Critical evidence management bolt-on.
+
+Evidence is deduplicated by content hash (type + data, not metadata or
+time), so overlapping submissions resolve to one stored item. Items are
+never deleted; a later item may supersede an earlier one with a recorded
+justification, and the canon root covers only the current, deduplicated
+set so the same fact is never counted twice.
+
+A vault written before the content index existed has its index rebuilt
+from the chain on open; where it holds the same content twice, the
+earliest item stands for it.
+
+The vault directory carries a version stamp (stamp.json) covering every
+file in it. It is checked on open, before anything else is read; a vault
+written before stamping reads as format 0 and is stamped on first open.
 """
 
 import json
@@ -10,15 +24,44 @@ from datetime import datetime
 from typing import Dict, List, Optional
 import base64
 
+from ..storage.versioning import (
+    MIGRATIONS,
+    UNBOUND_PROFILE,
+    VersionError,
+    VersionStamp,
+    check_stamp,
+    current_stamp,
+)
+
+ARTIFACT = "evidence_vault"
+
+
+def evidence_content_hash(evidence_type: str, data: Dict) -> str:
+    """Hash of the facts alone — metadata and timestamps excluded."""
+    record = {"type": evidence_type, "data": data}
+    return hashlib.sha256(json.dumps(record, sort_keys=True).encode()).hexdigest()
+
+
+def canon_root_of(content_hashes: List[str]) -> str:
+    """Canon root over a set of content hashes; order and repeats do not matter."""
+    return hashlib.sha256("".join(sorted(set(content_hashes))).encode()).hexdigest()
+
+
 class EvidenceVault:
-    def __init__(self, vault_path: str = "./vault"):
+    def __init__(self, vault_path: str = "./vault",
+                 constitution_hash: str = UNBOUND_PROFILE,
+                 accept_profile_change: bool = False):
         self.vault_path = vault_path
         self.chain_file = os.path.join(vault_path, "chain.json")
-        self._initialize_vault()
+        self.index_file = os.path.join(vault_path, "content_index.json")
+        self.supersession_file = os.path.join(vault_path, "supersessions.json")
+        self.stamp_file = os.path.join(vault_path, "stamp.json")
+        self._initialize_vault(constitution_hash, accept_profile_change)
     
-    def _initialize_vault(self):
+    def _initialize_vault(self, constitution_hash: str, accept_profile_change: bool):
         """Initialize the evidence vault."""
         os.makedirs(self.vault_path, exist_ok=True)
+        self._check_stamp(constitution_hash, accept_profile_change)
         
         if not os.path.exists(self.chain_file):
             # Create genesis block
@@ -30,10 +73,48 @@ class EvidenceVault:
                 "description": "Evidence Vault Genesis Block"
             }
             self._write_chain([genesis])
+        if not os.path.exists(self.index_file):
+            self._write_json(self.index_file, self._index_from_chain())
+        if not os.path.exists(self.supersession_file):
+            self._write_json(self.supersession_file, {})
+
+    def _check_stamp(self, constitution_hash: str, accept_profile_change: bool):
+        stamp = None
+        if os.path.exists(self.stamp_file):
+            stamp = VersionStamp.from_dict(self._read_json(self.stamp_file))
+        problems = check_stamp(stamp, ARTIFACT, constitution_hash, accept_profile_change)
+        if problems:
+            raise VersionError(f"{self.vault_path}: " + "; ".join(problems))
+        MIGRATIONS.upgrade(ARTIFACT, stamp, None)
+        if constitution_hash == UNBOUND_PROFILE and stamp is not None:
+            constitution_hash = stamp.constitution_hash
+        self._write_json(self.stamp_file, current_stamp(ARTIFACT, constitution_hash).to_dict())
+
+    @property
+    def version_stamp(self) -> VersionStamp:
+        return VersionStamp.from_dict(self._read_json(self.stamp_file))
+
+    def _index_from_chain(self) -> Dict[str, str]:
+        """Content index of every evidence item already on the chain."""
+        index: Dict[str, str] = {}
+        for block in self._read_chain()[1:]:
+            if block.get("type") == "supersession":
+                continue
+            index.setdefault(self.content_hash_of(block["evidence_id"]), block["evidence_id"])
+        return index
     
     def store_evidence(self, evidence_type: str, data: Dict, 
                       metadata: Optional[Dict] = None) -> str:
-        """Store evidence and return evidence ID."""
+        """Store evidence and return evidence ID.
+
+        Identical content (same type and data) returns the existing ID
+        without a new chain block.
+        """
+        content_hash = self.content_hash(evidence_type, data)
+        index = self._read_json(self.index_file)
+        if content_hash in index:
+            return index[content_hash]
+
         # Generate evidence ID
         evidence_id = self._generate_id(evidence_type, data)
         
@@ -43,7 +124,8 @@ class EvidenceVault:
             "type": evidence_type,
             "timestamp": datetime.now().isoformat(),
             "data": data,
-            "metadata": metadata or {}
+            "metadata": metadata or {},
+            "content_hash": content_hash
         }
         
         # Calculate hash
@@ -62,8 +144,79 @@ class EvidenceVault:
         
         # Generate manifest
         self._generate_manifest(evidence_id)
+
+        index[content_hash] = evidence_id
+        self._write_json(self.index_file, index)
         
         return evidence_id
+
+    def content_hash(self, evidence_type: str, data: Dict) -> str:
+        """Hash of the facts alone — metadata and timestamps excluded."""
+        return evidence_content_hash(evidence_type, data)
+
+    def supersede(self, old_id: str, new_id: str, justification: str) -> Dict:
+        """Record that new_id replaces old_id. Neither item is removed."""
+        if old_id == new_id:
+            raise ValueError("Evidence cannot supersede itself")
+        if not justification:
+            raise ValueError("Supersession requires a justification")
+        self.retrieve_evidence(old_id)
+        self.retrieve_evidence(new_id)
+
+        supersessions = self._read_json(self.supersession_file)
+        if old_id in supersessions:
+            raise ValueError(
+                f"Evidence {old_id} already superseded by {supersessions[old_id]['by']}"
+            )
+        if self.current_version(new_id) == old_id:
+            raise ValueError("Supersession would create a cycle")
+
+        record = {
+            "by": new_id,
+            "justification": justification,
+            "timestamp": datetime.now().isoformat()
+        }
+        supersessions[old_id] = record
+        self._write_json(self.supersession_file, supersessions)
+        self._add_to_chain(
+            f"{old_id}->{new_id}",
+            self._hash_record({"supersedes": old_id, **record}),
+            "supersession"
+        )
+        return record
+
+    def current_version(self, evidence_id: str) -> str:
+        """Follow supersessions to the item that currently stands."""
+        supersessions = self._read_json(self.supersession_file)
+        seen = set()
+        while evidence_id in supersessions and evidence_id not in seen:
+            seen.add(evidence_id)
+            evidence_id = supersessions[evidence_id]["by"]
+        return evidence_id
+
+    def evidence_ids(self) -> List[str]:
+        """Every stored item, superseded or not."""
+        return sorted(self._read_json(self.index_file).values())
+
+    def active_evidence(self) -> List[str]:
+        """Stored items that have not been superseded."""
+        supersessions = self._read_json(self.supersession_file)
+        index = self._read_json(self.index_file)
+        return sorted(eid for eid in index.values() if eid not in supersessions)
+
+    def canon_root(self, evidence_ids: Optional[List[str]] = None) -> str:
+        """Root over the current, deduplicated evidence set.
+
+        Superseded IDs resolve to their successor and duplicates collapse,
+        so two references to the same fact contribute one hash.
+        """
+        return canon_root_of(self.canon_leaves(evidence_ids))
+
+    def canon_leaves(self, evidence_ids: Optional[List[str]] = None) -> List[str]:
+        """Sorted, deduplicated content hashes that `canon_root` is taken over."""
+        ids = self.active_evidence() if evidence_ids is None else evidence_ids
+        current = sorted({self.current_version(eid) for eid in ids})
+        return sorted({self.content_hash_of(eid) for eid in current})
     
     def retrieve_evidence(self, evidence_id: str) -> Dict:
         """Retrieve evidence by ID."""
@@ -126,6 +279,12 @@ class EvidenceVault:
         chain.append(new_block)
         self._write_chain(chain)
     
+    def content_hash_of(self, evidence_id: str) -> str:
+        """Content hash a stored item is indexed and canonised under."""
+        record = self.retrieve_evidence(evidence_id)["record"]
+        # Items stored before dedup existed carry no content hash.
+        return record.get("content_hash") or self.content_hash(record["type"], record["data"])
+
     def _generate_manifest(self, evidence_id: str):
         """Generate manifest for single evidence."""
         manifest = self.generate_manifest([evidence_id])
@@ -159,6 +318,14 @@ class EvidenceVault:
         with open(self.chain_file, 'w') as f:
             json.dump(chain, f, indent=2)
 
+    def _read_json(self, path: str) -> Dict:
+        with open(path, 'r') as f:
+            return json.load(f)
+
+    def _write_json(self, path: str, data: Dict):
+        with open(path, 'w') as f:
+            json.dump(data, f, indent=2, sort_keys=True)
+
 # Merge Gate evidence storage
 def store_merge_evidence(vault: EvidenceVault, pr_data: Dict, 
                         validation_results: Dict) -> str:
@@ -0,0 +1,81 @@
+import json
+import os
+import tempfile
+import unittest
+
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.storage import VersionError
+
+
+class TestEvidenceDedup(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.vault = EvidenceVault(self._tmp.name)
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def _chain_length(self):
+        with open(self.vault.chain_file) as f:
+            return len(json.load(f))
+
+    def test_identical_content_is_stored_once(self):
+        a = self.vault.store_evidence("log", {"line": 1}, {"source": "node-a"})
+        length = self._chain_length()
+        b = self.vault.store_evidence("log", {"line": 1}, {"source": "node-b"})
+        self.assertEqual(a, b)
+        self.assertEqual(self._chain_length(), length)
+        self.assertNotEqual(a, self.vault.store_evidence("log", {"line": 2}))
+
+    def test_supersession_is_chained_and_resolves(self):
+        old = self.vault.store_evidence("report", {"v": 1})
+        new = self.vault.store_evidence("report", {"v": 2})
+        self.vault.supersede(old, new, "corrected figures")
+        self.assertEqual(self.vault.current_version(old), new)
+        self.assertEqual(self.vault.active_evidence(), [new])
+        self.assertTrue(self.vault.verify_evidence(old))
+        with self.assertRaises(ValueError):
+            self.vault.supersede(new, old, "revert")
+        with self.assertRaises(ValueError):
+            self.vault.supersede(old, new, "again")
+
+    def test_canon_root_does_not_double_count(self):
+        old = self.vault.store_evidence("report", {"v": 1})
+        new = self.vault.store_evidence("report", {"v": 2})
+        other = self.vault.store_evidence("log", {"line": 1})
+        self.vault.supersede(old, new, "corrected figures")
+        self.assertEqual(
+            self.vault.canon_root([old, new, other, other]),
+            self.vault.canon_root([new, other]),
+        )
+        self.assertEqual(self.vault.canon_root(), self.vault.canon_root([new, other]))
+
+    def test_vault_without_index_is_backfilled_from_chain(self):
+        a = self.vault.store_evidence("log", {"line": 1})
+        b = self.vault.store_evidence("log", {"line": 2})
+        os.remove(self.vault.index_file)
+        reopened = EvidenceVault(self._tmp.name)
+        self.assertEqual(reopened.evidence_ids(), sorted([a, b]))
+        self.assertEqual(reopened.active_evidence(), sorted([a, b]))
+        self.assertEqual(reopened.store_evidence("log", {"line": 1}), a)
+
+    def test_vault_is_stamped_and_checked_on_open(self):
+        self.assertEqual(self.vault.version_stamp.constitution_hash, "")
+        profile = "ab" * 32
+        bound = EvidenceVault(self._tmp.name, constitution_hash=profile)
+        self.assertEqual(bound.version_stamp.constitution_hash, profile)
+        self.assertEqual(EvidenceVault(self._tmp.name).version_stamp.constitution_hash, profile)
+        with self.assertRaises(VersionError):
+            EvidenceVault(self._tmp.name, constitution_hash="cd" * 32)
+
+        with open(self.vault.stamp_file) as f:
+            stamp = json.load(f)
+        stamp["format_version"] = 99
+        with open(self.vault.stamp_file, "w") as f:
+            json.dump(stamp, f)
+        with self.assertRaises(VersionError):
+            EvidenceVault(self._tmp.name)
+
+
+if __name__ == "__main__":
+    unittest.main()
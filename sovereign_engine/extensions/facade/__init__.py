@@ -0,0 +1,7 @@
+from .sovereign import FacadeError, Sovereign, SovereignBuilder
+
+__all__ = [
+    "FacadeError",
+    "Sovereign",
+    "SovereignBuilder",
+]
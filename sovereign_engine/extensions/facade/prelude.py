@@ -0,0 +1,38 @@
+"""
+Prelude - One import for the names a minimal integration needs.
+
+    from sovereign_engine.extensions.facade.prelude import *
+
+Re-exports only; nothing here has behaviour of its own.
+"""
+
+from ...core.phase1_signals import Signal
+from ...core.phase8_engine import EngineError, SovereignEngine
+from ..anchoring import AnchorQueue, FileAnchorBackend, HttpAnchorBackend
+from ..dry_run import DryRunVerdict, dry_run
+from ..forensics import ForensicsBundle
+from ..genesis import GenesisBuilder, GenesisRecord
+from ..quorum import MemberState, QuorumRoster, attest
+from ..self_audit import self_audit
+from .sovereign import FacadeError, Sovereign, SovereignBuilder
+
+__all__ = [
+    "AnchorQueue",
+    "DryRunVerdict",
+    "EngineError",
+    "FacadeError",
+    "FileAnchorBackend",
+    "ForensicsBundle",
+    "GenesisBuilder",
+    "GenesisRecord",
+    "HttpAnchorBackend",
+    "MemberState",
+    "QuorumRoster",
+    "Signal",
+    "Sovereign",
+    "SovereignBuilder",
+    "SovereignEngine",
+    "attest",
+    "dry_run",
+    "self_audit",
+]
@@ -0,0 +1,229 @@
+"""
+Sovereign Facade - Builder that wires a booted engine with its bolt-ons.
+S3-EXT-024: Integration ergonomics bolt-on.
+
+A minimal deployment needs the engine, a quorum roster that alerts through
+the engine, and an anchor queue fed from the ledger head. Wiring those by
+hand takes a dozen imports and is easy to get subtly wrong (a roster with
+no ledger attached audits nothing). The facade does the wiring once with
+constitutional defaults and hands back the same components, unwrapped.
+
+With a key directory configured, the directory is seeded from the genesis
+record and becomes the resolver for every path the facade wires that was
+not handed one explicitly, so a revoked or expired key stops verifying
+there without re-wiring anything.
+
+Dependency: Phase 7 (archetype), Phase 8 (engine), S3-EXT-003, S3-EXT-019, S3-EXT-036
+"""
+
+from __future__ import annotations
+
+import os
+from typing import Any, Callable, Dict, Iterable, Optional
+
+from ...core.phase1_signals import Signal
+from ...core.phase8_engine import SovereignEngine
+from ..anchoring import AnchorBackend, AnchorQueue
+from ..dry_run import DryRunVerdict, dry_run
+from ..forensics import ForensicsBundle
+from ..keys import KeyDirectory
+from ..quorum import QuorumRoster
+from ..self_audit import SelfAuditReport, self_audit
+from ..status import SovereignStatus
+
+
+DEFAULT_CONSTITUTION = os.path.join(
+    os.path.dirname(__file__), "..", "..", "configs", "constitution.json"
+)
+
+
+class FacadeError(Exception):
+    pass
+
+
+class SovereignBuilder:
+    """Collects wiring choices; `build()` boots and connects everything."""
+
+    def __init__(self):
+        self._constitution = DEFAULT_CONSTITUTION
+        self._handlers: Dict[str, Callable] = {}
+        self._archetype = "managerial"
+        self._genesis = None
+        self._genesis_resolver: Optional[Callable[[str], bytes]] = None
+        self._quorum: Optional[dict] = None
+        self._anchor_backend: Optional[AnchorBackend] = None
+        self._keystore: Optional[Callable[[str], Optional[bytes]]] = None
+        self._key_directory_options: Dict[str, Any] = {}
+
+    def with_constitution(self, path: str) -> "SovereignBuilder":
+        self._constitution = path
+        return self
+
+    def with_handlers(self, handlers: Dict[str, Callable]) -> "SovereignBuilder":
+        self._handlers = dict(handlers)
+        return self
+
+    def with_archetype(self, name: str) -> "SovereignBuilder":
+        self._archetype = name
+        return self
+
+    def with_genesis(self, record,
+                     secret_resolver: Optional[Callable[[str], bytes]] = None
+                     ) -> "SovereignBuilder":
+        """
+        Boot from a genesis record; its constitution, archetype and quorum
+        members win. The record is verified against its signer's key,
+        resolved through `secret_resolver` (or the key directory's
+        keystore), before anything boots.
+        """
+        self._genesis = record
+        self._genesis_resolver = secret_resolver
+        self._constitution = record.constitution_path
+        self._archetype = record.archetype
+        return self
+
+    def with_key_directory(self, keystore: Callable[[str], Optional[bytes]],
+                           **options) -> "SovereignBuilder":
+        """
+        Seed a KeyDirectory from the genesis record once the engine boots,
+        auditing to its ledger. `options` go to the directory constructor.
+        """
+        self._keystore = keystore
+        self._key_directory_options = dict(options)
+        return self
+
+    def with_quorum(self, members: Iterable[str],
+                    secret_resolver: Optional[Callable[[str], bytes]] = None,
+                    backups: Iterable[str] = (),
+                    attestations_required: int = 2) -> "SovereignBuilder":
+        self._quorum = {
+            "members": list(members),
+            "secret_resolver": secret_resolver,
+            "backups": list(backups),
+            "attestations_required": attestations_required,
+        }
+        return self
+
+    def with_anchor_backend(self, backend: AnchorBackend) -> "SovereignBuilder":
+        self._anchor_backend = backend
+        return self
+
+    def build(self) -> "Sovereign":
+        if self._keystore is not None and self._genesis is None:
+            raise FacadeError("A key directory is seeded from genesis; configure one")
+        if self._genesis is not None:
+            resolver = self._genesis_resolver or self._keystore
+            if resolver is None:
+                raise FacadeError("Genesis needs a secret resolver or a key directory")
+            engine = self._genesis.boot_engine(resolver, self._handlers)
+        else:
+            engine = SovereignEngine(self._constitution)
+            engine.boot(self._handlers)
+
+        archetype = engine.configurator.compile(self._archetype)
+        if not archetype.valid:
+            raise FacadeError(
+                f"Archetype '{self._archetype}' failed validation: {archetype.violations}"
+            )
+
+        keys = None
+        if self._keystore is not None:
+            keys = KeyDirectory.from_genesis(self._genesis, self._keystore, ledger=engine.ledger,
+                                             **self._key_directory_options)
+
+        roster = None
+        if self._quorum is not None:
+            resolver = self._quorum["secret_resolver"]
+            if resolver is None and keys is not None:
+                resolver = keys.resolver("quorum_member")
+            if resolver is None:
+                raise FacadeError("Quorum needs a secret resolver or a key directory")
+            if archetype.steward_mode != "quorum":
+                raise FacadeError(
+                    f"Quorum requested but archetype '{archetype.name}' is "
+                    f"{archetype.steward_mode}-stewarded"
+                )
+            wiring = dict(
+                backups=self._quorum["backups"],
+                attestations_required=self._quorum["attestations_required"],
+                ledger=engine.ledger,
+                emit=lambda alert: engine.submit_and_process(
+                    alert["type"], alert["domain"], alert["authority"],
+                    alert["payload"], source=alert["source"],
+                ),
+            )
+            if self._genesis is not None:
+                if set(self._quorum["members"]) != set(self._genesis.quorum_members):
+                    raise FacadeError("Quorum members differ from the genesis record")
+                # Checks every member key against its genesis commitment.
+                roster = self._genesis.build_roster(resolver, **wiring)
+            else:
+                roster = QuorumRoster.from_archetype(
+                    archetype, self._quorum["members"], resolver, **wiring,
+                )
+
+        anchors = None
+        if self._anchor_backend is not None:
+            anchors = AnchorQueue(
+                self._anchor_backend, ledger=engine.ledger,
+                emit=lambda alert: engine.submit_and_process(
+                    alert["type"], alert["domain"], alert["authority"],
+                    alert["payload"], source=alert["source"],
+                ),
+            )
+
+        return Sovereign(engine, archetype, roster, anchors, self._genesis, keys)
+
+
+class Sovereign:
+    """
+    A wired deployment. Components stay reachable as attributes; the
+    facade adds shortcuts, never a second path around the kernel.
+    """
+
+    def __init__(self, engine: SovereignEngine, archetype, roster=None,
+                 anchors: Optional[AnchorQueue] = None, genesis=None,
+                 keys: Optional[KeyDirectory] = None):
+        self.engine = engine
+        self.archetype = archetype
+        self.roster = roster
+        self.anchors = anchors
+        self.genesis = genesis
+        self.keys = keys
+
+    @staticmethod
+    def builder() -> SovereignBuilder:
+        return SovereignBuilder()
+
+    def submit(self, type: str, domain: str, authority: str, payload: Any,
+               context: Optional[dict] = None, **kwargs) -> dict:
+        return self.engine.submit_and_process(type, domain, authority, payload,
+                                              context, **kwargs)
+
+    def signal(self, type: str, domain: str, authority: str, payload: Any,
+               **kwargs) -> Signal:
+        return self.engine.create_signal(type, domain, authority, payload, **kwargs)
+
+    def dry_run(self, signal: Signal, context: Optional[dict] = None) -> DryRunVerdict:
+        return dry_run(self.engine, signal, context)
+
+    def self_audit(self, signer_id: str, secret: bytes, **kwargs) -> SelfAuditReport:
+        kwargs.setdefault("genesis", self.genesis)
+        return self_audit(self.engine, signer_id, secret, **kwargs)
+
+    def capture_forensics(self, **kwargs) -> ForensicsBundle:
+        return ForensicsBundle.capture(self.engine, **kwargs)
+
+    def status(self, **kwargs) -> SovereignStatus:
+        return SovereignStatus.collect(self.engine, roster=self.roster,
+                                       anchors=self.anchors, **kwargs)
+
+    def anchor_head(self):
+        if self.anchors is None:
+            raise FacadeError("No anchor backend configured")
+        self.anchors.enqueue_ledger_head(self.engine.ledger)
+        return self.anchors.flush()
+
+    @property
+    def is_halted(self) -> bool:
+        return self.engine.is_halted
@@ -0,0 +1,165 @@
+"""
+End-to-end constitutional lifecycle.
+
+Unit tests check each bolt-on against a bare engine. This walks one
+deployment through the whole story, so the seams between bolt-ons are
+exercised with real data: genesis, a run of routed signals, an anchored
+head, a constitutional halt (reported twice, logged once), forensics, dual-key recovery, quorum
+attrition, a signed state report, museum proofs, an archive audit and a
+restart from durable storage. The invariant monitor runs strict
+throughout, so any cross-module inconsistency fails the test where it
+happens.
+"""
+
+import os
+import tempfile
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.advisories import WarningLedger
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.facade import Sovereign
+from sovereign_engine.extensions.facade.prelude import (
+    FileAnchorBackend,
+    MemberState,
+    attest,
+)
+from sovereign_engine.extensions.genesis import REPO_ROOT, GenesisBuilder, key_commitment
+from sovereign_engine.extensions.hardening import InvariantMonitor
+from sovereign_engine.extensions.museum import ArchiveAudit, Museum, verify_inclusion
+from sovereign_engine.extensions.recovery import (
+    HaltLog,
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    halt_id,
+    sign_review,
+)
+from sovereign_engine.extensions.security import compute_kernel_fingerprint
+from sovereign_engine.extensions.status import build_state_report, verify_state_report
+from sovereign_engine.extensions.storage import DurableState, FileStorage
+
+MEMBERS = ["m1", "m2", "m3", "m4"]
+STEWARDS = ["s1", "s2"]
+SECRETS = {k: f"secret-{k}".encode() for k in MEMBERS + STEWARDS + ["b1"]}
+KERNEL = compute_kernel_fingerprint(REPO_ROOT)
+STREAK = 100
+STORAGE_KEY = b"storage-key"
+
+
+def _walk(session, bundle_hash):
+    """Perform whatever the playbook asks next, with every steward signing reviews."""
+    while session.next_action is not None:
+        action = session.next_action
+        if action in (RecoveryAction.STEWARD_REVIEW, RecoveryAction.CONSTITUTIONAL_REVIEW):
+            sigs = {s: sign_review(SECRETS[s], session.halt_id, action, "reviewed")
+                    for s in STEWARDS}
+            session.perform(action, "reviewed", sigs)
+        elif action == RecoveryAction.CAPTURE_FORENSICS:
+            session.perform(action, bundle_hash)
+        else:
+            session.perform(action)
+
+
+class TestConstitutionalLifecycle(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.tmp = self._tmp.name
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def test_genesis_to_museum(self):
+        # Genesis and wiring.
+        genesis = (
+            GenesisBuilder()
+            .with_archetype("federated")
+            .with_quorum_members({m: key_commitment(SECRETS[m]) for m in MEMBERS})
+            .with_stewards({s: key_commitment(SECRETS[s]) for s in STEWARDS})
+            .with_kernel_fingerprint(KERNEL)
+            .build("s1", SECRETS["s1"])
+        )
+        sov = (Sovereign.builder()
+               .with_genesis(genesis, SECRETS.get)
+               .with_quorum(MEMBERS, SECRETS.get, backups=["b1"])
+               .with_anchor_backend(FileAnchorBackend(os.path.join(self.tmp, "anchors.log")))
+               .build())
+        engine = sov.engine
+        halt_log = HaltLog(engine).attach()
+        storage = FileStorage(os.path.join(self.tmp, "state"))
+        DurableState(engine, storage, STORAGE_KEY).attach()
+        monitor = InvariantMonitor(engine, roster=sov.roster, strict=True)
+        monitor.attach()
+        vault = EvidenceVault(os.path.join(self.tmp, "vault"))
+        warnings = WarningLedger()
+
+        # A clean streak, then an externally anchored head.
+        for block in range(STREAK):
+            self.assertTrue(sov.submit("command", "operational", "operator",
+                                       {"block": block})["processed"])
+        [receipt] = sov.anchor_head()
+        anchored = (int(receipt.subject.split(":")[1]), receipt.digest)
+
+        # A constitutional breach halts everything.
+        warnings.record("router", "authority_drift", "warning")
+        engine.health.report_failure("router", "authority_breach", "operator lane bypassed")
+        engine.health.report_failure("router", "authority_breach", "operator lane bypassed")
+        self.assertTrue(sov.is_halted)
+        self.assertEqual(len(halt_log.halt_history), 1)
+        self.assertFalse(sov.submit("command", "operational", "operator", {})["processed"])
+
+        # Forensics into the vault, dual-key recovery, resume.
+        bundle = sov.capture_forensics(halt=halt_log.open_halt)
+        vault.store_evidence("forensics", {"bundle_hash": bundle.bundle_hash})
+        session = RecoverySession(
+            engine, RecoveryPlaybook.from_constitution(engine.constitution),
+            STEWARDS, SECRETS.get, halts=halt_log,
+        )
+        self.assertEqual(session.recovery_code, "full_constitutional_review")
+        engine.health.report_healthy("router")
+        _walk(session, bundle.bundle_hash)
+        self.assertFalse(sov.is_halted)
+        self.assertTrue(sov.submit("command", "operational", "operator", {"after": 1})["processed"])
+
+        # Attrition: a death promotes the backup, a second leaves a shortfall.
+        for member, attesters in [("m1", ["m2", "m3"]), ("m2", ["m3", "m4"])]:
+            atts = [attest(a, SECRETS[a], member, MemberState.ACTIVE, MemberState.DECEASED, "")
+                    for a in attesters]
+            sov.roster.transition(member, MemberState.DECEASED, atts, "")
+        self.assertEqual(sov.roster.state_of("b1"), MemberState.ACTIVE)
+        self.assertEqual(len(sov.roster.active_members), 3)
+
+        # The period's signed report, archived.
+        report = build_state_report(engine, genesis, 0.0, 4e9, "s2", SECRETS["s2"],
+                                    warnings=warnings, anchors=sov.anchors, vault=vault,
+                                    halts=halt_log)
+        self.assertEqual(verify_state_report(report, genesis, SECRETS.get), [])
+        self.assertEqual([h["halt_id"] for h in report.halts], [session.halt_id])
+        self.assertEqual(report.anchors["anchored"], 1)
+
+        # The museum serves the history with proofs, and the archive audits clean.
+        museum = Museum(engine.ledger, halt_log, vault)
+        self.assertEqual(museum.halts().items[0]["halt_id"], session.halt_id)
+        steps = museum.recovery_history(session.halt_id).items
+        self.assertEqual(steps[-1]["outcome"], "recovery_resume")
+        proof = museum.entry_with_proof(STREAK // 2)
+        self.assertTrue(verify_inclusion(proof, museum.head["head"]))
+        audit = ArchiveAudit(museum, engine.ledger, vault, ledger_challenges=16)
+        self.assertTrue(audit.run("lifecycle", anchored_head=anchored).passed)
+
+        # A restart from durable storage resumes the same history.
+        self.assertEqual(monitor.check(), [])
+        monitor.detach()
+        head, length = engine.ledger.last_hash, engine.ledger.length
+        restarted = SovereignEngine(genesis.constitution_path)
+        restarted.boot()
+        DurableState.restore(restarted, storage, STORAGE_KEY)
+        self.assertEqual(restarted.ledger.entries[length - 1].hash, head)
+        self.assertEqual(restarted.ledger.entries[-1].outcome, "ledger_restored")
+        self.assertFalse(restarted.is_halted)
+        restored_log = HaltLog(restarted).attach()
+        self.assertEqual(halt_id(restored_log.halt_history[-1]), session.halt_id)
+
+
+if __name__ == "__main__":
+    unittest.main()
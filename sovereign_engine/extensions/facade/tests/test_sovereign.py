@@ -0,0 +1,106 @@
+import os
+import tempfile
+import unittest
+
+from sovereign_engine.extensions.facade import FacadeError, Sovereign
+from sovereign_engine.extensions.facade.prelude import (
+    FileAnchorBackend,
+    GenesisBuilder,
+    MemberState,
+    attest,
+)
+from sovereign_engine.extensions.genesis import REPO_ROOT, GenesisError, key_commitment
+from sovereign_engine.extensions.keys import approve_revocation
+from sovereign_engine.extensions.quorum import QuorumError
+from sovereign_engine.extensions.security import compute_kernel_fingerprint
+
+MEMBERS = ["m1", "m2", "m3"]
+SECRETS = {m: f"secret-{m}".encode() for m in MEMBERS + ["s1", "s2"]}
+KERNEL = compute_kernel_fingerprint(REPO_ROOT)
+
+
+class TestSovereignFacade(unittest.TestCase):
+    def test_default_build_processes_signals(self):
+        sov = Sovereign.builder().build()
+        result = sov.submit("command", "operational", "operator", {"x": 1})
+        self.assertEqual(result["target"], "operator")
+        self.assertFalse(sov.dry_run(sov.signal("command", "operational", "operator", {})).authoritative)
+
+    def test_quorum_roster_is_audited_and_alerts_through_engine(self):
+        sov = (Sovereign.builder()
+               .with_archetype("federated")
+               .with_quorum(MEMBERS, SECRETS.get, attestations_required=1)
+               .build())
+        self.assertEqual(sov.roster.threshold, 2)
+        for member, attester in [("m1", "m2"), ("m2", "m3")]:
+            att = attest(attester, SECRETS[attester], member, MemberState.ACTIVE,
+                         MemberState.SABBATICAL, "leave")
+            sov.roster.transition(member, MemberState.SABBATICAL, [att], "leave")
+
+        self.assertFalse(sov.roster.quorum_achievable)
+        outcomes = [e.outcome for e in sov.engine.ledger.entries]
+        self.assertEqual(outcomes.count("member_sabbatical"), 2)
+        # The shortfall alert went through the engine, not around it.
+        self.assertEqual(sov.engine.engine_stats["signals_processed"], 1)
+        self.assertEqual(sov.engine.ledger.entries[-1].signal_domain, "emergency")
+
+    def test_quorum_refused_for_non_quorum_archetype(self):
+        with self.assertRaises(FacadeError):
+            Sovereign.builder().with_quorum(MEMBERS, SECRETS.get).build()
+
+    def test_genesis_is_verified_and_owns_the_roster(self):
+        genesis = (GenesisBuilder()
+                   .with_archetype("federated")
+                   .with_quorum_members({m: key_commitment(SECRETS[m]) for m in MEMBERS})
+                   .with_stewards({s: key_commitment(SECRETS[s]) for s in ("s1", "s2")})
+                   .with_kernel_fingerprint(KERNEL)
+                   .build("s1", SECRETS["s1"]))
+        sov = Sovereign.builder().with_genesis(genesis, SECRETS.get) \
+            .with_quorum(MEMBERS, SECRETS.get).build()
+        self.assertEqual(sov.roster.threshold, genesis.quorum_threshold)
+
+        forged = {**SECRETS, "s1": b"not-the-signer"}
+        with self.assertRaises(GenesisError):
+            Sovereign.builder().with_genesis(genesis, forged.get).build()
+        with self.assertRaises(FacadeError):
+            Sovereign.builder().with_genesis(genesis, SECRETS.get) \
+                .with_quorum(MEMBERS[:2] + ["m9"], SECRETS.get).build()
+        wrong_key = {**SECRETS, "m2": b"not-m2"}
+        with self.assertRaises(GenesisError):
+            Sovereign.builder().with_genesis(genesis, SECRETS.get) \
+                .with_quorum(MEMBERS, wrong_key.get).build()
+
+    def test_key_directory_resolves_quorum_keys(self):
+        genesis = (GenesisBuilder()
+                   .with_archetype("federated")
+                   .with_quorum_members({m: key_commitment(SECRETS[m]) for m in MEMBERS})
+                   .with_stewards({s: key_commitment(SECRETS[s]) for s in ("s1", "s2")})
+                   .with_kernel_fingerprint(KERNEL)
+                   .build("s1", SECRETS["s1"]))
+        sov = (Sovereign.builder().with_genesis(genesis)
+               .with_key_directory(SECRETS.get, revocation_threshold=1)
+               .with_quorum(MEMBERS, attestations_required=1).build())
+        self.assertIn("key_registered", [e.outcome for e in sov.engine.ledger.entries])
+
+        proposal = ("m1", MemberState.ACTIVE, MemberState.SABBATICAL, "leave")
+        sov.keys.revoke("m2", "quorum_member", "lost device",
+                        {"s1": approve_revocation("s1", SECRETS["s1"], "m2",
+                                                  "quorum_member", "lost device")})
+        with self.assertRaises(QuorumError):
+            sov.roster.transition("m1", MemberState.SABBATICAL,
+                                  [attest("m2", SECRETS["m2"], *proposal)], "leave")
+        sov.roster.transition("m1", MemberState.SABBATICAL,
+                              [attest("m3", SECRETS["m3"], *proposal)], "leave")
+
+    def test_anchor_head(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            sov = (Sovereign.builder()
+                   .with_anchor_backend(FileAnchorBackend(os.path.join(tmp, "anchors.jsonl")))
+                   .build())
+            receipts = sov.anchor_head()
+            self.assertEqual(len(receipts), 1)
+            self.assertTrue(sov.anchors.verify(receipts[0]))
+
+
+if __name__ == "__main__":
+    unittest.main()
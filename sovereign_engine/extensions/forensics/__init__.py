@@ -0,0 +1,23 @@
+from .bundle import BUNDLE_FORMAT, ForensicsBundle, ForensicsError
+from .review import (
+    REVIEW_ACTIONS,
+    ReviewSignature,
+    attestation_body,
+    inspect_bundle,
+    render_summary,
+    sign_bundle_review,
+    verify_review_signature,
+)
+
+__all__ = [
+    "BUNDLE_FORMAT",
+    "REVIEW_ACTIONS",
+    "ForensicsBundle",
+    "ForensicsError",
+    "ReviewSignature",
+    "attestation_body",
+    "inspect_bundle",
+    "render_summary",
+    "sign_bundle_review",
+    "verify_review_signature",
+]
@@ -0,0 +1,159 @@
+"""
+Forensics Bundle - Single adjudication artifact for a halt.
+S3-EXT-017: Halt forensics bolt-on.
+
+When the engine halts, the steward needs one artifact holding everything
+the halt decision was made on: the halt event, the tail of the audit
+ledger, watchdog and health state, timing breaches, containment events and
+failure events at the moment of capture. The bundle serialises canonically
+so its hash is reproducible, and that hash is committed to the audit ledger
+so the artifact cannot be swapped after the fact. The commit names the
+halt the bundle was captured for, so it cannot be cited for another.
+
+A bundle carries a version stamp naming its format and the constitutional
+profile it was captured under. Bundles captured before stamping read as
+format 0 and keep their original hash.
+
+Dependency: Phase 4 (audit), Phase 5 (halt), Phase 6 (failure), Phase 8 (engine)
+"""
+
+from __future__ import annotations
+
+import json
+import time
+from dataclasses import asdict, dataclass
+from typing import Any, Dict, List, Optional
+
+from ..recovery.playbook import kernel_halt_fields
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+from ..storage.versioning import (
+    MIGRATIONS,
+    UNBOUND_PROFILE,
+    VersionStamp,
+    check_stamp,
+    current_stamp,
+    profile_hash,
+)
+
+
+BUNDLE_FORMAT = "forensics-bundle/1"
+ARTIFACT = "forensics_bundle"
+
+
+class ForensicsError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class ForensicsBundle:
+    halt: Dict[str, Any]
+    ledger_tail: List[Dict[str, Any]]
+    ledger_head: Dict[str, Any]
+    watchdog: Dict[str, Any]
+    health: Dict[str, Any]
+    timing_breaches: List[Dict[str, Any]]
+    containment: List[Dict[str, Any]]
+    failures: List[Dict[str, Any]]
+    engine_stats: Dict[str, Any]
+    captured_at: float
+    format: str = BUNDLE_FORMAT
+    stamp: Optional[Dict[str, Any]] = None
+
+    @classmethod
+    def capture(cls, engine, halt: Optional[Dict[str, Any]] = None,
+                last_k: int = 50, commit: bool = True) -> "ForensicsBundle":
+        """
+        Capture the engine state around a halt.
+
+        `halt` defaults to the most recent entry in the halt history.
+        With `commit`, the bundle hash is written to the audit ledger.
+        """
+        if not engine.is_booted:
+            raise ForensicsError("Engine not booted")
+        if last_k < 1:
+            raise ForensicsError("last_k must be at least 1")
+
+        if halt is None:
+            history = engine.halt_ctrl.halt_history
+            if not history:
+                raise ForensicsError("No halt recorded — nothing to capture")
+            halt = history[-1]
+
+        entries = engine.ledger.entries
+        bundle = cls(
+            halt=dict(halt),
+            ledger_tail=[asdict(e) for e in entries[-last_k:]],
+            ledger_head={
+                "length": engine.ledger.length,
+                "last_hash": engine.ledger.last_hash,
+                "valid": engine.ledger.verify()["valid"],
+            },
+            watchdog={
+                name: asdict(state) for name, state in engine.watchdog.states.items()
+            },
+            health={
+                name: asdict(status) for name, status in engine.health.statuses.items()
+            },
+            timing_breaches=[asdict(b) for b in engine.timing.breaches],
+            containment=[asdict(c) for c in engine.gate.containment_log],
+            failures=[asdict(f) for f in engine.failure_matrix.event_log],
+            engine_stats=engine.engine_stats,
+            captured_at=time.time(),
+            stamp=current_stamp(ARTIFACT, profile_hash(engine.constitution)).to_dict(),
+        )
+
+        if commit:
+            engine.ledger.write({
+                "signal_type": "audit",
+                "route": "forensics",
+                "handler": "forensics",
+                "outcome": "forensics_bundle_captured",
+                "signal_id": bundle.bundle_hash,
+                "signal_domain": "constitutional",
+                "extra": canonical_json(kernel_halt_fields(bundle.halt)),
+            })
+
+        return bundle
+
+    @property
+    def version_stamp(self) -> Optional[VersionStamp]:
+        return VersionStamp.from_dict(self.stamp) if self.stamp else None
+
+    def to_dict(self) -> Dict[str, Any]:
+        data = asdict(self)
+        if data["stamp"] is None:
+            # Unstamped bundles hash exactly as they did when captured.
+            data.pop("stamp")
+        return data
+
+    def to_json(self) -> str:
+        """Canonical serialisation. Byte-identical for identical content."""
+        return canonical_json(self.to_dict())
+
+    @property
+    def bundle_hash(self) -> str:
+        return sha256_hex(self.to_json())
+
+    @classmethod
+    def from_json(cls, data: str, constitution_hash: str = UNBOUND_PROFILE,
+                  accept_profile_change: bool = False) -> "ForensicsBundle":
+        """
+        Parse a serialised bundle. With the reader's `constitution_hash`, a
+        bundle captured under another profile is refused unless accepted.
+        """
+        raw = json.loads(data)
+        if raw.get("format") != BUNDLE_FORMAT:
+            raise ForensicsError(f"Unsupported bundle format: {raw.get('format')}")
+        stamp = VersionStamp.from_dict(raw["stamp"]) if raw.get("stamp") else None
+        problems = check_stamp(stamp, ARTIFACT, constitution_hash, accept_profile_change)
+        if problems:
+            raise ForensicsError("; ".join(problems))
+        return cls(**MIGRATIONS.upgrade(ARTIFACT, stamp, raw))
+
+    def verify_against_ledger(self, ledger) -> bool:
+        """True if this exact bundle was committed to the given ledger."""
+        h = self.bundle_hash
+        return any(
+            e.outcome == "forensics_bundle_captured" and e.signal_id == h
+            for e in ledger.entries
+        )
@@ -0,0 +1,124 @@
+"""
+Bundle Review - Offline inspection and signing of a forensics bundle.
+
+A steward signing off on a halt should do it on a machine that holds
+their key and nothing else, looking at the bundle itself rather than at
+a dashboard that could be lying. This module is what that machine runs:
+it checks the bundle is internally consistent, renders it for a human,
+and produces a review signature bound to the halt ID, the review step
+and the bundle hash. The signature file's JSON is the body of an
+`attestation` message on the governance transport, and its fields map
+directly onto `RecoverySession.perform(action, evidence, signatures)`.
+
+`scripts/adjudicate.py` is the command-line front end.
+
+Dependency: S3-EXT-017 (forensics), S3-EXT-025 (recovery)
+"""
+
+from __future__ import annotations
+
+import json
+import time
+from dataclasses import asdict, dataclass
+from typing import Any, Callable, Dict, List
+
+from ..recovery.playbook import (
+    REVIEW_SIGNATURES,
+    RecoveryAction,
+    halt_id,
+    review_payload,
+    sign_review,
+)
+from ..security.zero_trust_evidence import verify_hmac
+from .bundle import ForensicsBundle
+
+REVIEW_ACTIONS = tuple(a.value for a in REVIEW_SIGNATURES)
+
+
+def inspect_bundle(bundle: ForensicsBundle) -> List[str]:
+    """Internal consistency problems. Empty means the bundle hangs together."""
+    problems: List[str] = []
+    tail = bundle.ledger_tail
+    for prev, entry in zip(tail, tail[1:]):
+        if entry.get("previous_hash") != prev.get("hash"):
+            problems.append(f"Ledger tail chain broken at entry {entry.get('index')}")
+    if tail and tail[-1].get("hash") != bundle.ledger_head.get("last_hash"):
+        problems.append("Ledger tail does not end at the recorded head")
+    if not bundle.ledger_head.get("valid", False):
+        problems.append("Ledger was reported invalid at capture")
+    if not bundle.halt.get("reason"):
+        problems.append("Halt record has no reason")
+    return problems
+
+
+def render_summary(bundle: ForensicsBundle) -> str:
+    halt = bundle.halt
+    unhealthy = sorted(n for n, s in bundle.health.items() if not s.get("healthy", True))
+    dead = sorted(n for n, s in bundle.watchdog.items() if not s.get("alive", True))
+    problems = inspect_bundle(bundle)
+    lines = [
+        "=" * 70,
+        "  FORENSICS BUNDLE",
+        "=" * 70,
+        f"  Bundle hash:   {bundle.bundle_hash}",
+        f"  Halt ID:       {halt_id(halt)}",
+        f"  Halt reason:   {halt.get('reason')}",
+        f"  Halt source:   {halt.get('source')}",
+        f"  Captured at:   {time.strftime('%Y-%m-%d %H:%M:%S', time.gmtime(bundle.captured_at))} UTC",
+        f"  Ledger:        {bundle.ledger_head.get('length')} entries, "
+        f"head {str(bundle.ledger_head.get('last_hash'))[:16]}…",
+        f"  Unhealthy:     {', '.join(unhealthy) or 'none'}",
+        f"  Watchdog dead: {', '.join(dead) or 'none'}",
+        f"  Failures:      {len(bundle.failures)}   Containment: {len(bundle.containment)}"
+        f"   Timing breaches: {len(bundle.timing_breaches)}",
+        "-" * 70,
+        "  Last ledger entries:",
+    ]
+    for e in bundle.ledger_tail[-5:]:
+        lines.append(f"    #{e.get('index')} {e.get('route')}/{e.get('outcome')}")
+    lines.append("-" * 70)
+    lines += [f"  PROBLEM: {p}" for p in problems] or ["  Consistency:   OK"]
+    lines.append("=" * 70)
+    return "\n".join(lines)
+
+
+@dataclass(frozen=True)
+class ReviewSignature:
+    halt_id: str
+    action: str
+    evidence: str          # the bundle hash
+    steward_id: str
+    signature: str
+
+    def to_json(self) -> str:
+        return json.dumps(asdict(self), indent=2, sort_keys=True)
+
+    @classmethod
+    def from_json(cls, data: str) -> "ReviewSignature":
+        return cls(**json.loads(data))
+
+
+def sign_bundle_review(bundle: ForensicsBundle, steward_id: str, secret: bytes,
+                       action: str = RecoveryAction.CONSTITUTIONAL_REVIEW.value) -> ReviewSignature:
+    """Sign a review step over this bundle. Refuses an inconsistent bundle."""
+    if action not in REVIEW_ACTIONS:
+        raise ValueError(f"Action must be one of {REVIEW_ACTIONS}")
+    problems = inspect_bundle(bundle)
+    if problems:
+        raise ValueError("Refusing to sign an inconsistent bundle: " + "; ".join(problems))
+    ref, evidence = halt_id(bundle.halt), bundle.bundle_hash
+    return ReviewSignature(ref, action, evidence, steward_id,
+                           sign_review(secret, ref, RecoveryAction(action), evidence))
+
+
+def verify_review_signature(review: ReviewSignature, bundle: ForensicsBundle,
+                            secret_resolver: Callable[[str], bytes]) -> bool:
+    if review.halt_id != halt_id(bundle.halt) or review.evidence != bundle.bundle_hash:
+        return False
+    payload = review_payload(review.halt_id, RecoveryAction(review.action), review.evidence)
+    return verify_hmac(secret_resolver(review.steward_id), payload, review.signature)
+
+
+def attestation_body(review: ReviewSignature) -> Dict[str, Any]:
+    """Body for an `attestation` GovernanceMessage carrying this review."""
+    return {"subject": "recovery_review", **asdict(review)}
@@ -0,0 +1,65 @@
+import json
+import os
+import unittest
+from dataclasses import replace
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.forensics import ForensicsBundle, ForensicsError
+from sovereign_engine.extensions.storage import profile_hash
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+def halted_engine():
+    engine = SovereignEngine(CONFIG_PATH)
+    engine.boot()
+    for i in range(5):
+        engine.submit_and_process("query", "operational", "operator", {"n": i})
+    engine.submit_and_process("halt", "operational", "system", {"reason": "lockdown"})
+    return engine
+
+
+class TestForensicsBundle(unittest.TestCase):
+    def test_capture_commits_hash_to_ledger(self):
+        engine = halted_engine()
+        bundle = ForensicsBundle.capture(engine, last_k=3)
+
+        self.assertEqual(bundle.halt["source"], "router")
+        self.assertEqual(len(bundle.ledger_tail), 3)
+        self.assertTrue(bundle.ledger_head["valid"])
+        self.assertIn("router", bundle.watchdog)
+        self.assertTrue(bundle.verify_against_ledger(engine.ledger))
+        self.assertTrue(engine.ledger.verify()["valid"])
+
+    def test_canonical_roundtrip_preserves_hash(self):
+        engine = halted_engine()
+        bundle = ForensicsBundle.capture(engine)
+        restored = ForensicsBundle.from_json(bundle.to_json())
+        self.assertEqual(restored.bundle_hash, bundle.bundle_hash)
+        self.assertTrue(restored.verify_against_ledger(engine.ledger))
+
+    def test_stamp_is_checked_and_unstamped_bundles_keep_their_hash(self):
+        engine = halted_engine()
+        bundle = ForensicsBundle.capture(engine)
+        profile = profile_hash(engine.constitution)
+        self.assertEqual(bundle.version_stamp.constitution_hash, profile)
+        ForensicsBundle.from_json(bundle.to_json(), constitution_hash=profile)
+        with self.assertRaises(ForensicsError):
+            ForensicsBundle.from_json(bundle.to_json(), constitution_hash="0" * 64)
+
+        legacy = replace(bundle, stamp=None)
+        self.assertNotIn("stamp", json.loads(legacy.to_json()))
+        restored = ForensicsBundle.from_json(legacy.to_json(), constitution_hash=profile)
+        self.assertEqual(restored.bundle_hash, legacy.bundle_hash)
+
+    def test_no_halt_refuses_capture(self):
+        engine = SovereignEngine(CONFIG_PATH)
+        engine.boot()
+        with self.assertRaises(ForensicsError):
+            ForensicsBundle.capture(engine)
+
+
+if __name__ == "__main__":
+    unittest.main()
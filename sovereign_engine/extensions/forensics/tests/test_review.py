@@ -0,0 +1,68 @@
+import dataclasses
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.forensics import (
+    ForensicsBundle,
+    ReviewSignature,
+    attestation_body,
+    inspect_bundle,
+    render_summary,
+    sign_bundle_review,
+    verify_review_signature,
+)
+from sovereign_engine.extensions.recovery import (
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+SECRETS = {"s1": b"steward-one", "s2": b"steward-two"}
+
+
+class TestBundleReview(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        for i in range(5):
+            self.engine.submit_and_process("query", "operational", "operator", {"i": i})
+        self.engine.health.report_failure("gate", "authority_breach", "unauthorised override")
+        self.bundle = ForensicsBundle.capture(self.engine)
+
+    def test_summary_renders_the_halt_and_passes_consistency(self):
+        self.assertEqual(inspect_bundle(self.bundle), [])
+        text = render_summary(self.bundle)
+        self.assertIn(self.bundle.bundle_hash, text)
+        self.assertIn("authority_breach", text)
+        self.assertIn("Consistency:   OK", text)
+
+    def test_signatures_from_the_file_drive_the_recovery_session(self):
+        session = RecoverySession(self.engine, RecoveryPlaybook.from_constitution(
+            self.engine.constitution), list(SECRETS), SECRETS.get)
+        session.perform(RecoveryAction.CAPTURE_FORENSICS, self.bundle.bundle_hash)
+        action = session.next_action
+        reviews = [ReviewSignature.from_json(
+            sign_bundle_review(self.bundle, s, SECRETS[s], action.value).to_json())
+            for s in SECRETS]
+        self.assertTrue(all(verify_review_signature(r, self.bundle, SECRETS.get) for r in reviews))
+        session.perform(action, reviews[0].evidence, {r.steward_id: r.signature for r in reviews})
+        self.assertEqual([s.action for s in session.completed][-1], action)
+        self.assertEqual(attestation_body(reviews[0])["halt_id"], session.halt_id)
+
+    def test_tampered_bundle_is_not_signed(self):
+        tail = list(self.bundle.ledger_tail)
+        tail[2] = {**tail[2], "hash": "0" * 64}
+        forged = dataclasses.replace(self.bundle, ledger_tail=tail)
+        self.assertTrue(inspect_bundle(forged))
+        with self.assertRaises(ValueError):
+            sign_bundle_review(forged, "s1", SECRETS["s1"])
+        review = sign_bundle_review(self.bundle, "s1", SECRETS["s1"])
+        self.assertFalse(verify_review_signature(review, forged, SECRETS.get))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,43 @@
+from .ceremony import (
+    CeremonyError,
+    KeyCeremony,
+    Publication,
+    possession_proof,
+)
+from .builder import (
+    REPO_ROOT,
+    GenesisBuilder,
+    GenesisError,
+    GenesisRecord,
+    constitution_hash,
+    key_commitment,
+)
+from .succession import (
+    SuccessionError,
+    SuccessionExport,
+    SuccessionExportBuilder,
+    SuccessionManifest,
+    missing_chunks,
+    read_succession_export,
+    verify_succession_export,
+)
+
+__all__ = [
+    "CeremonyError",
+    "KeyCeremony",
+    "Publication",
+    "possession_proof",
+    "REPO_ROOT",
+    "GenesisBuilder",
+    "GenesisError",
+    "GenesisRecord",
+    "constitution_hash",
+    "key_commitment",
+    "SuccessionError",
+    "SuccessionExport",
+    "SuccessionExportBuilder",
+    "SuccessionManifest",
+    "missing_chunks",
+    "read_succession_export",
+    "verify_succession_export",
+]
@@ -0,0 +1,309 @@
+"""
+Genesis Builder - Canonical, signed starting record for a deployment.
+S3-EXT-018: Genesis bolt-on.
+
+A deployment is more than a constitution file: it binds quorum member
+keys, steward dual-key holders, the archetype in force and the kernel
+fingerprint that was audited. The builder cross-validates all of them and
+emits one signed GenesisRecord; engine boot and quorum roster
+construction then start from that record instead of from hand-assembled
+parts and magic zero hashes. Boot recomputes the kernel fingerprint and
+refuses a kernel that is not the audited one.
+
+The record also carries an evidence root: the canon root of any archive
+carried into the deployment. It is attested by the signature only. Boot
+does not open a vault, and a vault's root moves as evidence is added, so
+nothing here checks a vault against it; an auditor who holds the original
+archive compares the two.
+
+Keys are never stored. The record carries SHA-256 commitments to each
+HMAC secret, and every secret presented later is checked against them.
+
+Dependency: Phase 0 (constitution), Phase 7 (archetype), Phase 8 (engine)
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Callable, Dict, Optional
+
+from ...core.phase0_constitution import Constitution, ConstitutionalError
+from ...core.phase4_audit import GENESIS_HASH
+from ...core.phase7_configurator import ConstitutionalConfigurator
+from ...core.phase8_engine import SovereignEngine
+from ..quorum import EmergencyQuorumPolicy, QuorumRoster
+from ..security.constitutional_enforcer import compute_kernel_fingerprint
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+from ..storage.versioning import profile_hash
+from .ceremony import KeyCeremony, key_commitment
+
+
+_HEX64 = re.compile(r"^[0-9a-f]{64}$")
+
+REPO_ROOT = str(Path(__file__).resolve().parents[3])
+DEFAULT_CONSTITUTION = str(
+    Path(__file__).resolve().parents[2] / "configs" / "constitution.json"
+)
+
+
+class GenesisError(Exception):
+    """Raised when genesis inputs are missing or mutually inconsistent."""
+    pass
+
+
+def constitution_hash(config_path: str) -> str:
+    """Profile hash of a constitution file, independent of its formatting."""
+    return profile_hash(Constitution(config_path).load())
+
+
+@dataclass(frozen=True)
+class GenesisRecord:
+    constitution_path: str
+    constitution_hash: str
+    archetype: str
+    quorum_threshold: Optional[int]
+    quorum_members: Dict[str, str]
+    stewards: Dict[str, str]
+    evidence_root: str
+    kernel_fingerprint: str
+    signer_id: str
+    ceremony_hash: str = ""
+    emergency_policy: Optional[Dict] = None
+    signature: str = ""
+    genesis_hash: str = ""
+
+    def unsigned_payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        data.pop("genesis_hash")
+        # The path is a local detail; the hash is what is attested.
+        data.pop("constitution_path")
+        return data
+
+    def to_json(self) -> str:
+        return canonical_json(asdict(self))
+
+    @classmethod
+    def from_json(cls, data: str) -> "GenesisRecord":
+        return cls(**json.loads(data))
+
+    def matches_ceremony(self, ceremony: KeyCeremony) -> bool:
+        """True if genesis used exactly the keys the closed ceremony published."""
+        if not ceremony.is_closed or not ceremony.verify_transcript():
+            return False
+        if self.ceremony_hash != ceremony.transcript_hash:
+            return False
+        regs = ceremony.registries()
+        return (regs["quorum_member"] == self.quorum_members
+                and regs["steward"] == self.stewards)
+
+    def verify(self, secret_resolver: Callable[[str], bytes]) -> bool:
+        """Verify signature, genesis hash and the signer's key commitment."""
+        payload = self.unsigned_payload()
+        if self.genesis_hash != sha256_hex(payload):
+            return False
+        secret = secret_resolver(self.signer_id)
+        if secret is None or key_commitment(secret) != self.stewards.get(self.signer_id):
+            return False
+        return verify_hmac(secret, payload, self.signature)
+
+    def emergency_quorum_policy(self) -> Optional[EmergencyQuorumPolicy]:
+        if self.emergency_policy is None:
+            return None
+        return EmergencyQuorumPolicy.from_dict(self.emergency_policy)
+
+    # ──────────────────────────────────────────────
+    # MODULE INITIALISATION
+    # ──────────────────────────────────────────────
+
+    def boot_engine(self, secret_resolver: Callable[[str], bytes],
+                    handlers: Optional[dict] = None,
+                    repo_root: str = REPO_ROOT) -> SovereignEngine:
+        """
+        Boot an engine from a record that verifies against its signer's key,
+        refusing a constitution or kernel that drifted since genesis.
+        """
+        if not self.verify(secret_resolver):
+            raise GenesisError("Genesis record does not verify against its signer's key")
+        if constitution_hash(self.constitution_path) != self.constitution_hash:
+            raise GenesisError("Constitution on disk does not match genesis record")
+        if compute_kernel_fingerprint(repo_root) != self.kernel_fingerprint:
+            raise GenesisError("Kernel on disk does not match the genesis fingerprint")
+        engine = SovereignEngine(self.constitution_path)
+        engine.boot(handlers)
+        engine.ledger.write({
+            "signal_type": "system",
+            "route": "genesis",
+            "handler": "engine",
+            "outcome": "genesis_bound",
+            "signal_id": self.genesis_hash,
+            "signal_domain": "constitutional",
+            "extra": self.archetype,
+        })
+        return engine
+
+    def build_roster(self, secret_resolver: Callable[[str], bytes],
+                     **kwargs) -> QuorumRoster:
+        """Quorum roster for the genesis members. Every secret must match."""
+        if self.quorum_threshold is None:
+            raise GenesisError(f"Archetype '{self.archetype}' has no quorum")
+        for member_id, commitment in self.quorum_members.items():
+            secret = secret_resolver(member_id)
+            if secret is None or key_commitment(secret) != commitment:
+                raise GenesisError(f"Key for {member_id} does not match genesis commitment")
+        return QuorumRoster(
+            self.quorum_members.keys(), self.quorum_threshold, secret_resolver, **kwargs
+        )
+
+
+@dataclass
+class GenesisBuilder:
+    """
+    Collects genesis inputs, validates them together, and signs.
+
+    INVARIANT: No record is emitted unless every cross-check passes.
+    INVARIANT: The signer must be a steward whose key matches its commitment.
+    """
+
+    constitution_path: str = DEFAULT_CONSTITUTION
+    archetype: Optional[str] = None
+    quorum_members: Dict[str, str] = field(default_factory=dict)
+    stewards: Dict[str, str] = field(default_factory=dict)
+    evidence_root: str = GENESIS_HASH
+    kernel_fingerprint: Optional[str] = None
+    ceremony_hash: str = ""
+    emergency_policy: Optional[EmergencyQuorumPolicy] = None
+
+    def with_constitution(self, config_path: str) -> "GenesisBuilder":
+        self.constitution_path = config_path
+        return self
+
+    def with_archetype(self, name: str) -> "GenesisBuilder":
+        self.archetype = name
+        return self
+
+    def with_quorum_members(self, commitments: Dict[str, str]) -> "GenesisBuilder":
+        self.quorum_members = dict(commitments)
+        return self
+
+    def with_stewards(self, commitments: Dict[str, str]) -> "GenesisBuilder":
+        self.stewards = dict(commitments)
+        return self
+
+    def with_ceremony(self, ceremony: KeyCeremony) -> "GenesisBuilder":
+        """Take member and steward registries from a closed key ceremony."""
+        if not ceremony.is_closed:
+            raise GenesisError("Key ceremony still open — commitments incomplete")
+        if not ceremony.verify_transcript():
+            raise GenesisError("Key ceremony transcript does not verify")
+        regs = ceremony.registries()
+        self.quorum_members = regs["quorum_member"]
+        self.stewards = regs["steward"]
+        self.ceremony_hash = ceremony.transcript_hash
+        return self
+
+    def with_evidence_root(self, root: str) -> "GenesisBuilder":
+        self.evidence_root = root
+        return self
+
+    def with_kernel_fingerprint(self, fingerprint: str) -> "GenesisBuilder":
+        self.kernel_fingerprint = fingerprint
+        return self
+
+    def with_emergency_policy(self, policy: EmergencyQuorumPolicy) -> "GenesisBuilder":
+        self.emergency_policy = policy
+        return self
+
+    def validate(self) -> list[str]:
+        """Return every inconsistency found. Empty means buildable."""
+        problems: list[str] = []
+
+        constitution = Constitution(self.constitution_path)
+        try:
+            constitution.load().validate()
+        except ConstitutionalError as e:
+            return [f"Constitution invalid: {e}"]
+
+        threshold = None
+        if not self.archetype:
+            problems.append("No archetype selected")
+        else:
+            configurator = ConstitutionalConfigurator(constitution)
+            if self.archetype not in configurator.list_archetypes():
+                problems.append(f"Unknown archetype: {self.archetype}")
+            else:
+                compiled = configurator.compile(self.archetype)
+                if not compiled.valid:
+                    problems.append(f"Archetype '{self.archetype}' does not compile cleanly")
+                threshold = compiled.routing_overrides.get("quorum_threshold")
+
+        if threshold is not None and len(self.quorum_members) < threshold:
+            problems.append(
+                f"Quorum archetype needs at least {threshold} members, "
+                f"got {len(self.quorum_members)}"
+            )
+        if threshold is None and self.quorum_members:
+            problems.append(f"Archetype '{self.archetype}' does not use a quorum")
+        if self.emergency_policy is not None:
+            if threshold is None:
+                problems.append("Emergency quorum policy declared without a quorum archetype")
+            else:
+                problems.extend(self.emergency_policy.validate(threshold))
+
+        # Steward overrides are dual-key; one steward can never satisfy that.
+        if len(self.stewards) < 2:
+            problems.append("At least two stewards are required for dual-key overrides")
+
+        overlap = set(self.quorum_members) & set(self.stewards)
+        if overlap:
+            problems.append(f"Ids hold both member and steward roles: {sorted(overlap)}")
+
+        commitments = list(self.quorum_members.values()) + list(self.stewards.values())
+        for c in commitments:
+            if not _HEX64.match(c):
+                problems.append(f"Malformed key commitment: {c!r}")
+        if len(set(commitments)) != len(commitments):
+            problems.append("Key commitments are reused across roles")
+
+        if not _HEX64.match(self.evidence_root):
+            problems.append("Evidence root must be a sha256 hex digest")
+        if not self.kernel_fingerprint or not _HEX64.match(self.kernel_fingerprint):
+            problems.append("Kernel fingerprint must be a sha256 hex digest")
+
+        return problems
+
+    def build(self, signer_id: str, signer_secret: bytes) -> GenesisRecord:
+        problems = self.validate()
+        if problems:
+            raise GenesisError("GENESIS INVALID:\n" + "\n".join(f"  - {p}" for p in problems))
+        if self.stewards.get(signer_id) != key_commitment(signer_secret):
+            raise GenesisError(f"Signer {signer_id} is not a steward with a matching key")
+
+        configurator = ConstitutionalConfigurator(
+            Constitution(self.constitution_path).load().validate()
+        )
+        threshold = configurator.compile(self.archetype).routing_overrides.get("quorum_threshold")
+
+        unsigned = GenesisRecord(
+            constitution_path=self.constitution_path,
+            constitution_hash=constitution_hash(self.constitution_path),
+            archetype=self.archetype,
+            quorum_threshold=threshold,
+            quorum_members=dict(self.quorum_members),
+            stewards=dict(self.stewards),
+            evidence_root=self.evidence_root,
+            kernel_fingerprint=self.kernel_fingerprint,
+            signer_id=signer_id,
+            ceremony_hash=self.ceremony_hash,
+            emergency_policy=(self.emergency_policy.to_dict()
+                              if self.emergency_policy is not None else None),
+        )
+        payload = unsigned.unsigned_payload()
+        return GenesisRecord(
+            **{**asdict(unsigned),
+               "signature": sign_hmac(bytes(signer_secret), payload),
+               "genesis_hash": sha256_hex(payload)}
+        )
@@ -0,0 +1,180 @@
+"""
+Key Ceremony - Multi-party publication of genesis key commitments.
+
+Each quorum member and steward generates their secret privately and
+publishes only a commitment, together with a proof of possession bound to
+the session. The ceremony closes only when every expected participant has
+published; its transcript hash is then carried in the genesis record, so
+anyone can check that genesis used exactly the keys that were published.
+
+Dependency: S3-EXT-018 (genesis builder)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass
+from typing import Dict, Iterable, List
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+
+
+ROLES = ("quorum_member", "steward")
+
+
+class CeremonyError(Exception):
+    pass
+
+
+def key_commitment(secret: bytes) -> str:
+    """Public commitment to a signing secret."""
+    return sha256_hex(bytes(secret))
+
+
+def possession_proof(session_id: str, participant_id: str, secret: bytes) -> str:
+    """Proves the publisher holds the secret behind their commitment."""
+    return sign_hmac(bytes(secret), {
+        "session_id": session_id,
+        "participant_id": participant_id,
+        "commitment": key_commitment(secret),
+    })
+
+
+@dataclass(frozen=True)
+class Publication:
+    participant_id: str
+    role: str
+    commitment: str
+    proof: str
+    published_at: float
+    previous_hash: str
+    hash: str
+
+
+class KeyCeremony:
+    """
+    One key-generation session.
+
+    INVARIANT: Only expected participants may publish, once each.
+    INVARIANT: The session cannot close with any commitment missing.
+    INVARIANT: Nothing may be published after close.
+    """
+
+    def __init__(self, session_id: str, quorum_members: Iterable[str],
+                 stewards: Iterable[str]):
+        self.session_id = session_id
+        self._expected: Dict[str, str] = {}
+        for m in quorum_members:
+            self._expected[m] = "quorum_member"
+        for s in stewards:
+            if s in self._expected:
+                raise CeremonyError(f"{s} cannot hold both member and steward roles")
+            self._expected[s] = "steward"
+        self._publications: List[Publication] = []
+        self._closed_at: float = 0.0
+
+    def publish(self, participant_id: str, commitment: str, proof: str) -> Publication:
+        if self.is_closed:
+            raise CeremonyError("Ceremony closed — no further publications")
+        role = self._expected.get(participant_id)
+        if role is None:
+            raise CeremonyError(f"{participant_id} is not an expected participant")
+        if participant_id in self.published:
+            raise CeremonyError(f"{participant_id} has already published")
+
+        prev = self._publications[-1].hash if self._publications else "0" * 64
+        ts = time.time()
+        body = {
+            "session_id": self.session_id,
+            "participant_id": participant_id,
+            "role": role,
+            "commitment": commitment,
+            "proof": proof,
+            "published_at": ts,
+            "previous_hash": prev,
+        }
+        pub = Publication(
+            participant_id=participant_id, role=role, commitment=commitment,
+            proof=proof, published_at=ts, previous_hash=prev,
+            hash=sha256_hex(canonical_json(body)),
+        )
+        self._publications.append(pub)
+        return pub
+
+    def verify_possession(self, participant_id: str, secret: bytes) -> bool:
+        """Check a published proof once the participant reveals their secret
+        to a verifier under the ceremony's witness procedure."""
+        pub = next((p for p in self._publications if p.participant_id == participant_id), None)
+        if pub is None or key_commitment(secret) != pub.commitment:
+            return False
+        return verify_hmac(bytes(secret), {
+            "session_id": self.session_id,
+            "participant_id": participant_id,
+            "commitment": pub.commitment,
+        }, pub.proof)
+
+    def close(self) -> str:
+        """Close the session. Returns the transcript hash."""
+        if self.is_closed:
+            raise CeremonyError("Ceremony already closed")
+        if self.missing:
+            raise CeremonyError(f"Commitments missing from: {sorted(self.missing)}")
+        commitments = [p.commitment for p in self._publications]
+        if len(set(commitments)) != len(commitments):
+            raise CeremonyError("Duplicate key commitments published")
+        self._closed_at = time.time()
+        return self.transcript_hash
+
+    # ──────────────────────────────────────────────
+    # OUTPUT
+    # ──────────────────────────────────────────────
+
+    @property
+    def transcript_hash(self) -> str:
+        return sha256_hex(canonical_json({
+            "session_id": self.session_id,
+            "publications": [asdict(p) for p in self._publications],
+        }))
+
+    def registries(self) -> Dict[str, Dict[str, str]]:
+        """Role → {participant → commitment}, as consumed by GenesisBuilder."""
+        if not self.is_closed:
+            raise CeremonyError("Registries are only available after close")
+        out: Dict[str, Dict[str, str]] = {role: {} for role in ROLES}
+        for p in self._publications:
+            out[p.role][p.participant_id] = p.commitment
+        return out
+
+    def verify_transcript(self) -> bool:
+        prev = "0" * 64
+        for p in self._publications:
+            body = {
+                "session_id": self.session_id,
+                "participant_id": p.participant_id,
+                "role": p.role,
+                "commitment": p.commitment,
+                "proof": p.proof,
+                "published_at": p.published_at,
+                "previous_hash": prev,
+            }
+            if p.previous_hash != prev or p.hash != sha256_hex(canonical_json(body)):
+                return False
+            prev = p.hash
+        return True
+
+    @property
+    def published(self) -> List[str]:
+        return [p.participant_id for p in self._publications]
+
+    @property
+    def missing(self) -> List[str]:
+        done = set(self.published)
+        return [pid for pid in self._expected if pid not in done]
+
+    @property
+    def is_closed(self) -> bool:
+        return self._closed_at > 0.0
+
+    @property
+    def publications(self) -> List[Publication]:
+        return list(self._publications)
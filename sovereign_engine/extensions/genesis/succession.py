@@ -0,0 +1,284 @@
+"""
+Succession Export - Everything a successor system needs, in one verifiable package.
+
+Genesis is how a deployment begins; a succession export is how it hands
+over. A root hash lets a successor check what it was given, but not
+rebuild from it. The export therefore carries the records themselves:
+the genesis record, the constitution content, every ledger entry, every
+evidence item with its supersession, and whatever registries the caller
+adds (quorum roster states, key directory entries).
+
+Records are serialised one canonical JSON line each and cut into
+fixed-size chunks. The manifest lists every chunk hash alongside the
+ledger head, evidence root and per-section counts, and is signed by a
+genesis steward. A receiver can fetch chunks in any order, ask which are
+still missing, and resume an interrupted transfer without re-fetching
+what already verified.
+
+The manifest carries a version stamp; a reader refuses an export whose
+format is newer than it understands, or that was written under another
+constitution than the reader's genesis record. The exported constitution
+and evidence are rehashed by the reader and compared against its own
+genesis record and the signed evidence root, not against the manifest's
+word for them.
+
+Building an export writes a `succession_export_built` ledger entry whose
+signal ID is the manifest hash, so the event that hands authority to a
+successor can cite it.
+
+Dependency: Phase 4 (audit), S3-EXT-004 (evidence vault), S3-EXT-018 (genesis)
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Any, Callable, Dict, List, Optional
+
+from ...core.phase0_constitution import Constitution
+from ..evidence_vault import canon_root_of, evidence_content_hash
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+from ..storage.versioning import VersionStamp, check_stamp, current_stamp, profile_hash
+from .builder import GenesisRecord, constitution_hash, key_commitment
+
+
+ARTIFACT = "succession_export"
+SECTIONS = ("genesis", "constitution", "ledger", "evidence", "registry")
+DEFAULT_CHUNK_SIZE = 1 << 20
+
+
+class SuccessionError(Exception):
+    pass
+
+
+def _chunk_name(index: int) -> str:
+    return f"chunk-{index:06d}.bin"
+
+
+@dataclass(frozen=True)
+class SuccessionManifest:
+    genesis_hash: str
+    constitution_hash: str
+    ledger_head: str
+    ledger_length: int
+    evidence_root: Optional[str]
+    sections: Dict[str, int]
+    chunk_size: int
+    chunks: List[str]
+    total_bytes: int
+    created_at: float
+    signer_id: str
+    stamp: Optional[Dict[str, Any]] = None
+    signature: str = ""
+    manifest_hash: str = ""
+
+    def unsigned_payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        data.pop("manifest_hash")
+        return data
+
+    @property
+    def version_stamp(self) -> Optional[VersionStamp]:
+        return VersionStamp.from_dict(self.stamp) if self.stamp else None
+
+    def to_json(self) -> str:
+        return canonical_json(asdict(self))
+
+    @classmethod
+    def from_json(cls, data: str) -> "SuccessionManifest":
+        return cls(**json.loads(data))
+
+
+@dataclass(frozen=True)
+class SuccessionExport:
+    manifest: SuccessionManifest
+    chunks: List[bytes]
+
+    def write(self, directory: str) -> int:
+        """Write manifest and chunks, skipping chunks already present and intact."""
+        os.makedirs(directory, exist_ok=True)
+        with open(os.path.join(directory, "manifest.json"), "w") as f:
+            f.write(self.manifest.to_json())
+        written = 0
+        missing = set(missing_chunks(self.manifest, directory))
+        for i, chunk in enumerate(self.chunks):
+            if i in missing:
+                with open(os.path.join(directory, _chunk_name(i)), "wb") as f:
+                    f.write(chunk)
+                written += 1
+        return written
+
+
+@dataclass
+class SuccessionExportBuilder:
+    """
+    Collects the state to hand over and signs the manifest.
+
+    INVARIANT: Every ledger entry and every evidence item is exported.
+    INVARIANT: The export is refused if the constitution drifted since genesis.
+    """
+
+    genesis: GenesisRecord
+    ledger: Any = None
+    vault: Any = None
+    registries: Dict[str, Any] = field(default_factory=dict)
+
+    def with_ledger(self, ledger) -> "SuccessionExportBuilder":
+        self.ledger = ledger
+        return self
+
+    def with_vault(self, vault) -> "SuccessionExportBuilder":
+        self.vault = vault
+        return self
+
+    def with_registry(self, name: str, data: Any) -> "SuccessionExportBuilder":
+        self.registries[name] = data
+        return self
+
+    def build(self, signer_id: str, signer_secret: bytes,
+              chunk_size: int = DEFAULT_CHUNK_SIZE,
+              clock: Callable[[], float] = time.time) -> SuccessionExport:
+        if self.ledger is None:
+            raise SuccessionError("A succession export needs the audit ledger")
+        if self.genesis.stewards.get(signer_id) != key_commitment(signer_secret):
+            raise SuccessionError(f"Signer {signer_id} is not a steward with a matching key")
+        if constitution_hash(self.genesis.constitution_path) != self.genesis.constitution_hash:
+            raise SuccessionError("Constitution on disk does not match genesis record")
+        if chunk_size < 1:
+            raise SuccessionError("Chunk size must be positive")
+
+        with open(self.genesis.constitution_path) as f:
+            constitution = json.load(f)
+        records: List[dict] = [
+            {"section": "genesis", "data": json.loads(self.genesis.to_json())},
+            {"section": "constitution", "data": constitution},
+        ]
+        entries = self.ledger.entries
+        records += [{"section": "ledger", "data": asdict(e)} for e in entries]
+        evidence_root = None
+        if self.vault is not None:
+            for eid in self.vault.evidence_ids():
+                current = self.vault.current_version(eid)
+                records.append({"section": "evidence", "data": {
+                    "id": eid,
+                    "stored": self.vault.retrieve_evidence(eid),
+                    "superseded_by": current if current != eid else None,
+                }})
+            evidence_root = self.vault.canon_root()
+        records += [{"section": "registry", "name": name, "data": data}
+                    for name, data in sorted(self.registries.items())]
+
+        blob = "".join(canonical_json(r) + "\n" for r in records).encode("utf-8")
+        chunks = [blob[i:i + chunk_size] for i in range(0, len(blob), chunk_size)]
+        sections = {s: sum(1 for r in records if r["section"] == s) for s in SECTIONS}
+
+        manifest = SuccessionManifest(
+            genesis_hash=self.genesis.genesis_hash,
+            constitution_hash=self.genesis.constitution_hash,
+            ledger_head=entries[-1].hash if entries else "",
+            ledger_length=len(entries),
+            evidence_root=evidence_root,
+            sections=sections,
+            chunk_size=chunk_size,
+            chunks=[sha256_hex(c) for c in chunks],
+            total_bytes=len(blob),
+            created_at=clock(),
+            signer_id=signer_id,
+            stamp=current_stamp(ARTIFACT, self.genesis.constitution_hash).to_dict(),
+        )
+        payload = manifest.unsigned_payload()
+        manifest = SuccessionManifest(**payload, signature=sign_hmac(signer_secret, payload),
+                                      manifest_hash=sha256_hex(payload))
+        self.ledger.write({
+            "signal_type": "system",
+            "route": "succession",
+            "handler": "succession_export",
+            "outcome": "succession_export_built",
+            "signal_id": manifest.manifest_hash,
+            "signal_domain": "constitutional",
+            "extra": canonical_json({"chunks": len(chunks), "sections": sections}),
+        })
+        return SuccessionExport(manifest, chunks)
+
+
+def missing_chunks(manifest: SuccessionManifest, directory: str) -> List[int]:
+    """Chunk indices absent from `directory` or not matching the manifest."""
+    missing: List[int] = []
+    for i, expected in enumerate(manifest.chunks):
+        path = os.path.join(directory, _chunk_name(i))
+        if not os.path.exists(path):
+            missing.append(i)
+            continue
+        with open(path, "rb") as f:
+            if sha256_hex(f.read()) != expected:
+                missing.append(i)
+    return missing
+
+
+def read_succession_export(manifest: SuccessionManifest, directory: str) -> Dict[str, List[dict]]:
+    """Reassemble the records by section. Raises while any chunk is missing."""
+    missing = missing_chunks(manifest, directory)
+    if missing:
+        raise SuccessionError(f"{len(missing)} chunks missing or corrupt: {missing[:10]}")
+    blob = b""
+    for i in range(len(manifest.chunks)):
+        with open(os.path.join(directory, _chunk_name(i)), "rb") as f:
+            blob += f.read()
+    records: Dict[str, List[dict]] = {s: [] for s in SECTIONS}
+    for line in blob.decode("utf-8").splitlines():
+        record = json.loads(line)
+        records[record["section"]].append(record)
+    return records
+
+
+def verify_succession_export(manifest: SuccessionManifest, genesis: GenesisRecord,
+                             secret_resolver: Callable[[str], bytes],
+                             directory: str) -> List[str]:
+    """Receiver-side checks. Empty means the export is complete and authentic."""
+    problems: List[str] = []
+    payload = manifest.unsigned_payload()
+    if manifest.manifest_hash != sha256_hex(payload):
+        problems.append("Manifest hash does not match its content")
+    secret = secret_resolver(manifest.signer_id)
+    if secret is None or key_commitment(secret) != genesis.stewards.get(manifest.signer_id):
+        problems.append(f"Signer {manifest.signer_id} is not a genesis steward")
+    elif not verify_hmac(secret, payload, manifest.signature):
+        problems.append("Manifest signature does not verify")
+    if manifest.genesis_hash != genesis.genesis_hash:
+        problems.append("Export is for a different genesis")
+    if manifest.constitution_hash != genesis.constitution_hash:
+        problems.append("Export names a different constitution than genesis")
+    problems += check_stamp(manifest.version_stamp, ARTIFACT, genesis.constitution_hash)
+
+    try:
+        records = read_succession_export(manifest, directory)
+    except SuccessionError as e:
+        return problems + [str(e)]
+
+    counts = {s: len(records[s]) for s in SECTIONS}
+    if counts != manifest.sections:
+        problems.append(f"Section counts {counts} do not match manifest {manifest.sections}")
+    exported = records["constitution"]
+    if exported and profile_hash(Constitution().load_from_dict(exported[0]["data"])) != \
+            genesis.constitution_hash:
+        problems.append("Exported constitution does not match genesis")
+    ledger = [r["data"] for r in records["ledger"]]
+    for prev, entry in zip(ledger, ledger[1:]):
+        if entry["previous_hash"] != prev["hash"]:
+            problems.append(f"Ledger chain broken at entry {entry['index']}")
+            break
+    if (ledger[-1]["hash"] if ledger else "") != manifest.ledger_head:
+        problems.append("Exported ledger does not end at the manifest head")
+
+    # Rebuilt from each item's facts, not from the content hash it was stored with.
+    evidence = [r["data"] for r in records["evidence"]]
+    if evidence or manifest.evidence_root is not None:
+        leaves = [evidence_content_hash(e["stored"]["record"]["type"],
+                                        e["stored"]["record"]["data"])
+                  for e in evidence if e["superseded_by"] is None]
+        if canon_root_of(leaves) != manifest.evidence_root:
+            problems.append("Exported evidence does not reproduce the manifest evidence root")
+    return problems
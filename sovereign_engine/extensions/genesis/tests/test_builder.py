@@ -0,0 +1,111 @@
+import os
+import shutil
+import tempfile
+import unittest
+
+from sovereign_engine.extensions.genesis import (
+    REPO_ROOT,
+    GenesisBuilder,
+    GenesisError,
+    GenesisRecord,
+    key_commitment,
+)
+from sovereign_engine.extensions.quorum import EmergencyQuorumPolicy
+from sovereign_engine.extensions.security import IMMUTABLE_PATHS, compute_kernel_fingerprint
+
+SECRETS = {k: f"secret-{k}".encode() for k in ["m1", "m2", "m3", "s1", "s2"]}
+FINGERPRINT = compute_kernel_fingerprint(REPO_ROOT)
+
+
+def federated_builder():
+    return (
+        GenesisBuilder()
+        .with_archetype("federated")
+        .with_quorum_members({m: key_commitment(SECRETS[m]) for m in ["m1", "m2", "m3"]})
+        .with_stewards({s: key_commitment(SECRETS[s]) for s in ["s1", "s2"]})
+        .with_kernel_fingerprint(FINGERPRINT)
+    )
+
+
+class TestGenesisBuilder(unittest.TestCase):
+    def test_signed_record_verifies_and_initialises_modules(self):
+        record = federated_builder().build("s1", SECRETS["s1"])
+        self.assertTrue(record.verify(lambda k: SECRETS[k]))
+
+        engine = record.boot_engine(SECRETS.get)
+        self.assertEqual(engine.ledger.entries[-1].signal_id, record.genesis_hash)
+        roster = record.build_roster(lambda k: SECRETS[k])
+        self.assertEqual(roster.achievable_quorum, 3)
+
+        restored = GenesisRecord.from_json(record.to_json())
+        self.assertTrue(restored.verify(lambda k: SECRETS[k]))
+
+    def test_cross_consistency_failures_are_all_reported(self):
+        builder = (
+            GenesisBuilder()
+            .with_archetype("managerial")
+            .with_quorum_members({"m1": key_commitment(SECRETS["m1"])})
+            .with_stewards({"m1": key_commitment(SECRETS["m1"])})
+        )
+        problems = builder.validate()
+        self.assertTrue(any("does not use a quorum" in p for p in problems))
+        self.assertTrue(any("two stewards" in p for p in problems))
+        self.assertTrue(any("both member and steward" in p for p in problems))
+        self.assertTrue(any("reused" in p for p in problems))
+        self.assertTrue(any("fingerprint" in p for p in problems))
+        with self.assertRaises(GenesisError):
+            builder.build("m1", SECRETS["m1"])
+
+    def test_emergency_policy_is_signed_into_the_record(self):
+        policy = EmergencyQuorumPolicy(1, frozenset({"halt"}))
+        record = federated_builder().with_emergency_policy(policy).build("s1", SECRETS["s1"])
+        self.assertTrue(record.verify(lambda k: SECRETS[k]))
+        self.assertEqual(GenesisRecord.from_json(record.to_json()).emergency_quorum_policy(), policy)
+        self.assertIsNone(federated_builder().build("s1", SECRETS["s1"]).emergency_quorum_policy())
+        too_wide = EmergencyQuorumPolicy(1, frozenset({"canon_update"}))
+        self.assertTrue(any("not reducible" in p for p in
+                            federated_builder().with_emergency_policy(too_wide).validate()))
+
+    def test_non_steward_or_wrong_key_cannot_sign(self):
+        with self.assertRaises(GenesisError):
+            federated_builder().build("m1", SECRETS["m1"])
+        with self.assertRaises(GenesisError):
+            federated_builder().build("s1", b"not-the-key")
+
+    def test_tampered_record_fails_verification(self):
+        record = federated_builder().build("s1", SECRETS["s1"])
+        forged = GenesisRecord.from_json(record.to_json().replace(FINGERPRINT, "cd" * 32))
+        self.assertFalse(forged.verify(lambda k: SECRETS[k]))
+        with self.assertRaises(GenesisError):
+            forged.boot_engine(SECRETS.get)
+
+    def test_kernel_drift_refuses_to_boot(self):
+        unaudited = (federated_builder().with_kernel_fingerprint("cd" * 32)
+                     .build("s1", SECRETS["s1"]))
+        self.assertTrue(unaudited.verify(SECRETS.get))
+        with self.assertRaisesRegex(GenesisError, "Kernel"):
+            unaudited.boot_engine(SECRETS.get)
+
+        record = federated_builder().build("s1", SECRETS["s1"])
+        with tempfile.TemporaryDirectory() as tmp:
+            for rel in IMMUTABLE_PATHS:
+                src, dst = os.path.join(REPO_ROOT, rel), os.path.join(tmp, rel)
+                if os.path.isdir(src):
+                    shutil.copytree(src, dst)
+                elif os.path.isfile(src):
+                    os.makedirs(os.path.dirname(dst), exist_ok=True)
+                    shutil.copy(src, dst)
+            record.boot_engine(SECRETS.get, repo_root=tmp)
+            with open(os.path.join(tmp, "sovereign_engine", "core", "patch.py"), "w") as f:
+                f.write("BYPASS = True\n")
+            with self.assertRaisesRegex(GenesisError, "Kernel"):
+                record.boot_engine(SECRETS.get, repo_root=tmp)
+
+    def test_emergency_policy_is_always_in_the_signed_payload(self):
+        record = federated_builder().build("s1", SECRETS["s1"])
+        self.assertIsNone(record.unsigned_payload()["emergency_policy"])
+        self.assertTrue(record.verify(SECRETS.get))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,70 @@
+import unittest
+
+from sovereign_engine.extensions.genesis import (
+    CeremonyError,
+    GenesisBuilder,
+    GenesisError,
+    KeyCeremony,
+    key_commitment,
+    possession_proof,
+)
+
+SECRETS = {k: f"secret-{k}".encode() for k in ["m1", "m2", "m3", "s1", "s2"]}
+
+
+def publish(ceremony, pid):
+    ceremony.publish(pid, key_commitment(SECRETS[pid]),
+                     possession_proof(ceremony.session_id, pid, SECRETS[pid]))
+
+
+class TestKeyCeremony(unittest.TestCase):
+    def setUp(self):
+        self.ceremony = KeyCeremony("genesis-1", ["m1", "m2", "m3"], ["s1", "s2"])
+
+    def test_cannot_close_until_every_commitment_published(self):
+        for pid in ["m1", "m2", "s1"]:
+            publish(self.ceremony, pid)
+        with self.assertRaises(CeremonyError):
+            self.ceremony.close()
+        self.assertEqual(sorted(self.ceremony.missing), ["m3", "s2"])
+        with self.assertRaises(GenesisError):
+            GenesisBuilder().with_ceremony(self.ceremony)
+
+    def test_rejects_unexpected_duplicate_and_late_publications(self):
+        with self.assertRaises(CeremonyError):
+            self.ceremony.publish("intruder", "ab" * 32, "")
+        publish(self.ceremony, "m1")
+        with self.assertRaises(CeremonyError):
+            publish(self.ceremony, "m1")
+        for pid in ["m2", "m3", "s1", "s2"]:
+            publish(self.ceremony, pid)
+        self.ceremony.close()
+        with self.assertRaises(CeremonyError):
+            self.ceremony.publish("m1", "cd" * 32, "")
+
+    def test_closed_ceremony_feeds_genesis(self):
+        for pid in ["m1", "m2", "m3", "s1", "s2"]:
+            publish(self.ceremony, pid)
+        self.ceremony.close()
+        self.assertTrue(self.ceremony.verify_possession("m2", SECRETS["m2"]))
+        self.assertFalse(self.ceremony.verify_possession("m2", b"guess"))
+
+        record = (
+            GenesisBuilder()
+            .with_archetype("federated")
+            .with_ceremony(self.ceremony)
+            .with_kernel_fingerprint("ab" * 32)
+            .build("s1", SECRETS["s1"])
+        )
+        self.assertTrue(record.matches_ceremony(self.ceremony))
+        self.assertTrue(record.verify(lambda k: SECRETS[k]))
+
+        other = KeyCeremony("genesis-2", ["m1", "m2", "m3"], ["s1", "s2"])
+        for pid in ["m1", "m2", "m3", "s1", "s2"]:
+            publish(other, pid)
+        other.close()
+        self.assertFalse(record.matches_ceremony(other))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,107 @@
+import os
+import tempfile
+import unittest
+from dataclasses import replace
+
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.genesis import (
+    SuccessionError,
+    SuccessionExportBuilder,
+    SuccessionManifest,
+    missing_chunks,
+    read_succession_export,
+    verify_succession_export,
+)
+from sovereign_engine.extensions.genesis.tests.test_builder import SECRETS, federated_builder
+from sovereign_engine.extensions.security.zero_trust_evidence import sha256_hex, sign_hmac
+
+
+class TestSuccessionExport(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.genesis = federated_builder().build("s1", SECRETS["s1"])
+        self.engine = self.genesis.boot_engine(SECRETS.get)
+        for i in range(20):
+            self.engine.submit_and_process("query", "operational", "operator", {"i": i})
+        self.vault = EvidenceVault(os.path.join(self._tmp.name, "vault"))
+        old = self.vault.store_evidence("report", {"v": 1})
+        new = self.vault.store_evidence("report", {"v": 2})
+        self.vault.supersede(old, new, "corrected")
+        self.out = os.path.join(self._tmp.name, "export")
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def _build(self, chunk_size=1024):
+        return (SuccessionExportBuilder(self.genesis)
+                .with_ledger(self.engine.ledger)
+                .with_vault(self.vault)
+                .with_registry("roster", {"m1": "active", "m2": "active", "m3": "deceased"})
+                .build("s2", SECRETS["s2"], chunk_size=chunk_size))
+
+    def test_export_round_trips_and_verifies(self):
+        length = self.engine.ledger.length
+        export = self._build()
+        self.assertGreater(len(export.chunks), 1)
+        self.assertEqual(self.engine.ledger.entries[-1].signal_id, export.manifest.manifest_hash)
+        export.write(self.out)
+
+        with open(os.path.join(self.out, "manifest.json")) as f:
+            manifest = SuccessionManifest.from_json(f.read())
+        self.assertEqual(verify_succession_export(manifest, self.genesis,
+                                                  lambda k: SECRETS[k], self.out), [])
+        records = read_succession_export(manifest, self.out)
+        self.assertEqual(len(records["ledger"]), length)
+        self.assertEqual(len(records["evidence"]), 2)
+        self.assertEqual(sum(1 for r in records["evidence"] if r["data"]["superseded_by"]), 1)
+        self.assertEqual(records["registry"][0]["data"]["m3"], "deceased")
+
+    def test_interrupted_transfer_resumes_with_only_missing_chunks(self):
+        export = self._build(chunk_size=512)
+        export.write(self.out)
+        os.remove(os.path.join(self.out, "chunk-000001.bin"))
+        with open(os.path.join(self.out, "chunk-000002.bin"), "ab") as f:
+            f.write(b"tampered")
+        self.assertEqual(missing_chunks(export.manifest, self.out), [1, 2])
+        self.assertTrue(verify_succession_export(export.manifest, self.genesis,
+                                                 lambda k: SECRETS[k], self.out))
+        self.assertEqual(export.write(self.out), 2)
+        self.assertEqual(verify_succession_export(export.manifest, self.genesis,
+                                                  lambda k: SECRETS[k], self.out), [])
+
+    def test_only_stewards_sign_and_forged_manifests_fail(self):
+        with self.assertRaises(SuccessionError):
+            SuccessionExportBuilder(self.genesis, ledger=self.engine.ledger).build(
+                "m1", SECRETS["m1"])
+        export = self._build()
+        export.write(self.out)
+        forged = SuccessionManifest(**{**export.manifest.unsigned_payload(), "ledger_length": 1},
+                                    signature=export.manifest.signature,
+                                    manifest_hash=export.manifest.manifest_hash)
+        problems = verify_succession_export(forged, self.genesis, lambda k: SECRETS[k], self.out)
+        self.assertTrue(any("Manifest hash" in p for p in problems))
+        self.assertTrue(any("signature" in p for p in problems))
+
+    def test_stamp_is_checked_against_the_readers_genesis(self):
+        export = self._build()
+        export.write(self.out)
+        other = replace(self.genesis, constitution_hash="0" * 64)
+        problems = verify_succession_export(export.manifest, other, SECRETS.get, self.out)
+        self.assertIn("Export names a different constitution than genesis", problems)
+        self.assertIn("Exported constitution does not match genesis", problems)
+        self.assertTrue(any("constitutional profile" in p for p in problems))
+
+    def test_signed_evidence_root_must_match_the_exported_evidence(self):
+        export = self._build()
+        export.write(self.out)
+        payload = {**export.manifest.unsigned_payload(), "evidence_root": "0" * 64}
+        lying = SuccessionManifest(**payload, signature=sign_hmac(SECRETS["s2"], payload),
+                                   manifest_hash=sha256_hex(payload))
+        self.assertEqual(
+            verify_succession_export(lying, self.genesis, SECRETS.get, self.out),
+            ["Exported evidence does not reproduce the manifest evidence root"],
+        )
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,37 @@
+from .canary import (
+    CANARY_FAILURE,
+    DEFAULT_CASES,
+    EXPECT_BLOCKED,
+    EXPECT_HALT,
+    EXPECT_PASS,
+    CanaryAgent,
+    CanaryCase,
+    CanaryRun,
+)
+from .guard import UNHANDLED_FAILURE, guarded_process, screen
+from .invariants import (
+    DEBUG_INVARIANTS_ENV,
+    InvariantMonitor,
+    InvariantViolation,
+    Violation,
+    debug_invariants_enabled,
+)
+
+__all__ = [
+    "CANARY_FAILURE",
+    "DEBUG_INVARIANTS_ENV",
+    "DEFAULT_CASES",
+    "EXPECT_BLOCKED",
+    "EXPECT_HALT",
+    "EXPECT_PASS",
+    "UNHANDLED_FAILURE",
+    "CanaryAgent",
+    "CanaryCase",
+    "CanaryRun",
+    "InvariantMonitor",
+    "InvariantViolation",
+    "Violation",
+    "debug_invariants_enabled",
+    "guarded_process",
+    "screen",
+]
@@ -0,0 +1,208 @@
+"""
+Canary Agent - Prove the verifier still says no.
+
+A legality gate that has been broken to accept everything looks, from
+the outside, exactly like a quiet week: every signal passes, nothing is
+contained, nothing halts. The only way to notice is to keep asking it
+questions whose answer is known. Each epoch the canary builds synthetic
+inputs that must be refused — a tampered hash, a silent escalation, a
+cross-authority direct call, a steward override without dual key — plus
+a halt signal that must route to halt and an ordinary query that must
+pass, and evaluates them through `dry_run`, so nothing is routed,
+contained or ledgered by the probes themselves.
+
+Payloads are keyed by the epoch nonce, so a gate cannot recognise
+canaries by shape and wave them through. A dry run checks a shadow gate
+built fresh from the constitution, which says nothing about the gate the
+engine actually routes through, so every case is also put to the live
+`engine.gate` instance. Its stats and containment log are restored
+afterwards; a gate whose private state was edited (an emptied forbidden
+set, a patched helper) fails there even though the shadow still holds.
+
+A refused-input case that passes means the verifier cannot be trusted:
+it is ledgered, raised as an emergency alert, and reported to the health
+monitor as `verifier_compromised`, which halts by doctrine. A known-good
+input being blocked errs on the safe side (a pause contains it, for
+instance) and is only ledgered as degraded.
+
+Dependency: Phase 3 (legality), Phase 6 (failure), S3-EXT-023 (dry run)
+"""
+
+from __future__ import annotations
+
+import copy
+import os
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Callable, Dict, List, Optional, Sequence, Tuple
+
+from ...core.phase1_signals import Signal
+from ...core.phase3_legality import LegalityGate
+from ..dry_run import dry_run
+from ..security.zero_trust_evidence import canonical_json
+
+
+CANARY_FAILURE = "verifier_compromised"
+
+# What a case's dry-run verdict must be.
+EXPECT_BLOCKED = "blocked"
+EXPECT_HALT = "halt"
+EXPECT_PASS = "pass"
+
+
+@dataclass(frozen=True)
+class CanaryCase:
+    name: str
+    expect: str
+    build: Callable[[object, dict], Tuple[Signal, dict]]
+
+
+def _tampered(engine, payload):
+    signal = engine.create_signal("command", "operational", "operator", payload)
+    signal.payload = {**payload, "altered": True}
+    return signal, {}
+
+
+def _silent_escalation(engine, payload):
+    return engine.create_signal("escalation", "operational", "operator", payload), {}
+
+
+def _direct_call(engine, payload):
+    signal = engine.create_signal("command", "operational", "operator", payload)
+    return signal, {"source_authority": "operator", "target_authority": "steward"}
+
+
+def _override_single_key(engine, payload):
+    signal = engine.create_signal("command", "constitutional", "steward", payload)
+    return signal, {"steward_override": True}
+
+
+def _halt(engine, payload):
+    return engine.create_signal("halt", "emergency", "system", payload, source="canary"), {}
+
+
+def _query(engine, payload):
+    return engine.create_signal("query", "operational", "operator", payload), {}
+
+
+DEFAULT_CASES: Tuple[CanaryCase, ...] = (
+    CanaryCase("tampered_hash", EXPECT_BLOCKED, _tampered),
+    CanaryCase("silent_escalation", EXPECT_BLOCKED, _silent_escalation),
+    CanaryCase("cross_authority_direct_call", EXPECT_BLOCKED, _direct_call),
+    CanaryCase("override_without_dual_key", EXPECT_BLOCKED, _override_single_key),
+    CanaryCase("halt_signal", EXPECT_HALT, _halt),
+    CanaryCase("known_good_query", EXPECT_PASS, _query),
+)
+
+
+@dataclass
+class CanaryRun:
+    epoch: int
+    nonce: str
+    at: float
+    passed: List[str] = field(default_factory=list)
+    compromised: List[str] = field(default_factory=list)
+    degraded: List[str] = field(default_factory=list)
+    skipped: bool = False
+
+    @property
+    def ok(self) -> bool:
+        return not self.compromised
+
+
+class CanaryAgent:
+    """
+    INVARIANT: Probes never route, contain or write; only the run's outcome is ledgered.
+    INVARIANT: Any refused-input case that passes halts the engine.
+    """
+
+    def __init__(self, engine, cases: Sequence[CanaryCase] = DEFAULT_CASES,
+                 emit: Optional[Callable[[dict], object]] = None,
+                 clock: Callable[[], float] = time.time):
+        self._engine = engine
+        self._cases = list(cases)
+        self._emit = emit
+        self._clock = clock
+        self._runs: List[CanaryRun] = []
+
+    def run(self, epoch: int, nonce: Optional[str] = None) -> CanaryRun:
+        """Probe the verifier once. Pass an epoch seed's challenge nonce in production."""
+        nonce = nonce or os.urandom(16).hex()
+        run = CanaryRun(epoch, nonce, self._clock())
+        self._runs.append(run)
+        if self._engine.is_halted:
+            run.skipped = True
+            return run
+
+        gate = self._engine.gate
+        if type(gate) is not LegalityGate or "check" in vars(gate):
+            run.compromised.append("live_gate_replaced")
+
+        payload = {nonce[:12]: nonce, "epoch": epoch}
+        for case in self._cases:
+            signal, context = case.build(self._engine, dict(payload))
+            verdict = dry_run(self._engine, signal, context)
+            live = self._live_legal(signal, context)
+            if case.expect == EXPECT_BLOCKED:
+                held = not verdict.legal and live is False
+            elif case.expect == EXPECT_HALT:
+                held = verdict.legal and verdict.would_halt and live is True
+            else:
+                held = verdict.would_pass and live is True
+            if held:
+                run.passed.append(case.name)
+            elif case.expect == EXPECT_PASS:
+                run.degraded.append(case.name)
+            else:
+                run.compromised.append(case.name)
+
+        if run.compromised:
+            self._compromised(run)
+        else:
+            self._record("canary_degraded" if run.degraded else "canary_passed", run)
+        return run
+
+    def _live_legal(self, signal: Signal, context: dict) -> Optional[bool]:
+        """Live gate verdict on a copy of the probe, bookkeeping put back. None if it raised."""
+        gate = self._engine.gate
+        stats, log = asdict(gate.stats), list(gate._containment_log)
+        try:
+            return gate.check(copy.deepcopy(signal), dict(context)).legal
+        except Exception:
+            return None
+        finally:
+            for name, value in stats.items():
+                setattr(gate.stats, name, value)
+            gate._containment_log[:] = log
+
+    def _compromised(self, run: CanaryRun):
+        self._record("canary_failed", run)
+        if self._emit:
+            self._emit({
+                "type": "alert",
+                "domain": "emergency",
+                "authority": "system",
+                "source": "canary_agent",
+                "payload": {"event": CANARY_FAILURE, "epoch": run.epoch,
+                            "accepted": run.compromised},
+            })
+        self._engine.health.report_failure(
+            "canary_agent", CANARY_FAILURE,
+            f"epoch {run.epoch}: verifier accepted {', '.join(run.compromised)}",
+        )
+
+    def _record(self, outcome: str, run: CanaryRun):
+        self._engine.ledger.write({
+            "signal_type": "audit",
+            "route": "canary",
+            "handler": "canary_agent",
+            "outcome": outcome,
+            "signal_id": f"canary-{run.epoch}",
+            "signal_domain": "constitutional",
+            "extra": canonical_json({"nonce": run.nonce, "compromised": run.compromised,
+                                     "degraded": run.degraded}),
+        })
+
+    @property
+    def runs(self) -> List[CanaryRun]:
+        return list(self._runs)
@@ -0,0 +1,99 @@
+"""
+Process Guard - The constitutional path never aborts the caller.
+S3-EXT-027: Input hardening bolt-on.
+
+`SovereignEngine.process` assumes a well-formed Signal and dict context.
+Given anything else it raises from deep inside the pipeline — an
+AttributeError from the legality gate for a non-Signal, a TypeError for
+an unhashable type or domain, a serialisation error for an exotic
+timestamp — and an exception that escapes mid-pipeline can leave a signal
+without its audit entry. The kernel is locked, so the guard sits in front
+of it:
+
+- Malformed input is screened before the kernel sees it, contained, and
+  written to the ledger like any other termination.
+- Any exception that still escapes the kernel, or the containment entry
+  itself failing to write, is reported to the health monitor as
+  `unhandled_exception`. That type is unknown to the constitution, so it
+  halts by doctrine.
+
+Either way the caller gets a result dict, never an exception.
+
+Dependency: Phase 1 (signals), Phase 6 (failure), Phase 8 (engine)
+"""
+
+from __future__ import annotations
+
+from typing import Any, List, Optional
+
+from ...core.phase1_signals import Signal
+from ..security.zero_trust_evidence import canonical_json
+
+
+UNHANDLED_FAILURE = "unhandled_exception"
+
+_STR_FIELDS = ("id", "type", "domain", "authority", "hash")
+_OPTIONAL_STR_FIELDS = ("source", "correlation_id")
+
+
+def screen(signal: Any, context: Any = None) -> List[str]:
+    """Shape problems that would make the kernel raise. Empty means safe."""
+    problems: List[str] = []
+    if context is not None and not isinstance(context, dict):
+        problems.append(f"context must be a dict, got {type(context).__name__}")
+    if not isinstance(signal, Signal):
+        problems.append(f"not a Signal: {type(signal).__name__}")
+        return problems
+
+    for name in _STR_FIELDS:
+        if not isinstance(getattr(signal, name, None), str):
+            problems.append(f"{name} must be a string")
+    for name in _OPTIONAL_STR_FIELDS:
+        value = getattr(signal, name, None)
+        if value is not None and not isinstance(value, str):
+            problems.append(f"{name} must be a string or None")
+    ts = getattr(signal, "timestamp", None)
+    if isinstance(ts, bool) or not isinstance(ts, (int, float)):
+        problems.append("timestamp must be a number")
+    try:
+        canonical_json(signal.payload)
+    except (TypeError, ValueError) as e:
+        problems.append(f"payload not serialisable: {e}")
+    return problems
+
+
+def guarded_process(engine, signal: Any, context: Optional[dict] = None) -> dict:
+    """
+    Process a signal through the engine without ever raising.
+
+    INVARIANT: Malformed input is contained and audited, never processed.
+    INVARIANT: An exception escaping the kernel halts the engine.
+    """
+    problems = screen(signal, context)
+    try:
+        if problems:
+            signal_id = getattr(signal, "id", None)
+            engine.ledger.write({
+                "signal_type": "containment",
+                "route": "guard",
+                "handler": "process_guard",
+                "outcome": "malformed_input_contained",
+                "signal_id": signal_id if isinstance(signal_id, str) else "malformed",
+                "signal_domain": "constitutional",
+                "extra": "; ".join(problems),
+            })
+            return {"processed": False, "stage": "guard", "violations": problems}
+        return engine.process(signal, context)
+    except Exception as e:
+        event = engine.health.report_failure(
+            "process_guard", UNHANDLED_FAILURE, f"{type(e).__name__}: {e}"
+        )
+        result = {
+            "processed": False,
+            "stage": "guard" if problems else "kernel_exception",
+            "error": f"{type(e).__name__}: {e}",
+            "action": event.action,
+        }
+        if problems:
+            result["violations"] = problems
+        return result
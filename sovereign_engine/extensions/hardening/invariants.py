@@ -0,0 +1,232 @@
+"""
+Invariant Monitor - Constitutional invariants re-checked at every mutation.
+
+The kernel states its invariants in docstrings and enforces most of them
+by construction. Integration environments want more: re-check them after
+every ledger write and every processed signal, and stop the test run at
+the first violation. Production must never abort on a check, so there
+the same violations are recorded to the audit ledger and kept for
+inspection instead, and each mutation verifies only the ledger entries
+added since the last check; an explicit `check()` verifies the whole
+chain.
+
+Strict mode is the `debug_invariants` switch: pass `strict=True`, or set
+SOVEREIGN_DEBUG_INVARIANTS=1 in the environment of an integration run.
+
+Detaching puts back exactly the methods that were there at attach time.
+If another extension has wrapped one of them since, detach refuses
+rather than strip that wrapper too.
+
+Checked:
+- ledger chain continuity (`AuditLedger.verify`, or from the last
+  verified entry on a production mutation)
+- halt gating: a halted engine routes nothing but halt signals
+- halt agreement: the router is never halted while the controller is not
+- roster lifecycle legality, when a roster is attached
+"""
+
+from __future__ import annotations
+
+import os
+from dataclasses import dataclass
+from typing import Any, Dict, List, Optional, Tuple
+
+from ...core.phase4_audit import GENESIS_HASH
+from ..quorum import ALLOWED_TRANSITIONS, TERMINAL_STATES
+
+
+DEBUG_INVARIANTS_ENV = "SOVEREIGN_DEBUG_INVARIANTS"
+
+
+def debug_invariants_enabled() -> bool:
+    return os.environ.get(DEBUG_INVARIANTS_ENV, "").lower() in ("1", "true", "yes")
+
+
+@dataclass(frozen=True)
+class Violation:
+    invariant: str
+    detail: str
+
+
+class InvariantViolation(Exception):
+    def __init__(self, violation: Violation):
+        super().__init__(f"{violation.invariant}: {violation.detail}")
+        self.violation = violation
+
+
+class InvariantMonitor:
+    """
+    INVARIANT: In strict mode the first violation raises InvariantViolation.
+    INVARIANT: Otherwise each distinct violation is recorded once, never raised.
+    """
+
+    def __init__(self, engine, roster=None, strict: Optional[bool] = None):
+        self._engine = engine
+        self._roster = roster
+        self._strict = debug_invariants_enabled() if strict is None else strict
+        self._violations: List[Violation] = []
+        self._seen: set = set()
+        self._roster_checked = 0
+        self._last_state: Dict[str, object] = {}
+        self._installed: Optional[List[Tuple[Any, str, Any, Optional[Any]]]] = None
+        self._busy = False
+        self._verified_ledger = None
+        self._verified = 0
+
+    # ──────────────────────────────────────────────
+    # CHECKS
+    # ──────────────────────────────────────────────
+
+    def check(self, full: bool = True) -> List[Violation]:
+        """Run every state check once. Returns violations found now."""
+        found: List[Violation] = []
+        corruptions = self._verify_ledger(full)
+        for c in corruptions:
+            found.append(Violation("ledger_chain_continuity",
+                                   f"entry {c['index']}: {c['reason']}"))
+        if self._engine.router.is_halted and not self._engine.halt_ctrl.is_halted:
+            found.append(Violation("halt_agreement",
+                                   "router halted while halt controller is running"))
+        if self._roster is not None:
+            found.extend(self._check_roster())
+        self._report(found)
+        return found
+
+    def _verify_ledger(self, full: bool) -> List[dict]:
+        ledger = self._engine.ledger
+        if full or ledger is not self._verified_ledger or ledger.length < self._verified:
+            corruptions = ledger.verify()["corruptions"]
+        else:
+            corruptions = self._verify_tail(ledger)
+        if not corruptions:
+            self._verified_ledger, self._verified = ledger, ledger.length
+        return corruptions
+
+    def _verify_tail(self, ledger) -> List[dict]:
+        # Same checks as AuditLedger.verify, from the last verified entry.
+        entries = ledger.entries
+        prev = entries[self._verified - 1].hash if self._verified else GENESIS_HASH
+        for entry in entries[self._verified:]:
+            if entry.previous_hash != prev:
+                return [{"index": entry.index, "reason": "previous_hash mismatch"}]
+            computed = ledger._compute_hash({
+                "index": entry.index,
+                "signal_type": entry.signal_type,
+                "route": entry.route,
+                "handler": entry.handler,
+                "outcome": entry.outcome,
+                "signal_id": entry.signal_id,
+                "signal_domain": entry.signal_domain,
+                "timestamp": entry.timestamp,
+                "previous_hash": entry.previous_hash,
+            })
+            if entry.hash != computed:
+                return [{"index": entry.index, "reason": "entry hash mismatch"}]
+            prev = entry.hash
+        return []
+
+    def _check_roster(self) -> List[Violation]:
+        found: List[Violation] = []
+        history = self._roster.history
+        for event in history[self._roster_checked:]:
+            previous = self._last_state.get(event.member_id)
+            if previous is not None and previous != event.from_state:
+                found.append(Violation(
+                    "lifecycle_continuity",
+                    f"{event.member_id} left {event.from_state.value} "
+                    f"but was {previous.value}",
+                ))
+            if previous in TERMINAL_STATES or \
+                    event.to_state not in ALLOWED_TRANSITIONS[event.from_state]:
+                found.append(Violation(
+                    "lifecycle_legality",
+                    f"{event.member_id}: {event.from_state.value} → {event.to_state.value}",
+                ))
+            self._last_state[event.member_id] = event.to_state
+        self._roster_checked = len(history)
+        return found
+
+    def _report(self, found: List[Violation]):
+        for v in found:
+            if self._strict:
+                raise InvariantViolation(v)
+            if v in self._seen:
+                continue
+            self._seen.add(v)
+            self._violations.append(v)
+            self._busy = True
+            try:
+                self._engine.ledger.write({
+                    "signal_type": "audit",
+                    "route": "invariant_monitor",
+                    "handler": "invariant_monitor",
+                    "outcome": "invariant_violation",
+                    "signal_id": v.invariant,
+                    "signal_domain": "constitutional",
+                    "extra": v.detail,
+                })
+            finally:
+                self._busy = False
+
+    # ──────────────────────────────────────────────
+    # INSTRUMENTATION
+    # ──────────────────────────────────────────────
+
+    def attach(self) -> "InvariantMonitor":
+        """Re-check after every ledger write and every processed signal."""
+        if self._installed is not None:
+            return self
+        engine = self._engine
+        write, process = engine.ledger.write, engine.process
+
+        def checked_write(record):
+            entry = write(record)
+            if not self._busy:
+                self.check(full=self._strict)
+            return entry
+
+        def checked_process(signal, context=None):
+            halted = engine.halt_ctrl.is_halted
+            before = engine.ledger.length
+            result = process(signal, context)
+            if halted and getattr(signal, "type", None) != "halt":
+                routed = [e for e in engine.ledger.entries[before:]
+                          if e.signal_id == signal.id and e.route != "legality_gate"]
+                if routed or result.get("processed"):
+                    self._report([Violation("halt_gating",
+                                            f"signal {signal.id} routed while halted")])
+            self.check(full=self._strict)
+            return result
+
+        self._installed = [
+            (engine.ledger, "write", checked_write, vars(engine.ledger).get("write")),
+            (engine, "process", checked_process, vars(engine).get("process")),
+        ]
+        engine.ledger.write = checked_write
+        engine.process = checked_process
+        return self
+
+    def detach(self):
+        """Put back what attach replaced. Refuses if it has been wrapped since."""
+        if self._installed is None:
+            return
+        for owner, name, wrapper, _ in self._installed:
+            if vars(owner).get(name) is not wrapper:
+                raise RuntimeError(
+                    f"{type(owner).__name__}.{name} was wrapped after the invariant "
+                    f"monitor attached; detach that wrapper first"
+                )
+        for owner, name, _, previous in self._installed:
+            if previous is None:
+                delattr(owner, name)
+            else:
+                setattr(owner, name, previous)
+        self._installed = None
+
+    @property
+    def strict(self) -> bool:
+        return self._strict
+
+    @property
+    def violations(self) -> List[Violation]:
+        return list(self._violations)
@@ -0,0 +1,79 @@
+import os
+import unittest
+from unittest import mock
+
+from sovereign_engine.core.phase3_legality import LegalityGate, LegalityResult
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.hardening import CANARY_FAILURE, CanaryAgent
+from sovereign_engine.extensions.pause import PauseController, sign_pause
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+def accept_everything(self, signal, context=None):
+    return LegalityResult(legal=True, signal=signal)
+
+
+class TestCanaryAgent(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.alerts = []
+        self.canary = CanaryAgent(self.engine, emit=self.alerts.append)
+
+    def test_sound_verifier_passes_without_side_effects(self):
+        length = self.engine.ledger.length
+        contained = len(self.engine.gate.containment_log)
+        run = self.canary.run(1)
+        self.assertTrue(run.ok)
+        self.assertEqual(len(run.passed), 6)
+        self.assertEqual(self.engine.ledger.length, length + 1)
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "canary_passed")
+        self.assertEqual(len(self.engine.gate.containment_log), contained)
+        self.assertFalse(self.engine.is_halted)
+
+    def test_verifier_that_accepts_everything_halts_the_engine(self):
+        with mock.patch.object(LegalityGate, "check", accept_everything):
+            run = self.canary.run(2)
+        self.assertIn("tampered_hash", run.compromised)
+        self.assertIn("silent_escalation", run.compromised)
+        self.assertTrue(self.engine.is_halted)
+        self.assertEqual(self.engine.halt_ctrl.halt_history[-1]["source"], "canary_agent")
+        self.assertEqual(self.alerts[-1]["domain"], "emergency")
+        self.assertEqual(self.alerts[-1]["payload"]["event"], CANARY_FAILURE)
+        self.assertIn("canary_failed", [e.outcome for e in self.engine.ledger.entries])
+        self.assertTrue(self.canary.run(3).skipped)
+
+    def test_replaced_live_gate_is_caught(self):
+        self.engine.gate.check = lambda signal, context=None: accept_everything(None, signal)
+        run = self.canary.run(4)
+        self.assertEqual(run.compromised[0], "live_gate_replaced")
+        self.assertIn("tampered_hash", run.compromised)
+        self.assertTrue(self.engine.is_halted)
+
+    def test_tampered_live_gate_state_is_caught(self):
+        gate = self.engine.gate
+        gate._check_forbidden = lambda signal, context, violations: None
+        gate._forbidden = frozenset()
+        checked, contained = gate.stats.checked, len(gate.containment_log)
+        run = self.canary.run(6)
+        self.assertIn("silent_escalation", run.compromised)
+        self.assertIn("cross_authority_direct_call", run.compromised)
+        self.assertNotIn("tampered_hash", run.compromised)
+        self.assertEqual((gate.stats.checked, len(gate.containment_log)), (checked, contained))
+        self.assertTrue(self.engine.is_halted)
+
+    def test_blocked_good_input_is_degraded_not_compromised(self):
+        pauses = PauseController(self.engine, {"op1": b"k"}.get)
+        pauses.pause("op1", "migration", 600, sign_pause(b"k", "op1", 0, "migration", 600))
+        run = self.canary.run(5)
+        self.assertTrue(run.ok)
+        self.assertEqual(run.degraded, ["known_good_query"])
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "canary_degraded")
+        self.assertFalse(self.engine.is_halted)
+
+
+if __name__ == "__main__":
+    unittest.main()
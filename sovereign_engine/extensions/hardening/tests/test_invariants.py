@@ -0,0 +1,98 @@
+import os
+import unittest
+from dataclasses import replace
+from unittest import mock
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.hardening import (
+    DEBUG_INVARIANTS_ENV,
+    InvariantMonitor,
+    InvariantViolation,
+)
+from sovereign_engine.extensions.quorum import MemberState, MembershipTransition, QuorumRoster
+from sovereign_engine.extensions.storage import DurableState, MemoryStorage
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STORAGE_KEY = b"storage-key"
+
+
+class TestInvariantMonitor(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+
+    def _submit(self):
+        return self.engine.submit_and_process("command", "operational", "operator", {"x": 1})
+
+    def _corrupt(self, index):
+        entries = self.engine.ledger._entries
+        entries[index] = replace(entries[index], outcome="forged")
+
+    def test_clean_run_has_no_violations(self):
+        monitor = InvariantMonitor(self.engine, strict=True).attach()
+        for _ in range(5):
+            self._submit()
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self._submit()
+        self.assertEqual(monitor.violations, [])
+
+    def test_strict_mode_stops_at_first_mutation_after_corruption(self):
+        InvariantMonitor(self.engine, strict=True).attach()
+        self._submit()
+        self._corrupt(0)
+        with self.assertRaises(InvariantViolation) as ctx:
+            self._submit()
+        self.assertEqual(ctx.exception.violation.invariant, "ledger_chain_continuity")
+
+    def test_production_mode_records_each_violation_once(self):
+        monitor = InvariantMonitor(self.engine, strict=False).attach()
+        self._submit()
+        entries = self.engine.ledger._entries
+        entries.append(replace(entries[-1], index=len(entries)))
+        self._submit()
+        self._submit()
+        self.assertEqual(len(monitor.violations), 1)
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertEqual(outcomes.count("invariant_violation"), 1)
+        monitor.detach()
+        self.assertNotIn("process", vars(self.engine))
+
+    def test_production_mutations_verify_only_new_entries(self):
+        monitor = InvariantMonitor(self.engine, strict=False).attach()
+        self._submit()
+        self._corrupt(0)
+        self._submit()
+        self.assertEqual(monitor.violations, [])
+        self.assertEqual(monitor.check()[0].invariant, "ledger_chain_continuity")
+
+    def test_detach_keeps_wrappers_installed_by_others(self):
+        storage = MemoryStorage()
+        DurableState(self.engine, storage, STORAGE_KEY).attach()
+        monitor = InvariantMonitor(self.engine, strict=True).attach()
+        monitor.detach()
+        self._submit()
+        self.assertEqual(len(storage.keys("ledger")), self.engine.ledger.length)
+
+        monitor.attach()
+        DurableState(self.engine, MemoryStorage(), STORAGE_KEY).attach()
+        with self.assertRaises(RuntimeError):
+            monitor.detach()
+
+    def test_illegal_roster_transition_is_caught(self):
+        roster = QuorumRoster(["m1", "m2", "m3"], threshold=2, secret_resolver=lambda m: b"k")
+        roster._history.append(MembershipTransition(
+            "m1", MemberState.DECEASED, MemberState.ACTIVE, "", [], 3))
+        monitor = InvariantMonitor(self.engine, roster=roster, strict=False)
+        self.assertEqual(monitor.check()[0].invariant, "lifecycle_legality")
+
+    def test_strict_mode_defaults_from_environment(self):
+        with mock.patch.dict(os.environ, {DEBUG_INVARIANTS_ENV: "1"}):
+            self.assertTrue(InvariantMonitor(self.engine).strict)
+        with mock.patch.dict(os.environ, {DEBUG_INVARIANTS_ENV: ""}):
+            self.assertFalse(InvariantMonitor(self.engine).strict)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,17 @@
+from .directory import (
+    ROLES,
+    KeyDirectory,
+    KeyDirectoryError,
+    KeyEntry,
+    approve_revocation,
+    revocation_payload,
+)
+
+__all__ = [
+    "ROLES",
+    "KeyDirectory",
+    "KeyDirectoryError",
+    "KeyEntry",
+    "approve_revocation",
+    "revocation_payload",
+]
@@ -0,0 +1,183 @@
+"""
+Key Directory - One place that says which keys may sign, as what, until when.
+S3-EXT-036: Key lifecycle bolt-on.
+
+Every verification path in the extensions takes a `secret_resolver`, and
+until now each was handed a plain dict lookup: a key was valid forever,
+for any role, because nothing said otherwise. The directory holds the
+public side of every key — its commitment, role, registration and expiry
+— and produces role-scoped resolvers that existing paths accept
+unchanged. A resolver returns nothing for a key that is unknown in that
+role, expired, revoked, or whose keystore secret no longer matches its
+commitment, so `verify_hmac` fails closed everywhere at once.
+
+Revocation is an adjudicated act: it needs signed approvals from a
+threshold of active keys in the adjudicating role (stewards by default),
+the subject's own approval does not count, and a revoked key is never
+reinstated. Registrations and revocations are written to the audit
+ledger; `revocations` is the published revocation list.
+
+Dependency: Phase 4 (audit), S3-EXT-018 (genesis)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass
+from typing import Callable, Dict, List, Optional, Tuple
+
+from ..genesis import key_commitment
+from ..security.zero_trust_evidence import canonical_json, sign_hmac, verify_hmac
+
+
+ROLES = ("steward", "quorum_member", "operator", "node", "feed")
+
+
+class KeyDirectoryError(Exception):
+    pass
+
+
+@dataclass
+class KeyEntry:
+    key_id: str
+    role: str
+    commitment: str
+    registered_at: float
+    expires_at: Optional[float] = None
+    revoked_at: Optional[float] = None
+    revocation_reason: Optional[str] = None
+
+    def status(self, at: float) -> str:
+        if self.revoked_at is not None and at >= self.revoked_at:
+            return "revoked"
+        if self.expires_at is not None and at >= self.expires_at:
+            return "expired"
+        return "active"
+
+
+def revocation_payload(key_id: str, role: str, reason: str) -> dict:
+    return {"key_id": key_id, "role": role, "reason": reason, "action": "revoke"}
+
+
+def approve_revocation(approver_id: str, secret: bytes, key_id: str, role: str,
+                       reason: str) -> str:
+    return sign_hmac(secret, revocation_payload(key_id, role, reason))
+
+
+class KeyDirectory:
+    """
+    INVARIANT: A key ID is registered at most once per role.
+    INVARIANT: Revocation needs threshold approvals from other active adjudicators.
+    INVARIANT: A revoked key never resolves again.
+    """
+
+    def __init__(self, keystore: Callable[[str], Optional[bytes]],
+                 adjudicator_role: str = "steward", revocation_threshold: int = 2,
+                 ledger=None, clock: Callable[[], float] = time.time):
+        if adjudicator_role not in ROLES:
+            raise KeyDirectoryError(f"Unknown role: {adjudicator_role}")
+        if revocation_threshold < 1:
+            raise KeyDirectoryError("Revocation threshold must be at least 1")
+        self._keystore = keystore
+        self._adjudicator_role = adjudicator_role
+        self._threshold = revocation_threshold
+        self._ledger = ledger
+        self._clock = clock
+        self._entries: Dict[Tuple[str, str], KeyEntry] = {}
+
+    @classmethod
+    def from_genesis(cls, genesis, keystore: Callable[[str], Optional[bytes]],
+                     **kwargs) -> "KeyDirectory":
+        """Seed stewards and quorum members from their genesis commitments."""
+        directory = cls(keystore, **kwargs)
+        for steward_id, commitment in sorted(genesis.stewards.items()):
+            directory.register(steward_id, "steward", commitment)
+        for member_id, commitment in sorted(genesis.quorum_members.items()):
+            directory.register(member_id, "quorum_member", commitment)
+        return directory
+
+    def register(self, key_id: str, role: str, commitment: str,
+                 expires_at: Optional[float] = None) -> KeyEntry:
+        if role not in ROLES:
+            raise KeyDirectoryError(f"Unknown role: {role}")
+        if (role, key_id) in self._entries:
+            raise KeyDirectoryError(f"{key_id} is already registered as {role}")
+        now = self._clock()
+        if expires_at is not None and expires_at <= now:
+            raise KeyDirectoryError(f"{key_id} would be registered already expired")
+        entry = KeyEntry(key_id, role, commitment, now, expires_at)
+        self._entries[(role, key_id)] = entry
+        self._record("key_registered", entry,
+                     canonical_json({"commitment": commitment, "expires_at": expires_at}))
+        return entry
+
+    def revoke(self, key_id: str, role: str, reason: str,
+               approvals: Dict[str, str]) -> KeyEntry:
+        """Apply an adjudicated revocation. `approvals` maps adjudicator ID to signature."""
+        entry = self._entries.get((role, key_id))
+        if entry is None:
+            raise KeyDirectoryError(f"{key_id} is not registered as {role}")
+        if entry.revoked_at is not None:
+            raise KeyDirectoryError(f"{key_id} is already revoked as {role}")
+        if not reason:
+            raise KeyDirectoryError("Revocation requires a reason")
+
+        resolve = self.resolver(self._adjudicator_role)
+        payload = revocation_payload(key_id, role, reason)
+        approvers = sorted(
+            a for a, sig in approvals.items()
+            if not (a == key_id and role == self._adjudicator_role)
+            and verify_hmac(resolve(a), payload, sig)
+        )
+        if len(approvers) < self._threshold:
+            raise KeyDirectoryError(
+                f"Revocation of {key_id} needs {self._threshold} "
+                f"{self._adjudicator_role} approvals, got {len(approvers)}"
+            )
+        entry.revoked_at = self._clock()
+        entry.revocation_reason = reason
+        self._record("key_revoked", entry, canonical_json({"reason": reason, "approvers": approvers}))
+        return entry
+
+    def lookup(self, key_id: str, role: str, at: Optional[float] = None) -> Optional[KeyEntry]:
+        """The entry if the key may sign in this role at `at` (default now)."""
+        entry = self._entries.get((role, key_id))
+        if entry is None:
+            return None
+        return entry if entry.status(self._clock() if at is None else at) == "active" else None
+
+    def resolver(self, role: str) -> Callable[[str], Optional[bytes]]:
+        """A `secret_resolver` for one role, for any existing verification path."""
+        if role not in ROLES:
+            raise KeyDirectoryError(f"Unknown role: {role}")
+
+        def resolve(key_id: str) -> Optional[bytes]:
+            entry = self.lookup(key_id, role)
+            if entry is None:
+                return None
+            secret = self._keystore(key_id)
+            if secret is None or key_commitment(secret) != entry.commitment:
+                return None
+            return secret
+        return resolve
+
+    def _record(self, outcome: str, entry: KeyEntry, extra: str):
+        if self._ledger is None:
+            return
+        self._ledger.write({
+            "signal_type": "audit",
+            "route": "key_directory",
+            "handler": "key_directory",
+            "outcome": outcome,
+            "signal_id": f"{entry.role}:{entry.key_id}",
+            "signal_domain": "governance",
+            "extra": extra,
+        })
+
+    def entries(self, role: Optional[str] = None) -> List[KeyEntry]:
+        return [e for e in self._entries.values() if role is None or e.role == role]
+
+    @property
+    def revocations(self) -> List[KeyEntry]:
+        return sorted((e for e in self._entries.values() if e.revoked_at is not None),
+                      key=lambda e: e.revoked_at)
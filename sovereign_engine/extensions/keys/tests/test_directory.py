@@ -0,0 +1,101 @@
+import unittest
+
+from sovereign_engine.extensions.forensics import ForensicsBundle
+from sovereign_engine.extensions.genesis import REPO_ROOT, GenesisBuilder, key_commitment
+from sovereign_engine.extensions.keys import (
+    KeyDirectory,
+    KeyDirectoryError,
+    approve_revocation,
+)
+from sovereign_engine.extensions.recovery import (
+    PlaybookViolation,
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    sign_review,
+)
+from sovereign_engine.extensions.security import compute_kernel_fingerprint
+
+STEWARDS = ["s1", "s2", "s3"]
+MEMBERS = ["m1", "m2", "m3"]
+SECRETS = {k: f"secret-{k}".encode() for k in STEWARDS + MEMBERS + ["feed-1"]}
+KERNEL = compute_kernel_fingerprint(REPO_ROOT)
+
+
+class TestKeyDirectory(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.genesis = (
+            GenesisBuilder()
+            .with_archetype("federated")
+            .with_quorum_members({m: key_commitment(SECRETS[m]) for m in MEMBERS})
+            .with_stewards({s: key_commitment(SECRETS[s]) for s in STEWARDS})
+            .with_kernel_fingerprint(KERNEL)
+            .build("s1", SECRETS["s1"])
+        )
+        self.engine = self.genesis.boot_engine(SECRETS.get)
+        self.keys = KeyDirectory.from_genesis(self.genesis, SECRETS.get,
+                                              ledger=self.engine.ledger,
+                                              clock=lambda: self.now)
+
+    def _revoke(self, key_id, role, approvers, reason="compromised"):
+        approvals = {a: approve_revocation(a, SECRETS[a], key_id, role, reason)
+                     for a in approvers}
+        return self.keys.revoke(key_id, role, reason, approvals)
+
+    def test_resolvers_are_role_scoped_and_expire(self):
+        stewards = self.keys.resolver("steward")
+        self.assertEqual(stewards("s1"), SECRETS["s1"])
+        self.assertIsNone(stewards("m1"))
+        self.keys.register("feed-1", "feed", key_commitment(SECRETS["feed-1"]),
+                           expires_at=self.now + 60)
+        feeds = self.keys.resolver("feed")
+        self.assertEqual(feeds("feed-1"), SECRETS["feed-1"])
+        self.now += 61
+        self.assertIsNone(feeds("feed-1"))
+        self.assertEqual(self.keys.entries("feed")[0].status(self.now), "expired")
+        with self.assertRaises(KeyDirectoryError):
+            self.keys.register("s1", "steward", "00" * 32)
+
+    def test_revocation_is_adjudicated_and_permanent(self):
+        with self.assertRaises(KeyDirectoryError):
+            self._revoke("s3", "steward", ["s1", "s3"])      # self-approval does not count
+        forged = {"s1": approve_revocation("s1", SECRETS["s1"], "s3", "steward", "compromised"),
+                  "s2": "00" * 32}
+        with self.assertRaises(KeyDirectoryError):
+            self.keys.revoke("s3", "steward", "compromised", forged)
+
+        self._revoke("s3", "steward", ["s1", "s2"])
+        self.assertIsNone(self.keys.resolver("steward")("s3"))
+        self.assertEqual([e.key_id for e in self.keys.revocations], ["s3"])
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "key_revoked")
+        with self.assertRaises(KeyDirectoryError):
+            self._revoke("s3", "steward", ["s1", "s2"])
+        # A revoked steward can no longer approve anything.
+        with self.assertRaises(KeyDirectoryError):
+            self._revoke("m1", "quorum_member", ["s1", "s3"])
+
+    def test_revoked_keys_fail_existing_verification_paths(self):
+        self._revoke("s2", "steward", ["s1", "s3"])
+        stewards = self.keys.resolver("steward")
+        self.assertFalse(self.genesis.verify(lambda k: None))
+        self.assertTrue(self.genesis.verify(stewards))
+
+        self.engine.health.report_failure("router", "authority_breach", "bypass")
+        self.engine.health.report_healthy("router")
+        session = RecoverySession(
+            self.engine, RecoveryPlaybook.from_constitution(self.engine.constitution),
+            STEWARDS, stewards,
+        )
+        bundle = ForensicsBundle.capture(self.engine)
+        session.perform(RecoveryAction.CAPTURE_FORENSICS, bundle.bundle_hash)
+        action = RecoveryAction.CONSTITUTIONAL_REVIEW
+        sigs = {s: sign_review(SECRETS[s], session.halt_id, action, "") for s in ["s1", "s2"]}
+        with self.assertRaises(PlaybookViolation):
+            session.perform(action, "", sigs)
+        sigs["s3"] = sign_review(SECRETS["s3"], session.halt_id, action, "")
+        self.assertEqual(session.perform(action, "", sigs).actors, ["s1", "s3"])
+
+
+if __name__ == "__main__":
+    unittest.main()
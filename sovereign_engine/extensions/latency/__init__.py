@@ -0,0 +1,15 @@
+from .budget import (
+    STAGES,
+    BudgetError,
+    BudgetedPipeline,
+    LatencyBudget,
+    StageReport,
+)
+
+__all__ = [
+    "STAGES",
+    "BudgetError",
+    "BudgetedPipeline",
+    "LatencyBudget",
+    "StageReport",
+]
@@ -0,0 +1,170 @@
+"""
+Latency Budget - Where the routing ceiling is spent, per stage.
+S3-EXT-031: Latency accounting bolt-on.
+
+`max_routing_latency_ms` bounds a whole `process()` call; it says nothing
+about which stage ate the time. The budgeted pipeline times the three
+constitutional stages (legality, routing, audit) separately against
+sub-budgets, then runs optional diagnostics only while the cumulative
+time leaves room for them. Constitutional stages are never skipped or
+shortened — they run inside the kernel and this module only watches
+them. Diagnostics are the only thing that gives way.
+
+The whole call is measured through the kernel's TimingEnforcer, so a
+ceiling breach lands in the same breach list the watchdog and status
+surfaces already read.
+
+Dependency: Phase 5 (timing), Phase 8 (engine)
+"""
+
+from __future__ import annotations
+
+import statistics
+import time
+from collections import deque
+from dataclasses import dataclass, field
+from typing import Any, Callable, Deque, Dict, List, Optional, Tuple
+
+
+CONTRACT = "max_routing_latency_ms"
+STAGES = ("legality", "routing", "audit")
+
+# Default split of the ceiling; the rest is diagnostics headroom.
+DEFAULT_SHARES = {"legality": 0.3, "routing": 0.4, "audit": 0.2}
+
+
+class BudgetError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class LatencyBudget:
+    ceiling_ms: float
+    allocations_ms: Dict[str, float]
+    diagnostic_ms: Dict[str, float] = field(default_factory=dict)
+
+    def __post_init__(self):
+        unknown = set(self.allocations_ms) - set(STAGES)
+        if unknown:
+            raise BudgetError(f"Unknown stages: {sorted(unknown)}")
+        if sum(self.allocations_ms.values()) > self.ceiling_ms:
+            raise BudgetError(
+                f"Stage allocations {sum(self.allocations_ms.values())}ms "
+                f"exceed the {self.ceiling_ms}ms ceiling"
+            )
+
+    @classmethod
+    def from_constitution(cls, constitution, shares: Optional[Dict[str, float]] = None,
+                          diagnostic_ms: Optional[Dict[str, float]] = None) -> "LatencyBudget":
+        ceiling = constitution.get_timing(CONTRACT)
+        shares = shares or DEFAULT_SHARES
+        return cls(ceiling, {s: ceiling * f for s, f in shares.items()}, dict(diagnostic_ms or {}))
+
+
+@dataclass
+class StageReport:
+    stages_ms: Dict[str, float]
+    total_ms: float
+    over_budget: List[str]
+    diagnostics_run: List[str]
+    diagnostics_skipped: List[str]
+    ceiling_breached: bool
+
+
+class BudgetedPipeline:
+    """
+    INVARIANT: Every kernel stage runs; only diagnostics are ever skipped.
+    INVARIANT: A diagnostic starts only if its allocation still fits under the ceiling.
+    """
+
+    def __init__(self, engine, budget: Optional[LatencyBudget] = None,
+                 diagnostics: Optional[List[Tuple[str, Callable[[Any, dict], Any]]]] = None,
+                 history: int = 1000):
+        self._engine = engine
+        self._budget = budget or LatencyBudget.from_constitution(engine.constitution)
+        self._diagnostics = list(diagnostics or [])
+        self._reports: Deque[StageReport] = deque(maxlen=history)
+
+    def process(self, signal, context: Optional[dict] = None) -> Tuple[dict, StageReport]:
+        engine = self._engine
+        spent: Dict[str, float] = {s: 0.0 for s in STAGES}
+        targets = ((engine.gate, "check", "legality"),
+                   (engine.router, "route", "routing"),
+                   (engine.ledger, "write", "audit"))
+
+        def timed(fn, stage):
+            def wrapper(*args, **kwargs):
+                start = time.perf_counter()
+                try:
+                    return fn(*args, **kwargs)
+                finally:
+                    spent[stage] += (time.perf_counter() - start) * 1000.0
+            return wrapper
+
+        # Instance attributes already in place (a durable-state mirror, an
+        # invariant monitor) are wrapped, then put back exactly as found.
+        installed = []
+        for obj, name, stage in targets:
+            wrapper = timed(getattr(obj, name), stage)
+            installed.append((obj, name, wrapper, vars(obj).get(name)))
+            setattr(obj, name, wrapper)
+        start = time.perf_counter()
+        try:
+            result, breach = engine.timing.measure(
+                "engine", CONTRACT, engine.process, signal, context)
+        finally:
+            for obj, name, wrapper, previous in reversed(installed):
+                if vars(obj).get(name) is not wrapper:
+                    continue
+                if previous is None:
+                    delattr(obj, name)
+                else:
+                    setattr(obj, name, previous)
+
+        ran: List[str] = []
+        skipped: List[str] = []
+        for name, fn in self._diagnostics:
+            elapsed = (time.perf_counter() - start) * 1000.0
+            if elapsed + self._budget.diagnostic_ms.get(name, 0.0) > self._budget.ceiling_ms:
+                skipped.append(name)
+                continue
+            t0 = time.perf_counter()
+            fn(signal, result)
+            spent[name] = (time.perf_counter() - t0) * 1000.0
+            ran.append(name)
+
+        total = (time.perf_counter() - start) * 1000.0
+        over = [s for s, limit in self._budget.allocations_ms.items() if spent[s] > limit]
+        over += [d for d in ran if spent[d] > self._budget.diagnostic_ms.get(d, float("inf"))]
+        report = StageReport(
+            stages_ms=spent, total_ms=total, over_budget=over,
+            diagnostics_run=ran, diagnostics_skipped=skipped,
+            ceiling_breached=breach is not None or total > self._budget.ceiling_ms,
+        )
+        self._reports.append(report)
+        return result, report
+
+    def summary(self) -> Dict[str, Dict[str, float]]:
+        """p50 / p95 / max per stage over the retained history."""
+        out: Dict[str, Dict[str, float]] = {}
+        names = list(STAGES) + [n for n, _ in self._diagnostics]
+        for name in names:
+            samples = sorted(r.stages_ms[name] for r in self._reports if name in r.stages_ms)
+            if not samples:
+                continue
+            out[name] = {
+                "p50_ms": statistics.median(samples),
+                "p95_ms": samples[min(len(samples) - 1, int(len(samples) * 0.95))],
+                "max_ms": samples[-1],
+                "budget_ms": self._budget.allocations_ms.get(
+                    name, self._budget.diagnostic_ms.get(name, 0.0)),
+            }
+        return out
+
+    @property
+    def budget(self) -> LatencyBudget:
+        return self._budget
+
+    @property
+    def reports(self) -> List[StageReport]:
+        return list(self._reports)
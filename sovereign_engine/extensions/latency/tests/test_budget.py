@@ -0,0 +1,72 @@
+import os
+import time
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.latency import (
+    STAGES,
+    BudgetError,
+    BudgetedPipeline,
+    LatencyBudget,
+)
+from sovereign_engine.extensions.storage import DurableState, MemoryStorage
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STORAGE_KEY = b"storage-key"
+
+
+class TestLatencyBudget(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+
+    def _signal(self):
+        return self.engine.create_signal("command", "operational", "operator", {"x": 1})
+
+    def test_stages_are_timed_and_components_restored(self):
+        pipeline = BudgetedPipeline(self.engine)
+        result, report = pipeline.process(self._signal())
+        self.assertTrue(result["processed"])
+        self.assertEqual(set(report.stages_ms), set(STAGES))
+        self.assertTrue(all(v > 0 for v in report.stages_ms.values()))
+        self.assertNotIn("check", vars(self.engine.gate))
+        self.assertNotIn("write", vars(self.engine.ledger))
+        self.assertEqual(pipeline.budget.ceiling_ms, 500)
+        self.assertIn("audit", pipeline.summary())
+
+    def test_existing_wrappers_survive_processing(self):
+        storage = MemoryStorage()
+        DurableState(self.engine, storage, STORAGE_KEY).attach()
+        BudgetedPipeline(self.engine).process(self._signal())
+        self.engine.submit_and_process("command", "operational", "operator", {"x": 2})
+        self.assertEqual(len(storage.keys("ledger")), self.engine.ledger.length)
+
+    def test_diagnostics_give_way_when_the_ceiling_is_near(self):
+        ran = []
+        budget = LatencyBudget(20, {"legality": 5, "routing": 5, "audit": 5},
+                               {"slow": 5, "trace": 1})
+
+        def slow(signal, result):
+            ran.append("slow")
+            time.sleep(0.025)
+
+        pipeline = BudgetedPipeline(self.engine, budget,
+                                    [("slow", slow), ("trace", lambda s, r: ran.append("trace"))])
+        result, report = pipeline.process(self._signal())
+        self.assertTrue(result["processed"])
+        self.assertEqual(report.diagnostics_run, ["slow"])
+        self.assertEqual(report.diagnostics_skipped, ["trace"])
+        self.assertIn("slow", report.over_budget)
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "routed")
+
+    def test_allocations_must_fit_the_ceiling(self):
+        with self.assertRaises(BudgetError):
+            LatencyBudget(10, {"legality": 6, "routing": 6})
+        with self.assertRaises(BudgetError):
+            LatencyBudget(10, {"proof": 1})
+
+
+if __name__ == "__main__":
+    unittest.main()
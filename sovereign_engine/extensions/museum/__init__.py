@@ -0,0 +1,26 @@
+from .audit import ArchiveAudit, ArchiveAuditReport, ChallengeResult, pick
+from .queries import (
+    API_VERSION,
+    CanonProof,
+    InclusionProof,
+    Museum,
+    MuseumError,
+    Page,
+    verify_canon,
+    verify_inclusion,
+)
+
+__all__ = [
+    "API_VERSION",
+    "ArchiveAudit",
+    "ArchiveAuditReport",
+    "CanonProof",
+    "ChallengeResult",
+    "InclusionProof",
+    "Museum",
+    "MuseumError",
+    "Page",
+    "pick",
+    "verify_canon",
+    "verify_inclusion",
+]
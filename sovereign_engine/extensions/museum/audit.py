@@ -0,0 +1,174 @@
+"""
+Archive Audit - Challenge the archive to prove its exhibits are intact.
+
+Read APIs are only worth serving if what they serve has not rotted. An
+audit round derives its challenges from a nonce (an epoch seed's
+challenge nonce, in production), so nobody can prepare for the picks and
+an auditor can re-derive them. Each challenged ledger entry must come
+with an inclusion proof that reaches the current head, and the chain
+must still pass through any head that was anchored externally. Each
+challenged evidence item must still hash to its record, and the content
+hash recomputed from that record must sit under the canon root recorded
+outside the vault. The vault's own root proves nothing about itself: a
+vault rewritten consistently reports a consistent root.
+
+Every round is appended to the ledger. A failed challenge is also
+written as an `archive_corruption` entry and raised as an emergency
+alert.
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass, field
+from typing import Callable, List, Optional, Tuple
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex
+from .queries import CanonProof, Museum, MuseumError, verify_canon, verify_inclusion
+
+
+def pick(nonce: str, label: str, population: int, count: int) -> List[int]:
+    """Distinct indices in [0, population), derived from the nonce."""
+    count = min(count, population)
+    picked: List[int] = []
+    i = 0
+    while len(picked) < count:
+        n = int(sha256_hex(canonical_json([nonce, label, i])), 16) % population
+        if n not in picked:
+            picked.append(n)
+        i += 1
+    return sorted(picked)
+
+
+@dataclass(frozen=True)
+class ChallengeResult:
+    kind: str           # "ledger" or "evidence"
+    target: str
+    passed: bool
+    detail: str = ""
+
+
+@dataclass(frozen=True)
+class ArchiveAuditReport:
+    nonce: str
+    results: List[ChallengeResult]
+    at: float = field(default_factory=time.time)
+
+    @property
+    def passed(self) -> bool:
+        return all(r.passed for r in self.results)
+
+    @property
+    def failures(self) -> List[ChallengeResult]:
+        return [r for r in self.results if not r.passed]
+
+
+class ArchiveAudit:
+    """
+    INVARIANT: Challenges are a pure function of the nonce and archive size.
+    INVARIANT: Every round is ledgered; every failure is alerted.
+    """
+
+    def __init__(self, museum: Museum, ledger, vault=None,
+                 emit: Optional[Callable[[dict], object]] = None,
+                 ledger_challenges: int = 8, evidence_challenges: int = 4):
+        self._museum = museum
+        self._ledger = ledger
+        self._vault = vault
+        self._emit = emit
+        self._ledger_challenges = ledger_challenges
+        self._evidence_challenges = evidence_challenges
+        self._reports: List[ArchiveAuditReport] = []
+
+    def run(self, nonce: str,
+            anchored_head: Optional[Tuple[int, str]] = None,
+            canon_root: Optional[str] = None) -> ArchiveAuditReport:
+        """
+        One audit round. `anchored_head` is (ledger length, head hash);
+        `canon_root` is the evidence canon root as recorded elsewhere (a
+        succession manifest, an onboarding package). Without it, evidence
+        is only checked against the vault's current leaf set.
+        """
+        length = self._ledger.length
+        head = self._museum.head["head"]
+        results: List[ChallengeResult] = []
+
+        if anchored_head is not None:
+            at, expected = anchored_head
+            entries = self._ledger.entries
+            ok = 0 < at <= length and entries[at - 1].hash == expected
+            results.append(ChallengeResult("ledger", f"anchor@{at}", ok,
+                                           "" if ok else "anchored head not in chain"))
+
+        for index in pick(nonce, "ledger", length, self._ledger_challenges):
+            try:
+                ok = verify_inclusion(self._museum.entry_with_proof(index), head)
+            except MuseumError as e:
+                results.append(ChallengeResult("ledger", str(index), False, str(e)))
+                continue
+            results.append(ChallengeResult("ledger", str(index), ok,
+                                           "" if ok else "inclusion proof does not reach head"))
+
+        if self._vault is not None:
+            ids = self._vault.active_evidence()
+            leaves = self._vault.canon_leaves(ids)
+            for i in pick(nonce, "evidence", len(ids), self._evidence_challenges):
+                results.append(self._challenge_evidence(ids[i], leaves, canon_root))
+
+        report = ArchiveAuditReport(nonce=nonce, results=results)
+        self._reports.append(report)
+        self._record(report)
+        return report
+
+    def _challenge_evidence(self, evidence_id: str, leaves: List[str],
+                            canon_root: Optional[str]) -> ChallengeResult:
+        try:
+            if not self._vault.verify_evidence(evidence_id):
+                return ChallengeResult("evidence", evidence_id, False, "record hash mismatch")
+            record = self._vault.retrieve_evidence(evidence_id)["record"]
+            content = self._vault.content_hash(record["type"], record["data"])
+        except Exception as e:
+            return ChallengeResult("evidence", evidence_id, False, f"unreadable: {e}")
+        if content not in leaves:
+            return ChallengeResult("evidence", evidence_id, False, "content not in canon leaves")
+        if canon_root is not None and not verify_canon(CanonProof(content, leaves, canon_root)):
+            return ChallengeResult("evidence", evidence_id, False, "not under canon root")
+        return ChallengeResult("evidence", evidence_id, True)
+
+    def _record(self, report: ArchiveAuditReport):
+        for failure in report.failures:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "archive_audit",
+                "handler": "museum",
+                "outcome": "archive_corruption",
+                "signal_id": f"{failure.kind}:{failure.target}",
+                "signal_domain": "emergency",
+                "extra": failure.detail,
+            })
+        self._ledger.write({
+            "signal_type": "audit",
+            "route": "archive_audit",
+            "handler": "museum",
+            "outcome": "archive_audit_passed" if report.passed else "archive_audit_failed",
+            "signal_id": report.nonce,
+            "signal_domain": "governance",
+            "extra": canonical_json({"challenges": len(report.results),
+                                     "failures": len(report.failures)}),
+        })
+        if report.failures and self._emit:
+            self._emit({
+                "type": "alert",
+                "domain": "emergency",
+                "authority": "system",
+                "source": "archive_audit",
+                "payload": {
+                    "event": "archive_corruption",
+                    "nonce": report.nonce,
+                    "failures": [f"{f.kind}:{f.target}" for f in report.failures],
+                },
+            })
+
+    @property
+    def reports(self) -> List[ArchiveAuditReport]:
+        return list(self._reports)
@@ -0,0 +1,197 @@
+"""
+Museum - Stable, versioned, read-only queries over the audit record.
+S3-EXT-029: External research bolt-on.
+
+Researchers and auditors looking at a system from the outside need
+answers that do not shift between calls: the same query returns the same
+items in the same order, and pages are addressed by ledger index rather
+than by offset into a filtered list, so appends between calls never
+reshuffle a page. Nothing here writes; the museum holds no capability
+beyond reading the ledger and halt history it was given.
+
+Inclusion proofs let a reader holding only a published ledger head check
+that an entry belongs to the chain. The ledger hashes entries as SHA-256
+over sorted-key JSON of the chained fields; `extra` is not part of the
+chain, so proofs cover everything except it. Canon evidence is served
+from the evidence vault with the leaf set behind its canon root.
+
+There are no frozen-canon markers in this tree (see synth-3670 in
+docs/BACKLOG_TRIAGE.md); halts are the terminal events a museum serves.
+
+Dependency: Phase 4 (audit), Phase 5 (halt), S3-EXT-025 (recovery), evidence vault
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+from dataclasses import asdict, dataclass, field
+from typing import Any, Callable, Dict, List, Optional
+
+from ...core.phase4_audit import GENESIS_HASH
+from ..recovery import halt_id
+
+
+API_VERSION = "museum/1"
+MAX_PAGE = 500
+
+# Fields the ledger chains, in the ledger's own hashing.
+CHAINED_FIELDS = ("index", "signal_type", "route", "handler", "outcome",
+                  "signal_id", "signal_domain", "timestamp", "previous_hash")
+
+
+class MuseumError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class Page:
+    items: List[Dict[str, Any]]
+    next_cursor: Optional[int]
+    api_version: str = API_VERSION
+
+
+@dataclass(frozen=True)
+class InclusionProof:
+    """The entry plus every later entry's chained fields, up to the head."""
+    entry: Dict[str, Any]
+    successors: List[Dict[str, Any]] = field(default_factory=list)
+    api_version: str = API_VERSION
+
+
+@dataclass(frozen=True)
+class CanonProof:
+    """Content hashes whose concatenation, sorted, hashes to the canon root."""
+    content_hash: str
+    leaves: List[str]
+    root: str
+    api_version: str = API_VERSION
+
+
+def _chain_hash(fields: Dict[str, Any]) -> str:
+    data = {k: fields[k] for k in CHAINED_FIELDS}
+    return hashlib.sha256(json.dumps(data, sort_keys=True).encode()).hexdigest()
+
+
+def verify_inclusion(proof: InclusionProof, head: str) -> bool:
+    """Recompute the chain from the entry to `head`. Needs no ledger access."""
+    previous = proof.entry["previous_hash"]
+    for fields in [proof.entry] + list(proof.successors):
+        if fields["previous_hash"] != previous:
+            return False
+        previous = _chain_hash(fields)
+    return previous == head
+
+
+def verify_canon(proof: CanonProof) -> bool:
+    if proof.content_hash not in proof.leaves or proof.leaves != sorted(set(proof.leaves)):
+        return False
+    return hashlib.sha256("".join(proof.leaves).encode()).hexdigest() == proof.root
+
+
+class Museum:
+    """
+    INVARIANT: No query mutates the ledger or halt history.
+    INVARIANT: Results are ordered by ledger index (or halt sequence).
+
+    `halt_ctrl` is any halt history source: the kernel controller, or a
+    HaltLog for canonical halt IDs.
+    """
+
+    def __init__(self, ledger, halt_ctrl=None, vault=None):
+        self._ledger = ledger
+        self._halt_ctrl = halt_ctrl
+        self._vault = vault
+
+    @staticmethod
+    def _limit(limit: int) -> int:
+        if not 1 <= limit <= MAX_PAGE:
+            raise MuseumError(f"limit must be between 1 and {MAX_PAGE}")
+        return limit
+
+    def entries(self, cursor: int = 0, limit: int = 100,
+                where: Optional[Callable[[Any], bool]] = None,
+                outcome: Optional[str] = None, route: Optional[str] = None,
+                since: Optional[float] = None, until: Optional[float] = None) -> Page:
+        """Ledger entries from index `cursor` matching every given filter."""
+        limit = self._limit(limit)
+        entries = self._ledger.entries
+        items: List[Dict[str, Any]] = []
+        i = max(0, cursor)
+        while i < len(entries) and len(items) < limit:
+            e = entries[i]
+            i += 1
+            if outcome is not None and e.outcome != outcome:
+                continue
+            if route is not None and e.route != route:
+                continue
+            if since is not None and e.timestamp < since:
+                continue
+            if until is not None and e.timestamp >= until:
+                continue
+            if where is not None and not where(e):
+                continue
+            items.append(asdict(e))
+        return Page(items=items, next_cursor=i if i < len(entries) else None)
+
+    def halts(self, cursor: int = 0, limit: int = 100,
+              since: Optional[float] = None, until: Optional[float] = None) -> Page:
+        """Halt events in the order they happened, each with its halt ID."""
+        if self._halt_ctrl is None:
+            raise MuseumError("No halt history available to this museum")
+        limit = self._limit(limit)
+        history = self._halt_ctrl.halt_history
+        items: List[Dict[str, Any]] = []
+        i = max(0, cursor)
+        while i < len(history) and len(items) < limit:
+            h = history[i]
+            i += 1
+            if since is not None and h["timestamp"] < since:
+                continue
+            if until is not None and h["timestamp"] >= until:
+                continue
+            items.append({"sequence": i - 1, "halt_id": halt_id(h), **h})
+        return Page(items=items, next_cursor=i if i < len(history) else None)
+
+    def recovery_history(self, halt_ref: str, cursor: int = 0, limit: int = 100) -> Page:
+        """Every recovery step recorded against one halt."""
+        return self.entries(cursor, limit, route="recovery",
+                            where=lambda e: e.signal_id == halt_ref)
+
+    def canon_evidence(self, cursor: int = 0, limit: int = 100) -> Page:
+        """Active canon evidence in ID order, each with its canon-root proof."""
+        if self._vault is None:
+            raise MuseumError("No evidence vault available to this museum")
+        limit = self._limit(limit)
+        ids = self._vault.active_evidence()
+        root = self._vault.canon_root(ids)
+        leaves = self._vault.canon_leaves(ids)
+        items: List[Dict[str, Any]] = []
+        for eid in ids[max(0, cursor):max(0, cursor) + limit]:
+            evidence = self._vault.retrieve_evidence(eid)
+            proof = CanonProof(self._vault.content_hash_of(eid), leaves, root)
+            items.append({
+                "evidence_id": eid,
+                "evidence": evidence,
+                "intact": self._vault.verify_evidence(eid),
+                "proof": asdict(proof),
+            })
+        end = max(0, cursor) + limit
+        return Page(items=items, next_cursor=end if end < len(ids) else None)
+
+    def entry_with_proof(self, index: int) -> InclusionProof:
+        entries = self._ledger.entries
+        if not 0 <= index < len(entries):
+            raise MuseumError(f"No ledger entry at index {index}")
+        return InclusionProof(
+            entry=asdict(entries[index]),
+            successors=[asdict(e) for e in entries[index + 1:]],
+        )
+
+    @property
+    def head(self) -> Dict[str, Any]:
+        return {
+            "length": self._ledger.length,
+            "head": self._ledger.last_hash if self._ledger.length else GENESIS_HASH,
+            "api_version": API_VERSION,
+        }
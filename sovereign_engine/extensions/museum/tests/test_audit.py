@@ -0,0 +1,82 @@
+import hashlib
+import json
+import os
+import tempfile
+import unittest
+from dataclasses import replace
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.museum import ArchiveAudit, Museum, pick
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class TestArchiveAudit(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        for i in range(20):
+            self.engine.submit_and_process("command", "operational", "operator", {"i": i})
+        self.vault = EvidenceVault(self._tmp.name)
+        self.ids = [self.vault.store_evidence("log", {"line": i}) for i in range(5)]
+        self.alerts = []
+        self.audit = ArchiveAudit(Museum(self.engine.ledger, vault=self.vault),
+                                  self.engine.ledger, self.vault, emit=self.alerts.append,
+                                  ledger_challenges=20, evidence_challenges=5)
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def test_intact_archive_passes_and_round_is_ledgered(self):
+        anchor = (self.engine.ledger.length, self.engine.ledger.last_hash)
+        report = self.audit.run("nonce-1", anchored_head=anchor)
+        self.assertTrue(report.passed)
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "archive_audit_passed")
+        self.assertEqual(pick("nonce-1", "ledger", 21, 5), pick("nonce-1", "ledger", 21, 5))
+
+    def test_rotted_ledger_entry_fails_and_alerts(self):
+        entries = self.engine.ledger._entries
+        entries[10] = replace(entries[10], outcome="forged")
+        report = self.audit.run("nonce-2")
+        self.assertFalse(report.passed)
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertIn("archive_corruption", outcomes)
+        self.assertEqual(outcomes[-1], "archive_audit_failed")
+        self.assertEqual(self.alerts[-1]["payload"]["event"], "archive_corruption")
+
+    def test_rotted_evidence_and_rewritten_anchor_fail(self):
+        path = os.path.join(self._tmp.name, f"{self.ids[2]}.json")
+        with open(path) as f:
+            data = json.load(f)
+        data["record"]["data"]["line"] = 99
+        with open(path, "w") as f:
+            json.dump(data, f)
+        report = self.audit.run("nonce-3", anchored_head=(5, "f" * 64))
+        failed = {(r.kind, r.target) for r in report.failures}
+        self.assertIn(("evidence", self.ids[2]), failed)
+        self.assertIn(("ledger", "anchor@5"), failed)
+
+    def test_consistent_rewrite_fails_against_recorded_canon_root(self):
+        recorded = self.vault.canon_root()
+        path = os.path.join(self._tmp.name, f"{self.ids[2]}.json")
+        with open(path) as f:
+            data = json.load(f)
+        record = data["record"]
+        record["data"]["line"] = 99
+        record["content_hash"] = self.vault.content_hash(record["type"], record["data"])
+        data["hash"] = hashlib.sha256(json.dumps(record, sort_keys=True).encode()).hexdigest()
+        with open(path, "w") as f:
+            json.dump(data, f)
+        self.assertTrue(self.vault.verify_evidence(self.ids[2]))
+
+        report = self.audit.run("nonce-4", canon_root=recorded)
+        failed = {(r.kind, r.target): r.detail for r in report.failures}
+        self.assertEqual(failed[("evidence", self.ids[2])], "not under canon root")
+
+
+if __name__ == "__main__":
+    unittest.main()
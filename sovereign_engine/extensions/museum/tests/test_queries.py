@@ -0,0 +1,80 @@
+import os
+import tempfile
+import unittest
+from dataclasses import replace
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.museum import (
+    CanonProof, Museum, MuseumError, verify_canon, verify_inclusion,
+)
+from sovereign_engine.extensions.recovery import RecoveryPlaybook, RecoverySession
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class TestMuseum(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        for i in range(12):
+            self.engine.submit_and_process("command", "operational", "operator", {"i": i})
+        self.museum = Museum(self.engine.ledger, self.engine.halt_ctrl)
+
+    def test_pagination_is_stable_under_appends(self):
+        first = self.museum.entries(limit=5, route="operator")
+        self.engine.submit_and_process("command", "operational", "operator", {"late": 1})
+        second = self.museum.entries(cursor=first.next_cursor, limit=5, route="operator")
+        again = self.museum.entries(limit=5, route="operator")
+        self.assertEqual(first.items, again.items)
+        indexes = [e["index"] for e in first.items + second.items]
+        self.assertEqual(indexes, sorted(set(indexes)))
+        self.assertEqual(first.api_version, "museum/1")
+
+    def test_reads_do_not_write(self):
+        length = self.engine.ledger.length
+        self.museum.entries(limit=500)
+        self.museum.halts()
+        self.museum.entry_with_proof(3)
+        self.assertEqual(self.engine.ledger.length, length)
+
+    def test_inclusion_proof_verifies_against_head_only(self):
+        proof = self.museum.entry_with_proof(4)
+        head = self.museum.head["head"]
+        self.assertTrue(verify_inclusion(proof, head))
+        tampered = replace(proof, entry={**proof.entry, "outcome": "forged"})
+        self.assertFalse(verify_inclusion(tampered, head))
+
+    def test_halts_and_recovery_history(self):
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        session = RecoverySession(self.engine, RecoveryPlaybook.from_constitution(
+            self.engine.constitution), [], lambda s: b"")
+        halts = self.museum.halts().items
+        self.assertEqual(halts[0]["halt_id"], session.halt_id)
+        self.assertEqual(self.museum.recovery_history(session.halt_id).items, [])
+
+    def test_canon_evidence_carries_root_proof(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            vault = EvidenceVault(tmp)
+            old = vault.store_evidence("report", {"v": 1})
+            new = vault.store_evidence("report", {"v": 2})
+            vault.store_evidence("log", {"line": 1})
+            vault.supersede(old, new, "corrected")
+            museum = Museum(self.engine.ledger, vault=vault)
+            items = museum.canon_evidence(limit=1).items + museum.canon_evidence(cursor=1).items
+            self.assertNotIn(old, [i["evidence_id"] for i in items])
+            for item in items:
+                proof = CanonProof(**item["proof"])
+                self.assertTrue(item["intact"])
+                self.assertTrue(verify_canon(proof))
+                self.assertEqual(proof.root, vault.canon_root())
+
+    def test_limit_bounds(self):
+        with self.assertRaises(MuseumError):
+            self.museum.entries(limit=0)
+
+
+if __name__ == "__main__":
+    unittest.main()
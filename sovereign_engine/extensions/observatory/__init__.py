@@ -3,10 +3,21 @@ Observatory - Live telemetry, health, and drift monitoring.
 S3-EXT-006: Foundation monitoring bolt-on.
 """
 
+from .anomaly import AnomalyReport, MetricVerdict, RobustAnomalyDetector
+from .calibration import (
+    ALL_CLEAR,
+    CONFIRMED,
+    AuditOutcome,
+    CalibrationError,
+    SensitivityCalibrator,
+)
+
+
 class Observatory:
     def __init__(self):
         self.metrics = {}
         self.alerts = []
+        self.anomaly_detector = RobustAnomalyDetector()
     
     def collect_telemetry(self):
         """Collect system telemetry."""
@@ -50,6 +61,13 @@ class Observatory:
         
         return drift
     
+    def detect_anomalies(self, snapshot=None):
+        """Judge a snapshot (default: fresh telemetry) against its history."""
+        report = self.anomaly_detector.observe(snapshot or self.collect_telemetry())
+        if report.status == 'anomalous':
+            self.alerts.append({'type': 'anomaly', 'metrics': report.shifts})
+        return report
+
     def generate_health_report(self):
         """Generate health report."""
         metrics = self.collect_telemetry()
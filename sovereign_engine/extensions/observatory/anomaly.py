@@ -0,0 +1,135 @@
+"""
+Robust anomaly detection over observatory snapshot history.
+
+A single snapshot compared against a fixed threshold cannot tell a bad
+week from ordinary variance. Per metric, this keeps a rolling history and
+applies two robust tests:
+
+- MAD bands: a point further than `k` robust standard deviations
+  (1.4826 × median absolute deviation) from the median is an outlier.
+  Median and MAD ignore the outliers they are looking for, unlike mean
+  and standard deviation.
+- Two-sided CUSUM on robust z-scores: small deviations that persist
+  accumulate until they cross `h`, flagging a sustained shift that no
+  single point would trip.
+
+An isolated outlier is a warning; only a CUSUM shift is anomalous.
+Points under a suspected drift are held out of the baseline, so a real
+level change keeps alarming until an operator accepts it with
+`rebaseline`.
+"""
+
+import statistics
+from collections import deque
+from dataclasses import dataclass, field
+from typing import Deque, Dict, List
+
+MAD_SCALE = 1.4826
+
+
+@dataclass
+class MetricVerdict:
+    metric: str
+    value: float
+    median: float
+    robust_z: float
+    outlier: bool
+    shift: bool
+
+
+@dataclass
+class AnomalyReport:
+    status: str                      # learning | normal | warning | anomalous
+    verdicts: Dict[str, MetricVerdict] = field(default_factory=dict)
+
+    @property
+    def outliers(self) -> List[str]:
+        return [m for m, v in self.verdicts.items() if v.outlier]
+
+    @property
+    def shifts(self) -> List[str]:
+        return [m for m, v in self.verdicts.items() if v.shift]
+
+
+class RobustAnomalyDetector:
+    # h=8 rather than the textbook 5: sigma is estimated from a short
+    # window, and the extra margin offsets that estimation noise.
+    def __init__(self, window: int = 60, min_history: int = 15,
+                 mad_k: float = 3.5, cusum_k: float = 0.5, cusum_h: float = 8.0):
+        self.window = window
+        self.min_history = min_history
+        self.mad_k = mad_k
+        self.cusum_k = cusum_k
+        self.cusum_h = cusum_h
+        self._history: Dict[str, Deque[float]] = {}
+        self._cusum: Dict[str, List[float]] = {}
+
+    def observe(self, snapshot: Dict[str, object]) -> AnomalyReport:
+        """Judge a snapshot against history, then add it to history."""
+        verdicts: Dict[str, MetricVerdict] = {}
+        learning = False
+
+        for metric, raw in snapshot.items():
+            if isinstance(raw, bool) or not isinstance(raw, (int, float)):
+                continue
+            value = float(raw)
+            history = self._history.setdefault(metric, deque(maxlen=self.window))
+
+            if len(history) < self.min_history:
+                learning = True
+            else:
+                verdicts[metric] = self._judge(metric, value, list(history))
+
+            # Outliers and points under a suspected drift stay out of the
+            # baseline, so it cannot quietly follow the change it should catch.
+            if metric not in verdicts or not self._suspect(metric, verdicts[metric]):
+                history.append(value)
+
+        if not verdicts:
+            return AnomalyReport(status="learning" if learning else "normal")
+        if any(v.shift for v in verdicts.values()):
+            status = "anomalous"
+        elif any(v.outlier for v in verdicts.values()):
+            status = "warning"
+        else:
+            status = "normal"
+        return AnomalyReport(status=status, verdicts=verdicts)
+
+    def _suspect(self, metric: str, verdict: MetricVerdict) -> bool:
+        hi, lo = self._cusum.get(metric, [0.0, 0.0])
+        return verdict.outlier or verdict.shift or max(hi, lo) > self.cusum_h / 2
+
+    def rebaseline(self, metric: str):
+        """Accept a confirmed level change: forget history for a metric."""
+        self._history.pop(metric, None)
+        self._cusum.pop(metric, None)
+
+    def _judge(self, metric: str, value: float, history: List[float]) -> MetricVerdict:
+        median = statistics.median(history)
+        mad = statistics.median(abs(x - median) for x in history)
+        sigma = MAD_SCALE * mad
+        if sigma == 0:
+            # A perfectly flat history: any change at all is a deviation.
+            z = 0.0 if value == median else float("inf") * (1 if value > median else -1)
+        else:
+            z = (value - median) / sigma
+
+        hi, lo = self._cusum.get(metric, [0.0, 0.0])
+        # One point may contribute at most half the decision interval, so a
+        # lone spike cannot trip the CUSUM; that is the MAD test's job.
+        cap = self.cusum_h / 2
+        bounded = max(-cap, min(cap, z))
+        hi = max(0.0, hi + bounded - self.cusum_k)
+        lo = max(0.0, lo - bounded - self.cusum_k)
+        shift = hi > self.cusum_h or lo > self.cusum_h
+        # Reset after a detected change so the next shift is measured fresh.
+        self._cusum[metric] = [0.0, 0.0] if shift else [hi, lo]
+
+        return MetricVerdict(
+            metric=metric,
+            value=value,
+            median=median,
+            robust_z=z,
+            outlier=abs(z) > self.mad_k,
+            shift=shift,
+        )
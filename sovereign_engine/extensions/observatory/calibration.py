@@ -0,0 +1,145 @@
+"""
+Sensitivity calibration from audit outcomes.
+
+A detector that cries wolf gets ignored; one that stays quiet through a
+real incident is worse. When an audit reviews what the detector did, its
+conclusion is the ground truth the detector never sees. The calibrator
+feeds that back:
+
+- flagged, audit all clear (false positive): thresholds loosen a little
+- not flagged, audit confirms an incident (false negative): thresholds
+  tighten by a larger step, since a miss costs more than a false alarm
+- agreement in either direction leaves the thresholds alone
+
+Every adjustment stays inside declared bounds, which must be finite, so
+no run of all-clear audits can loosen detection into silence. Outcomes
+are kept with their time so false-positive and false-negative rates can
+be read per period, and `drift_report` shows how far the thresholds have
+moved from where they started.
+"""
+
+import math
+import time
+from dataclasses import dataclass
+from typing import Callable, Dict, List, Optional, Tuple
+
+from ..security.zero_trust_evidence import canonical_json
+
+ALL_CLEAR = "all_clear"
+CONFIRMED = "confirmed"
+
+# Tunables the calibrator may move, on RobustAnomalyDetector.
+PARAMETERS = ("mad_k", "cusum_h")
+
+
+class CalibrationError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class AuditOutcome:
+    subject: str
+    flagged: bool
+    verdict: str
+    at: float
+
+    @property
+    def kind(self) -> str:
+        if self.flagged:
+            return "true_positive" if self.verdict == CONFIRMED else "false_positive"
+        return "false_negative" if self.verdict == CONFIRMED else "true_negative"
+
+
+class SensitivityCalibrator:
+    """
+    INVARIANT: Thresholds never leave their declared, finite bounds.
+    INVARIANT: Only a disagreement between detector and audit moves a threshold.
+    """
+
+    def __init__(self, detector, bounds: Optional[Dict[str, Tuple[float, float]]] = None,
+                 loosen: float = 1.05, tighten: float = 0.85, ledger=None,
+                 clock: Callable[[], float] = time.time):
+        self._detector = detector
+        self._baseline = {p: getattr(detector, p) for p in PARAMETERS}
+        self._bounds = bounds or {p: (v * 0.75, v * 1.5) for p, v in self._baseline.items()}
+        for p in PARAMETERS:
+            lo, hi = self._bounds.get(p, (None, None))
+            if lo is None or not (0 < lo <= self._baseline[p] <= hi) or not math.isfinite(hi):
+                raise CalibrationError(f"{p}: bounds must be finite and contain the baseline")
+        if not (loosen > 1 and 0 < tighten < 1):
+            raise CalibrationError("loosen must exceed 1 and tighten lie in (0, 1)")
+        self._factors = {"false_positive": loosen, "false_negative": tighten}
+        self._ledger = ledger
+        self._clock = clock
+        self._outcomes: List[AuditOutcome] = []
+
+    def record(self, subject: str, flagged: bool, verdict: str,
+               at: Optional[float] = None) -> AuditOutcome:
+        if verdict not in (ALL_CLEAR, CONFIRMED):
+            raise CalibrationError(f"Verdict must be {ALL_CLEAR!r} or {CONFIRMED!r}")
+        outcome = AuditOutcome(subject, flagged, verdict, self._clock() if at is None else at)
+        self._outcomes.append(outcome)
+        factor = self._factors.get(outcome.kind)
+        if factor is not None:
+            before = self.parameters
+            for p in PARAMETERS:
+                lo, hi = self._bounds[p]
+                setattr(self._detector, p, min(hi, max(lo, getattr(self._detector, p) * factor)))
+            if self._ledger is not None and self.parameters != before:
+                self._ledger.write({
+                    "signal_type": "audit",
+                    "route": "observatory",
+                    "handler": "sensitivity_calibrator",
+                    "outcome": "sensitivity_adjusted",
+                    "signal_id": subject,
+                    "signal_domain": "operational",
+                    "extra": canonical_json({"kind": outcome.kind, "before": before,
+                                             "after": self.parameters}),
+                })
+        return outcome
+
+    def rates(self, since: float = float("-inf"), until: float = float("inf")) -> Dict[str, float]:
+        """False-positive rate among flags and false-negative rate among incidents."""
+        window = [o for o in self._outcomes if since <= o.at < until]
+        flagged = [o for o in window if o.flagged]
+        incidents = [o for o in window if o.verdict == CONFIRMED]
+        return {
+            "audits": len(window),
+            "false_positive_rate": (sum(o.kind == "false_positive" for o in flagged)
+                                    / len(flagged)) if flagged else 0.0,
+            "false_negative_rate": (sum(o.kind == "false_negative" for o in incidents)
+                                    / len(incidents)) if incidents else 0.0,
+        }
+
+    def drift_report(self, bucket_s: float = 30 * 86400.0) -> Dict[str, object]:
+        """Thresholds against baseline, with error rates per time bucket."""
+        buckets: List[Dict[str, float]] = []
+        if self._outcomes:
+            start = min(o.at for o in self._outcomes)
+            end = max(o.at for o in self._outcomes)
+            t = start
+            while t <= end:
+                buckets.append({"from": t, **self.rates(t, t + bucket_s)})
+                t += bucket_s
+        return {
+            "parameters": {
+                p: {
+                    "baseline": self._baseline[p],
+                    "current": getattr(self._detector, p),
+                    "drift": getattr(self._detector, p) / self._baseline[p] - 1.0,
+                    "bounds": list(self._bounds[p]),
+                    "at_bound": getattr(self._detector, p) in self._bounds[p],
+                }
+                for p in PARAMETERS
+            },
+            "overall": self.rates(),
+            "buckets": buckets,
+        }
+
+    @property
+    def parameters(self) -> Dict[str, float]:
+        return {p: getattr(self._detector, p) for p in PARAMETERS}
+
+    @property
+    def outcomes(self) -> List[AuditOutcome]:
+        return list(self._outcomes)
@@ -0,0 +1,67 @@
+import itertools
+import unittest
+
+from sovereign_engine.extensions.observatory import Observatory, RobustAnomalyDetector
+
+# Repeating noise around 50: median 50, MAD 1, robust sigma ~1.48.
+NOISE = [48.0, 51.0, 50.0, 52.0, 49.0]
+
+
+class TestRobustAnomalyDetector(unittest.TestCase):
+    def setUp(self):
+        self.detector = RobustAnomalyDetector()
+        self.noise = itertools.cycle(NOISE)
+
+    def _feed(self, n, offset=0.0):
+        return [self.detector.observe({"cpu_percent": next(self.noise) + offset})
+                for _ in range(n)]
+
+    def _feed_constant(self, n, value):
+        return [self.detector.observe({"cpu_percent": value}) for _ in range(n)]
+
+    def test_learning_until_min_history(self):
+        reports = self._feed(15)
+        self.assertTrue(all(r.status == "learning" for r in reports))
+        self.assertEqual(self._feed(1)[0].status, "normal")
+
+    def test_normal_variance_is_not_flagged(self):
+        self._feed(15)
+        statuses = {r.status for r in self._feed(200)}
+        self.assertEqual(statuses, {"normal"})
+
+    def test_single_spike_is_warning_not_anomaly(self):
+        self._feed(20)
+        report = self.detector.observe({"cpu_percent": 95.0})
+        self.assertEqual(report.status, "warning")
+        self.assertEqual(report.outliers, ["cpu_percent"])
+        self.assertEqual({r.status for r in self._feed(20)}, {"normal"})
+
+    def test_sustained_small_shift_is_detected_by_cusum(self):
+        self._feed(20)
+        # 52.5 sits inside the MAD band every time, but it persists.
+        reports = self._feed_constant(10, 52.5)
+        self.assertFalse(any(r.outliers for r in reports))
+        self.assertIn("anomalous", [r.status for r in reports])
+
+    def test_rebaseline_accepts_new_level(self):
+        self._feed(20)
+        self._feed_constant(10, 52.5)
+        self.detector.rebaseline("cpu_percent")
+        self._feed(15, offset=2.5)
+        self.assertEqual({r.status for r in self._feed(50, offset=2.5)}, {"normal"})
+
+    def test_non_numeric_fields_ignored(self):
+        report = self.detector.observe({"python_version": "3.12", "kernel_tests_passed": True})
+        self.assertEqual(report.status, "normal")
+
+    def test_observatory_records_anomaly_alert(self):
+        obs = Observatory()
+        for value in itertools.islice(self.noise, 20):
+            obs.detect_anomalies({"memory_percent": value - 10})
+        for _ in range(10):
+            obs.detect_anomalies({"memory_percent": 42.5})
+        self.assertEqual(obs.alerts[-1]["metrics"], ["memory_percent"])
+
+
+if __name__ == "__main__":
+    unittest.main()
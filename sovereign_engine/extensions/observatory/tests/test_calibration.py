@@ -0,0 +1,73 @@
+import itertools
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.observatory import (
+    ALL_CLEAR,
+    CONFIRMED,
+    CalibrationError,
+    RobustAnomalyDetector,
+    SensitivityCalibrator,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+DAY = 86400.0
+
+
+class TestSensitivityCalibrator(unittest.TestCase):
+    def setUp(self):
+        self.now = 0.0
+        self.detector = RobustAnomalyDetector()
+        self.calibrator = SensitivityCalibrator(self.detector, clock=lambda: self.now)
+
+    def test_false_positives_loosen_only_to_the_bound(self):
+        for i in range(100):
+            self.calibrator.record(f"flag-{i}", flagged=True, verdict=ALL_CLEAR)
+        self.assertAlmostEqual(self.detector.mad_k, 3.5 * 1.5)
+        self.assertAlmostEqual(self.detector.cusum_h, 8.0 * 1.5)
+
+        # Still detects: a large spike is flagged at the loosest setting.
+        noise = itertools.cycle([48.0, 51.0, 50.0, 52.0, 49.0])
+        for _ in range(20):
+            self.detector.observe({"cpu": next(noise)})
+        self.assertEqual(self.detector.observe({"cpu": 95.0}).status, "warning")
+
+    def test_misses_tighten_harder_and_agreement_changes_nothing(self):
+        engine = SovereignEngine(CONFIG_PATH)
+        engine.boot()
+        calibrator = SensitivityCalibrator(RobustAnomalyDetector(), ledger=engine.ledger,
+                                           clock=lambda: self.now)
+        calibrator.record("a", flagged=True, verdict=CONFIRMED)
+        calibrator.record("b", flagged=False, verdict=ALL_CLEAR)
+        self.assertEqual(calibrator.parameters, {"mad_k": 3.5, "cusum_h": 8.0})
+        calibrator.record("c", flagged=True, verdict=ALL_CLEAR)
+        calibrator.record("d", flagged=False, verdict=CONFIRMED)
+        self.assertLess(calibrator.parameters["mad_k"], 3.5)
+        outcomes = [e.outcome for e in engine.ledger.entries]
+        self.assertEqual(outcomes.count("sensitivity_adjusted"), 2)
+        with self.assertRaises(CalibrationError):
+            calibrator.record("e", flagged=True, verdict="maybe")
+        with self.assertRaises(CalibrationError):
+            SensitivityCalibrator(RobustAnomalyDetector(), bounds={
+                "mad_k": (1.0, float("inf")), "cusum_h": (4.0, 16.0)})
+
+    def test_rates_and_drift_report_by_period(self):
+        for i in range(4):
+            self.calibrator.record(f"m1-{i}", flagged=True, verdict=ALL_CLEAR if i else CONFIRMED)
+        self.now = 40 * DAY
+        self.calibrator.record("m2-a", flagged=True, verdict=CONFIRMED)
+        self.calibrator.record("m2-b", flagged=False, verdict=CONFIRMED)
+
+        report = self.calibrator.drift_report(bucket_s=30 * DAY)
+        first, second = report["buckets"]
+        self.assertAlmostEqual(first["false_positive_rate"], 0.75)
+        self.assertEqual(second["false_negative_rate"], 0.5)
+        self.assertEqual(report["overall"]["audits"], 6)
+        self.assertAlmostEqual(report["parameters"]["mad_k"]["drift"], 1.05 ** 3 * 0.85 - 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
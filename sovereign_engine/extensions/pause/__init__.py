@@ -0,0 +1,27 @@
+from .controller import (
+    DEFAULT_MAX_PAUSE_S,
+    PAUSE_OVERRUN,
+    PAUSE_REASON,
+    PAUSE_RULE,
+    Pause,
+    PauseController,
+    PauseError,
+    pause_payload,
+    resume_payload,
+    sign_pause,
+    sign_resume,
+)
+
+__all__ = [
+    "DEFAULT_MAX_PAUSE_S",
+    "PAUSE_OVERRUN",
+    "PAUSE_REASON",
+    "PAUSE_RULE",
+    "Pause",
+    "PauseController",
+    "PauseError",
+    "pause_payload",
+    "resume_payload",
+    "sign_pause",
+    "sign_resume",
+]
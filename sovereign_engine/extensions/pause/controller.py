@@ -0,0 +1,191 @@
+"""
+Pause Controller - A maintenance window is not a halt.
+S3-EXT-037: Governance pause bolt-on.
+
+Operators sometimes need the engine quiet for an hour: a migration, a
+key rotation, a handler redeploy. Using a halt for that pollutes the
+halt history, breaks halt-free streaks in state reports and drags a
+planned action through a recovery playbook. A pause is voluntary and
+reversible instead:
+
+- It is requested with an operator signature over a sequence number, so
+  a captured request cannot be replayed to pause again later. The next
+  sequence number is derived from the `pause_started` entries in the
+  ledger, so it carries over to a new controller on a restored ledger.
+- While paused, the legality gate contains ordinary signals with a pause
+  reason. Halt signals and the emergency and constitutional lanes still
+  pass; a pause never stands between the engine and its halt doctrine.
+- It has a declared duration capped by `max_pause_s`. A pause still open
+  at its deadline — found by `check()` or by a late resume — is escalated
+  to a real halt through the failure doctrine as `pause_overrun`.
+- A pause cannot start while the engine is halted.
+
+Starting, ending and overrunning are written to the audit ledger. The
+halt history is untouched unless a pause overruns.
+
+Dependency: Phase 3 (legality), Phase 4 (audit), Phase 6 (failure)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass
+from typing import Callable, List, Optional, Tuple
+
+from ..security.zero_trust_evidence import canonical_json, sign_hmac, verify_hmac
+
+
+PAUSE_RULE = "governance_pause"
+HANDLER = "pause_controller"
+PAUSE_REASON = "Governance pause in effect"
+PAUSE_OVERRUN = "pause_overrun"
+DEFAULT_MAX_PAUSE_S = 4 * 3600.0
+
+# Lanes that stay open during a pause.
+UNPAUSABLE_DOMAINS = frozenset({"emergency", "constitutional"})
+
+
+class PauseError(Exception):
+    pass
+
+
+def pause_payload(operator_id: str, sequence: int, reason: str, duration_s: float) -> dict:
+    return {"action": "pause", "operator_id": operator_id, "sequence": sequence,
+            "reason": reason, "duration_s": duration_s}
+
+
+def resume_payload(operator_id: str, sequence: int) -> dict:
+    return {"action": "resume", "operator_id": operator_id, "sequence": sequence}
+
+
+def sign_pause(secret: bytes, operator_id: str, sequence: int, reason: str,
+               duration_s: float) -> str:
+    return sign_hmac(secret, pause_payload(operator_id, sequence, reason, duration_s))
+
+
+def sign_resume(secret: bytes, operator_id: str, sequence: int) -> str:
+    return sign_hmac(secret, resume_payload(operator_id, sequence))
+
+
+@dataclass
+class Pause:
+    sequence: int
+    operator_id: str
+    reason: str
+    started_at: float
+    ends_at: float
+    ended_at: Optional[float] = None
+    ended_by: Optional[str] = None
+    outcome: Optional[str] = None       # resumed | overrun
+
+    @property
+    def is_open(self) -> bool:
+        return self.ended_at is None
+
+
+class PauseController:
+    """
+    INVARIANT: A pause never blocks halt signals or the emergency and constitutional lanes.
+    INVARIANT: A pause past its deadline becomes a halt.
+    INVARIANT: Each signed request is valid for one sequence number only.
+    """
+
+    def __init__(self, engine, secret_resolver: Callable[[str], Optional[bytes]],
+                 max_pause_s: float = DEFAULT_MAX_PAUSE_S,
+                 clock: Callable[[], float] = time.time):
+        self._engine = engine
+        self._resolve = secret_resolver
+        self._max_pause_s = max_pause_s
+        self._clock = clock
+        self._pauses: List[Pause] = []
+        engine.gate.add_rule(PAUSE_RULE, self._gate_rule)
+
+    def _gate_rule(self, signal, context) -> Tuple[bool, str]:
+        if self.current is None or signal.type == "halt" or signal.domain in UNPAUSABLE_DOMAINS:
+            return True, ""
+        return False, PAUSE_REASON
+
+    @property
+    def next_sequence(self) -> int:
+        used = [p.sequence for p in self._pauses] + [
+            int(e.signal_id.split("-", 1)[1]) for e in self._engine.ledger.entries
+            if e.handler == HANDLER and e.outcome == "pause_started"
+        ]
+        return max(used, default=-1) + 1
+
+    def pause(self, operator_id: str, reason: str, duration_s: float, signature: str) -> Pause:
+        if self._engine.is_halted:
+            raise PauseError("Cannot pause a halted engine")
+        if self.current is not None:
+            raise PauseError(f"Pause {self.current.sequence} is already in effect")
+        if not 0 < duration_s <= self._max_pause_s:
+            raise PauseError(f"Pause duration must be in (0, {self._max_pause_s}] seconds")
+        if not reason:
+            raise PauseError("A pause needs a reason")
+        sequence = self.next_sequence
+        payload = pause_payload(operator_id, sequence, reason, duration_s)
+        if not verify_hmac(self._resolve(operator_id), payload, signature):
+            raise PauseError(f"Invalid pause request from {operator_id}")
+        now = self._clock()
+        pause = Pause(sequence, operator_id, reason, now, now + duration_s)
+        self._pauses.append(pause)
+        self._record("pause_started", pause, canonical_json(payload))
+        return pause
+
+    def resume(self, operator_id: str, signature: str) -> Pause:
+        pause = self.current
+        if pause is None:
+            raise PauseError("No pause in effect")
+        if not verify_hmac(self._resolve(operator_id), resume_payload(operator_id, pause.sequence),
+                           signature):
+            raise PauseError(f"Invalid resume request from {operator_id}")
+        if self._clock() > pause.ends_at:
+            self._overrun(pause)
+            return pause
+        self._close(pause, operator_id, "resumed")
+        self._record("pause_ended", pause, operator_id)
+        return pause
+
+    def check(self) -> Optional[Pause]:
+        """Escalate a pause that has outlived its deadline. Call periodically."""
+        pause = self.current
+        if pause is not None and self._clock() > pause.ends_at:
+            self._overrun(pause)
+            return pause
+        return None
+
+    def _overrun(self, pause: Pause):
+        overdue = self._clock() - pause.ends_at
+        self._close(pause, None, "overrun")
+        self._record("pause_overrun", pause, f"{overdue:.0f}s past deadline")
+        if not self._engine.is_halted:
+            self._engine.health.report_failure(
+                "pause_controller", PAUSE_OVERRUN,
+                f"pause {pause.sequence} by {pause.operator_id} overran by {overdue:.0f}s",
+            )
+
+    def _close(self, pause: Pause, by: Optional[str], outcome: str):
+        pause.ended_at = self._clock()
+        pause.ended_by = by
+        pause.outcome = outcome
+
+    def _record(self, outcome: str, pause: Pause, extra: str):
+        self._engine.ledger.write({
+            "signal_type": "system",
+            "route": "pause",
+            "handler": HANDLER,
+            "outcome": outcome,
+            "signal_id": f"pause-{pause.sequence}",
+            "signal_domain": "governance",
+            "extra": extra,
+        })
+
+    @property
+    def current(self) -> Optional[Pause]:
+        if self._pauses and self._pauses[-1].is_open:
+            return self._pauses[-1]
+        return None
+
+    @property
+    def history(self) -> List[Pause]:
+        return list(self._pauses)
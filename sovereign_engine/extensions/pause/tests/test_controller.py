@@ -0,0 +1,92 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.pause import (
+    PAUSE_REASON,
+    PauseController,
+    PauseError,
+    sign_pause,
+    sign_resume,
+)
+from sovereign_engine.extensions.storage import DurableState, MemoryStorage
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STORAGE_KEY = b"storage-key"
+OPERATORS = {"op1": b"secret-op1"}
+
+
+class TestPauseController(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.pauses = PauseController(self.engine, OPERATORS.get, max_pause_s=3600,
+                                      clock=lambda: self.now)
+
+    def _pause(self, duration_s=600, reason="migration"):
+        seq = self.pauses.next_sequence
+        sig = sign_pause(OPERATORS["op1"], "op1", seq, reason, duration_s)
+        return self.pauses.pause("op1", reason, duration_s, sig)
+
+    def _resume(self):
+        seq = self.pauses.current.sequence
+        return self.pauses.resume("op1", sign_resume(OPERATORS["op1"], "op1", seq))
+
+    def test_pause_contains_ordinary_signals_but_not_the_halt_lanes(self):
+        self._pause()
+        result = self.engine.submit_and_process("command", "operational", "operator", {})
+        self.assertFalse(result["processed"])
+        self.assertIn(PAUSE_REASON, str(result))
+        self.assertTrue(self.engine.submit_and_process(
+            "alert", "emergency", "system", {}, source="ops")["processed"])
+        self.assertFalse(self.engine.is_halted)
+
+        self.now += 300
+        self.assertEqual(self._resume().outcome, "resumed")
+        self.assertTrue(self.engine.submit_and_process(
+            "command", "operational", "operator", {})["processed"])
+        self.assertEqual(self.engine.halt_ctrl.halt_history, [])
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertLess(outcomes.index("pause_started"), outcomes.index("pause_ended"))
+
+    def test_overrun_escalates_to_a_real_halt(self):
+        self._pause(duration_s=600)
+        self.now += 601
+        self.assertEqual(self.pauses.check().outcome, "overrun")
+        self.assertTrue(self.engine.is_halted)
+        self.assertEqual(self.engine.halt_ctrl.halt_history[-1]["source"], "pause_controller")
+        with self.assertRaises(PauseError):
+            self._pause()
+
+    def test_requests_are_signed_bounded_and_not_replayable(self):
+        with self.assertRaises(PauseError):
+            self._pause(duration_s=7200)
+        sig = sign_pause(OPERATORS["op1"], "op1", 0, "migration", 600)
+        with self.assertRaises(PauseError):
+            self.pauses.pause("op1", "migration", 600, "00" * 32)
+        self.pauses.pause("op1", "migration", 600, sig)
+        self._resume()
+        with self.assertRaises(PauseError):
+            self.pauses.pause("op1", "migration", 600, sig)   # sequence 0 already used
+
+    def test_request_is_not_replayable_after_restart(self):
+        storage = MemoryStorage()
+        DurableState(self.engine, storage, STORAGE_KEY).attach()
+        sig = sign_pause(OPERATORS["op1"], "op1", 0, "migration", 600)
+        self.pauses.pause("op1", "migration", 600, sig)
+        self._resume()
+
+        restarted = SovereignEngine(CONFIG_PATH)
+        restarted.boot()
+        DurableState.restore(restarted, storage, STORAGE_KEY)
+        pauses = PauseController(restarted, OPERATORS.get, clock=lambda: self.now)
+        self.assertEqual(pauses.next_sequence, 1)
+        with self.assertRaises(PauseError):
+            pauses.pause("op1", "migration", 600, sig)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,73 @@
+from .blackout import (
+    BlackoutAssessment,
+    BlackoutDetector,
+    BlackoutKind,
+    ChallengeOutcome,
+)
+from .contact import (
+    ContactEntry,
+    ContactError,
+    ContactMetrics,
+    ContactTranscript,
+    sign_attempt,
+    sign_response,
+)
+from .emergency import (
+    REDUCIBLE_ACTIONS,
+    DecisionFinalizer,
+    EmergencyQuorumPolicy,
+    QuorumDecision,
+    approve_decision,
+    decision_payload,
+)
+from .forecast import (
+    ForecastError,
+    QuorumForecast,
+    QuorumForecaster,
+    at_least,
+)
+from .roster import (
+    ALLOWED_TRANSITIONS,
+    TERMINAL_STATES,
+    Attestation,
+    MemberState,
+    MembershipTransition,
+    QuorumError,
+    QuorumRoster,
+    QuorumShortfall,
+    attest,
+    transition_payload,
+)
+
+__all__ = [
+    "ALLOWED_TRANSITIONS",
+    "REDUCIBLE_ACTIONS",
+    "TERMINAL_STATES",
+    "Attestation",
+    "BlackoutAssessment",
+    "BlackoutDetector",
+    "BlackoutKind",
+    "ChallengeOutcome",
+    "ContactEntry",
+    "ContactError",
+    "ContactMetrics",
+    "ContactTranscript",
+    "DecisionFinalizer",
+    "EmergencyQuorumPolicy",
+    "ForecastError",
+    "MemberState",
+    "MembershipTransition",
+    "QuorumDecision",
+    "QuorumError",
+    "QuorumForecast",
+    "QuorumForecaster",
+    "QuorumRoster",
+    "QuorumShortfall",
+    "approve_decision",
+    "at_least",
+    "attest",
+    "decision_payload",
+    "sign_attempt",
+    "sign_response",
+    "transition_payload",
+]
@@ -0,0 +1,165 @@
+"""
+Blackout Detector - Tells a communications blackout from a silent quorum.
+
+"Nobody answered" has two very different causes. If liveness challenges
+were never delivered, the channel is down and the members may be fine;
+if they were delivered and nobody answered, the members are the problem.
+The detector keeps the latest challenge outcome per active member, using
+transport acknowledgements to separate the two, and when the responding
+members can no longer field a quorum it raises the matching alert: a
+blackout goes to the emergency lane, an unresponsive quorum to governance.
+
+Dependency: S3-EXT-003 (quorum roster), Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass, field
+from enum import Enum
+from typing import Callable, Dict, List, Optional
+
+from .roster import QuorumRoster
+
+
+class ChallengeOutcome(str, Enum):
+    UNDELIVERED = "undelivered"         # no transport acknowledgement
+    DELIVERED_SILENT = "delivered_silent"
+    RESPONDED = "responded"
+
+
+class BlackoutKind(str, Enum):
+    CLEAR = "clear"
+    BLACKOUT = "blackout"
+    UNRESPONSIVE = "unresponsive"
+
+
+# Alert lane per kind. A dead channel is infrastructure, so it is an
+# emergency; members ignoring delivered challenges is a governance matter.
+ALERT_DOMAINS = {
+    BlackoutKind.BLACKOUT: ("emergency", "comms_blackout"),
+    BlackoutKind.UNRESPONSIVE: ("governance", "quorum_unresponsive"),
+}
+
+
+@dataclass(frozen=True)
+class ChallengeRecord:
+    member_id: str
+    challenge_id: str
+    outcome: ChallengeOutcome
+    at: float
+
+
+@dataclass(frozen=True)
+class BlackoutAssessment:
+    kind: BlackoutKind
+    responded: List[str]
+    undelivered: List[str]
+    silent: List[str]
+    unchallenged: List[str]
+    threshold: int
+    at: float = field(default_factory=time.time)
+
+    def to_payload(self) -> dict:
+        return {
+            "kind": self.kind.value,
+            "responded": list(self.responded),
+            "undelivered": list(self.undelivered),
+            "silent": list(self.silent),
+            "unchallenged": list(self.unchallenged),
+            "threshold": self.threshold,
+        }
+
+
+class BlackoutDetector:
+    """
+    INVARIANT: Only challenges inside the window are considered.
+    INVARIANT: An alert is raised on every change into a non-clear kind.
+    """
+
+    def __init__(self, roster: QuorumRoster, window_s: float = 3600.0,
+                 emit: Optional[Callable[[dict], object]] = None, ledger=None,
+                 clock: Callable[[], float] = time.time):
+        self._roster = roster
+        self._window_s = window_s
+        self._emit = emit
+        self._ledger = ledger
+        self._clock = clock
+        self._latest: Dict[str, ChallengeRecord] = {}
+        self._last_kind = BlackoutKind.CLEAR
+        self._assessments: List[BlackoutAssessment] = []
+
+    def record(self, member_id: str, challenge_id: str, delivered: bool,
+               responded: bool) -> ChallengeRecord:
+        self._roster.state_of(member_id)
+        if responded:
+            outcome = ChallengeOutcome.RESPONDED
+        elif delivered:
+            outcome = ChallengeOutcome.DELIVERED_SILENT
+        else:
+            outcome = ChallengeOutcome.UNDELIVERED
+        rec = ChallengeRecord(member_id, challenge_id, outcome, self._clock())
+        self._latest[member_id] = rec
+        return rec
+
+    def assess(self) -> BlackoutAssessment:
+        now = self._clock()
+        buckets: Dict[ChallengeOutcome, List[str]] = {o: [] for o in ChallengeOutcome}
+        unchallenged: List[str] = []
+        for member in self._roster.active_members:
+            rec = self._latest.get(member)
+            if rec is None or now - rec.at > self._window_s:
+                unchallenged.append(member)
+            else:
+                buckets[rec.outcome].append(member)
+
+        responded = buckets[ChallengeOutcome.RESPONDED]
+        undelivered = buckets[ChallengeOutcome.UNDELIVERED]
+        silent = buckets[ChallengeOutcome.DELIVERED_SILENT]
+        threshold = self._roster.threshold
+
+        if len(responded) >= threshold or not (undelivered or silent):
+            kind = BlackoutKind.CLEAR
+        elif len(undelivered) >= len(silent):
+            kind = BlackoutKind.BLACKOUT
+        else:
+            kind = BlackoutKind.UNRESPONSIVE
+
+        assessment = BlackoutAssessment(kind, responded, undelivered, silent,
+                                        unchallenged, threshold, at=now)
+        self._assessments.append(assessment)
+        if kind != self._last_kind:
+            self._on_change(assessment)
+        self._last_kind = kind
+        return assessment
+
+    def _on_change(self, assessment: BlackoutAssessment):
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "quorum_blackout",
+                "handler": "blackout_detector",
+                "outcome": f"blackout_{assessment.kind.value}",
+                "signal_id": assessment.kind.value,
+                "signal_domain": "governance",
+                "extra": f"undelivered={len(assessment.undelivered)} "
+                         f"silent={len(assessment.silent)}",
+            })
+        if assessment.kind == BlackoutKind.CLEAR or not self._emit:
+            return
+        domain, event = ALERT_DOMAINS[assessment.kind]
+        self._emit({
+            "type": "alert",
+            "domain": domain,
+            "authority": "system",
+            "source": "blackout_detector",
+            "payload": {"event": event, **assessment.to_payload()},
+        })
+
+    @property
+    def current_kind(self) -> BlackoutKind:
+        return self._last_kind
+
+    @property
+    def assessments(self) -> List[BlackoutAssessment]:
+        return list(self._assessments)
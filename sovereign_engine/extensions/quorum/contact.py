@@ -0,0 +1,222 @@
+"""
+Contact Transcript - Signed record of attempts to reach quorum members.
+
+When a vote is needed under stress, "member X could not be reached" must
+be evidence, not an assertion. Every contact attempt is signed by the
+member making it and every response by the member answering; entries are
+hash-chained so the transcript cannot be edited after the fact. No-show
+streaks and days absent are derived from the transcript rather than
+entered by hand, and `evidence()` yields the chain segment to cite when
+proposing an incapacity transition. An attempt's signed time must be
+within `max_skew_s` of the transcript clock, so a member cannot backdate
+attempts into a no-show streak.
+
+Dependency: S3-EXT-003 (quorum roster), Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass
+from typing import Callable, Dict, List, Optional
+
+from ..security.zero_trust_evidence import sha256_hex, sign_hmac, verify_hmac
+from .roster import MemberState, QuorumRoster
+
+
+DAY_S = 86400.0
+MAX_ATTEMPT_SKEW_S = 300.0
+
+
+class ContactError(Exception):
+    pass
+
+
+def attempt_payload(member_id: str, channel: str, contacted_by: str,
+                    attempted_at: float) -> dict:
+    return {
+        "member_id": member_id,
+        "channel": channel,
+        "contacted_by": contacted_by,
+        "attempted_at": attempted_at,
+    }
+
+
+def response_payload(attempt_hash: str, member_id: str, response: str) -> dict:
+    return {"attempt_hash": attempt_hash, "member_id": member_id, "response": response}
+
+
+def sign_attempt(secret: bytes, member_id: str, channel: str, contacted_by: str,
+                 attempted_at: float) -> str:
+    return sign_hmac(bytes(secret), attempt_payload(member_id, channel, contacted_by,
+                                                    attempted_at))
+
+
+def sign_response(secret: bytes, attempt_hash: str, member_id: str, response: str) -> str:
+    return sign_hmac(bytes(secret), response_payload(attempt_hash, member_id, response))
+
+
+@dataclass(frozen=True)
+class ContactEntry:
+    kind: str                 # attempt | response
+    member_id: str
+    channel: str
+    signer_id: str
+    at: float
+    refers_to: str            # attempt hash for responses, "" for attempts
+    response: str
+    signature: str
+    previous_hash: str
+    hash: str
+
+
+@dataclass(frozen=True)
+class ContactMetrics:
+    member_id: str
+    attempts: int
+    responses: int
+    no_show_streak: int
+    days_absent: float
+    last_response_at: Optional[float]
+
+
+class ContactTranscript:
+    """
+    Hash-chained transcript of contact attempts and responses.
+
+    INVARIANT: Attempts are signed by an active member other than the subject.
+    INVARIANT: Attempt times are within max_skew_s of the transcript clock.
+    INVARIANT: Responses are signed by the subject, once per attempt.
+    INVARIANT: Entries are append-only and chained.
+    """
+
+    def __init__(self, roster: QuorumRoster, secret_resolver: Callable[[str], bytes],
+                 response_window_s: float = DAY_S, ledger=None,
+                 max_skew_s: float = MAX_ATTEMPT_SKEW_S,
+                 clock: Callable[[], float] = time.time):
+        self._roster = roster
+        self._secret_resolver = secret_resolver
+        self._response_window_s = response_window_s
+        self._ledger = ledger
+        self._max_skew_s = max_skew_s
+        self._clock = clock
+        self._entries: List[ContactEntry] = []
+        self._responded: Dict[str, ContactEntry] = {}
+
+    def record_attempt(self, member_id: str, channel: str, contacted_by: str,
+                       attempted_at: float, signature: str) -> ContactEntry:
+        self._roster.state_of(member_id)
+        if contacted_by == member_id:
+            raise ContactError("A member cannot record contact with themselves")
+        if self._roster.state_of(contacted_by) != MemberState.ACTIVE:
+            raise ContactError(f"{contacted_by} is not an active member")
+        skew = attempted_at - self._clock()
+        if abs(skew) > self._max_skew_s:
+            raise ContactError(
+                f"Attempt time is {skew:+.0f}s from the transcript clock "
+                f"(limit {self._max_skew_s:.0f}s)"
+            )
+        payload = attempt_payload(member_id, channel, contacted_by, attempted_at)
+        if not verify_hmac(self._secret_resolver(contacted_by), payload, signature):
+            raise ContactError(f"Invalid attempt signature from {contacted_by}")
+        return self._append("attempt", member_id, channel, contacted_by,
+                            attempted_at, "", "", signature)
+
+    def record_response(self, attempt_hash: str, response: str,
+                        signature: str) -> ContactEntry:
+        attempt = next((e for e in self._entries
+                        if e.kind == "attempt" and e.hash == attempt_hash), None)
+        if attempt is None:
+            raise ContactError(f"Unknown contact attempt: {attempt_hash[:12]}…")
+        if attempt_hash in self._responded:
+            raise ContactError("Attempt already answered")
+        payload = response_payload(attempt_hash, attempt.member_id, response)
+        if not verify_hmac(self._secret_resolver(attempt.member_id), payload, signature):
+            raise ContactError(f"Invalid response signature from {attempt.member_id}")
+        entry = self._append("response", attempt.member_id, attempt.channel,
+                             attempt.member_id, self._clock(), attempt_hash,
+                             response, signature)
+        self._responded[attempt_hash] = entry
+        return entry
+
+    def _append(self, kind, member_id, channel, signer_id, at, refers_to,
+                response, signature) -> ContactEntry:
+        previous = self._entries[-1].hash if self._entries else "0" * 64
+        body = {
+            "kind": kind, "member_id": member_id, "channel": channel,
+            "signer_id": signer_id, "at": at, "refers_to": refers_to,
+            "response": response, "signature": signature,
+            "previous_hash": previous,
+        }
+        entry = ContactEntry(hash=sha256_hex(body), **body)
+        self._entries.append(entry)
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "quorum_contact",
+                "handler": "contact_transcript",
+                "outcome": f"contact_{kind}",
+                "signal_id": entry.hash,
+                "signal_domain": "governance",
+                "extra": f"{member_id} via {channel}",
+            })
+        return entry
+
+    # ──────────────────────────────────────────────
+    # DERIVED METRICS
+    # ──────────────────────────────────────────────
+
+    def metrics(self, member_id: str) -> ContactMetrics:
+        """
+        An attempt is a no-show once its response window has elapsed with
+        no answer; attempts still inside the window count for neither side.
+        """
+        now = self._clock()
+        attempts = [e for e in self._entries
+                    if e.kind == "attempt" and e.member_id == member_id]
+        responses = [self._responded[a.hash] for a in attempts if a.hash in self._responded]
+
+        streak = 0
+        for a in reversed(attempts):
+            if a.hash in self._responded:
+                break
+            if now - a.at >= self._response_window_s:
+                streak += 1
+
+        last_response = max((r.at for r in responses), default=None)
+        if last_response is not None:
+            absent_since = last_response
+        elif attempts:
+            absent_since = attempts[0].at
+        else:
+            absent_since = now
+        return ContactMetrics(
+            member_id=member_id,
+            attempts=len(attempts),
+            responses=len(responses),
+            no_show_streak=streak,
+            days_absent=max(0.0, now - absent_since) / DAY_S if streak else 0.0,
+            last_response_at=last_response,
+        )
+
+    def evidence(self, member_id: str) -> List[dict]:
+        """Transcript entries concerning one member, for citing in a transition."""
+        return [asdict(e) for e in self._entries if e.member_id == member_id]
+
+    def verify(self) -> bool:
+        previous = "0" * 64
+        for e in self._entries:
+            body = asdict(e)
+            stored = body.pop("hash")
+            if body["previous_hash"] != previous or sha256_hex(body) != stored:
+                return False
+            previous = stored
+        return True
+
+    @property
+    def head(self) -> str:
+        return self._entries[-1].hash if self._entries else "0" * 64
+
+    @property
+    def entries(self) -> List[ContactEntry]:
+        return list(self._entries)
@@ -0,0 +1,167 @@
+"""
+Emergency Quorum - Pre-declared reduced thresholds for stopping actions.
+
+A full threshold is sized for ordinary business. When the roster cannot
+field it, the one thing a deployment must still be able to do is stop
+itself. An EmergencyQuorumPolicy, declared at genesis, lets a smaller
+quorum authorise a closed set of stopping actions while the roster is in
+shortfall — and nothing else. Canon changes, membership changes and
+resumption always need the full threshold, whatever the circumstances.
+
+The emergency is not something a member declares: it is the shortfall
+the roster itself surfaces, so a reduced quorum cannot talk itself into
+emergency powers.
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass
+from typing import Callable, Dict, FrozenSet, Iterable, List, Optional
+
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+from .roster import Attestation, MemberState, QuorumError
+
+
+# Actions that stop or narrow the system. Nothing outside this set may
+# ever be authorised by a reduced quorum.
+REDUCIBLE_ACTIONS: FrozenSet[str] = frozenset({"halt", "containment"})
+
+
+def decision_payload(decision_id: str, action: str, subject: str) -> dict:
+    """Canonical payload every approver signs for a decision."""
+    return {"decision_id": decision_id, "action": action, "subject": subject}
+
+
+def approve_decision(member_id: str, secret: bytes, decision_id: str,
+                     action: str, subject: str) -> Attestation:
+    payload = decision_payload(decision_id, action, subject)
+    return Attestation(attester_id=member_id, signature=sign_hmac(secret, payload))
+
+
+@dataclass(frozen=True)
+class EmergencyQuorumPolicy:
+    reduced_threshold: int
+    actions: FrozenSet[str] = frozenset({"halt"})
+
+    def validate(self, threshold: int) -> List[str]:
+        problems: List[str] = []
+        if not 1 <= self.reduced_threshold < threshold:
+            problems.append(
+                f"Reduced threshold {self.reduced_threshold} must be between 1 "
+                f"and the full threshold {threshold} (exclusive)"
+            )
+        if not self.actions:
+            problems.append("Emergency policy authorises no actions")
+        illegal = set(self.actions) - REDUCIBLE_ACTIONS
+        if illegal:
+            problems.append(f"Actions not reducible in an emergency: {sorted(illegal)}")
+        return problems
+
+    def to_dict(self) -> Dict:
+        return {"reduced_threshold": self.reduced_threshold, "actions": sorted(self.actions)}
+
+    @classmethod
+    def from_dict(cls, data: Dict) -> "EmergencyQuorumPolicy":
+        return cls(int(data["reduced_threshold"]), frozenset(data["actions"]))
+
+
+@dataclass(frozen=True)
+class QuorumDecision:
+    decision_id: str
+    action: str
+    subject: str
+    approvers: List[str]
+    threshold_applied: int
+    emergency: bool
+    finalized_at: float
+
+    @property
+    def hash(self) -> str:
+        return sha256_hex(canonical_json({
+            "decision_id": self.decision_id, "action": self.action,
+            "subject": self.subject, "approvers": self.approvers,
+            "threshold_applied": self.threshold_applied, "emergency": self.emergency,
+        }))
+
+
+class DecisionFinalizer:
+    """
+    Counts approvals for quorum decisions against the roster.
+
+    INVARIANT: The full threshold applies unless the roster is in shortfall.
+    INVARIANT: A reduced quorum never authorises an action outside the policy.
+    INVARIANT: Every finalized decision is audited when a ledger is attached.
+    """
+
+    def __init__(self, roster, secret_resolver: Callable[[str], bytes],
+                 policy: Optional[EmergencyQuorumPolicy] = None, ledger=None,
+                 clock: Callable[[], float] = time.time):
+        if policy is not None:
+            problems = policy.validate(roster.threshold)
+            if problems:
+                raise QuorumError("; ".join(problems))
+        self._roster = roster
+        self._secret_resolver = secret_resolver
+        self._policy = policy
+        self._ledger = ledger
+        self._clock = clock
+        self._finalized: Dict[str, QuorumDecision] = {}
+
+    def required(self, action: str) -> int:
+        """Approvals needed for an action given the roster's current state."""
+        if (self._policy is not None and not self._roster.quorum_achievable
+                and action in self._policy.actions):
+            return self._policy.reduced_threshold
+        return self._roster.threshold
+
+    def finalize(self, decision_id: str, action: str, subject: str,
+                 approvals: Iterable[Attestation]) -> QuorumDecision:
+        if decision_id in self._finalized:
+            raise QuorumError(f"Decision {decision_id} already finalized")
+
+        payload = decision_payload(decision_id, action, subject)
+        approvers: List[str] = []
+        for a in approvals:
+            if a.attester_id in approvers:
+                continue
+            if self._roster.state_of(a.attester_id) != MemberState.ACTIVE:
+                continue
+            if verify_hmac(self._secret_resolver(a.attester_id), payload, a.signature):
+                approvers.append(a.attester_id)
+
+        required = self.required(action)
+        if len(approvers) < required:
+            raise QuorumError(
+                f"Decision {decision_id} ({action}) has {len(approvers)}/{required} "
+                f"valid approvals"
+            )
+
+        decision = QuorumDecision(
+            decision_id=decision_id, action=action, subject=subject,
+            approvers=sorted(approvers), threshold_applied=required,
+            emergency=required < self._roster.threshold,
+            finalized_at=self._clock(),
+        )
+        self._finalized[decision_id] = decision
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "quorum_decision",
+                "handler": "quorum_roster",
+                "outcome": "emergency_decision_finalized" if decision.emergency
+                           else "decision_finalized",
+                "signal_id": decision.hash,
+                "signal_domain": "emergency" if decision.emergency else "governance",
+                "extra": canonical_json({"decision_id": decision_id, "action": action,
+                                         "approvers": decision.approvers}),
+            })
+        return decision
+
+    @property
+    def policy(self) -> Optional[EmergencyQuorumPolicy]:
+        return self._policy
+
+    @property
+    def finalized(self) -> List[QuorumDecision]:
+        return list(self._finalized.values())
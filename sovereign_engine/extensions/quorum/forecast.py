@@ -0,0 +1,117 @@
+"""
+Quorum Forecast - How likely the roster is to field a quorum when asked.
+
+`QuorumRoster.quorum_achievable` answers on paper: enough members are in
+the active state. Whether they will actually answer is a different
+question. The forecaster estimates each active member's availability
+from the signed contact transcript (response rate, shrunk towards a
+prior so one missed call is not a verdict, and halved for every
+unanswered attempt in the current streak), zeroes members on sabbatical
+or incapacitated, and combines them:
+
+- Members in the same region share that region's availability, so a
+  regional outage takes them out together rather than independently.
+- A common availability factor covers shocks that hit everyone at once.
+
+The result is the exact probability that at least `threshold` members
+are reachable under that model, computed by enumerating region outcomes
+and convolving member probabilities within each.
+
+Dependency: S3-EXT-003 (quorum roster)
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from itertools import product
+from typing import Dict, List, Optional
+
+from .roster import MemberState, QuorumRoster
+
+
+class ForecastError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class QuorumForecast:
+    threshold: int
+    probability: float
+    expected_available: float
+    members: Dict[str, float]
+    regions: Dict[str, float]
+
+
+def at_least(probabilities: List[float], k: int) -> float:
+    """P(at least k of independent events occur). Poisson-binomial by convolution."""
+    dist = [1.0]
+    for p in probabilities:
+        nxt = [0.0] * (len(dist) + 1)
+        for i, q in enumerate(dist):
+            nxt[i] += q * (1 - p)
+            nxt[i + 1] += q * p
+        dist = nxt
+    return sum(dist[k:]) if k < len(dist) else 0.0
+
+
+class QuorumForecaster:
+    """
+    INVARIANT: Only active members contribute; other states forecast as unavailable.
+    INVARIANT: Members sharing a region fail together with it.
+    """
+
+    def __init__(self, roster: QuorumRoster, contacts=None,
+                 regions: Optional[Dict[str, str]] = None,
+                 region_availability: Optional[Dict[str, float]] = None,
+                 prior: float = 0.95, prior_weight: float = 20.0):
+        if not 0.0 <= prior <= 1.0:
+            raise ForecastError("prior must be a probability")
+        self._roster = roster
+        self._contacts = contacts
+        self._regions = dict(regions or {})
+        self._region_availability = dict(region_availability or {})
+        for region, p in self._region_availability.items():
+            if not 0.0 <= p <= 1.0:
+                raise ForecastError(f"Availability for region {region} is not a probability")
+        self._prior = prior
+        self._prior_weight = prior_weight
+
+    def member_availability(self, member_id: str) -> float:
+        """Availability of one member, excluding regional and common factors."""
+        if self._roster.state_of(member_id) != MemberState.ACTIVE:
+            return 0.0
+        if self._contacts is None:
+            return self._prior
+        m = self._contacts.metrics(member_id)
+        rate = ((m.responses + self._prior * self._prior_weight)
+                / (m.attempts + self._prior_weight))
+        return rate * 0.5 ** m.no_show_streak
+
+    def forecast(self, common_availability: float = 1.0) -> QuorumForecast:
+        if not 0.0 <= common_availability <= 1.0:
+            raise ForecastError("common_availability must be a probability")
+        members = {m: self.member_availability(m) for m in sorted(self._roster.active_members)}
+        regions = sorted({self._regions[m] for m in members if m in self._regions})
+        region_p = {r: self._region_availability.get(r, 1.0) for r in regions}
+
+        probability = 0.0
+        expected = 0.0
+        for outcome in product((True, False), repeat=len(regions)):
+            up = dict(zip(regions, outcome))
+            weight = 1.0
+            for r in regions:
+                weight *= region_p[r] if up[r] else 1 - region_p[r]
+            if weight == 0.0:
+                continue
+            conditional = [p if up.get(self._regions.get(m), True) else 0.0
+                           for m, p in members.items()]
+            probability += weight * at_least(conditional, self._roster.threshold)
+            expected += weight * sum(conditional)
+
+        return QuorumForecast(
+            threshold=self._roster.threshold,
+            probability=common_availability * probability,
+            expected_available=common_availability * expected,
+            members=members,
+            regions=region_p,
+        )
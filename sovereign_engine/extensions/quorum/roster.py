@@ -0,0 +1,340 @@
+"""
+Quorum Roster - Membership lifecycle for federated quorum members.
+S3-EXT-003: Quorum Consensus Handler (Steward level).
+
+Rotation and sabbaticals are reversible; death, removal and long-term
+incapacity are not. Every lifecycle transition must be attested by other
+active members, is written to the audit ledger, and triggers a
+recomputation of the quorum the roster can still field. A shortfall below
+the archetype's quorum threshold is raised as an emergency alert — the
+kernel decides what happens next, the roster never does.
+
+Dependency: Phase 0 (constitution), Phase 4 (audit), Phase 7 (archetype)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import dataclass, field
+from enum import Enum
+from typing import Callable, Dict, Iterable, List, Optional
+
+from ..security.zero_trust_evidence import sign_hmac, verify_hmac
+
+
+class QuorumError(Exception):
+    """Raised on illegal or insufficiently attested roster operations."""
+    pass
+
+
+class MemberState(str, Enum):
+    ACTIVE = "active"
+    SABBATICAL = "sabbatical"
+    INCAPACITATED = "incapacitated"
+    DECEASED = "deceased"
+    REMOVED = "removed"
+    BACKUP = "backup"
+
+
+# Terminal states can never be left. Incapacity is recoverable because a
+# member may return from hospital; death and removal are not.
+TERMINAL_STATES = frozenset({MemberState.DECEASED, MemberState.REMOVED})
+
+ALLOWED_TRANSITIONS: Dict[MemberState, frozenset] = {
+    MemberState.ACTIVE: frozenset({
+        MemberState.SABBATICAL, MemberState.INCAPACITATED,
+        MemberState.DECEASED, MemberState.REMOVED,
+    }),
+    MemberState.SABBATICAL: frozenset({
+        MemberState.ACTIVE, MemberState.INCAPACITATED,
+        MemberState.DECEASED, MemberState.REMOVED,
+    }),
+    MemberState.INCAPACITATED: frozenset({
+        MemberState.ACTIVE, MemberState.DECEASED, MemberState.REMOVED,
+    }),
+    MemberState.DECEASED: frozenset(),
+    MemberState.REMOVED: frozenset(),
+    MemberState.BACKUP: frozenset({MemberState.ACTIVE, MemberState.REMOVED}),
+}
+
+
+def transition_payload(member_id: str, from_state: MemberState,
+                       to_state: MemberState, reason: str) -> dict:
+    """Canonical payload every attester signs for a transition."""
+    return {
+        "member_id": member_id,
+        "from_state": from_state.value,
+        "to_state": to_state.value,
+        "reason": reason,
+    }
+
+
+@dataclass(frozen=True)
+class Attestation:
+    attester_id: str
+    signature: str
+
+
+def attest(attester_id: str, secret: bytes, member_id: str,
+           from_state: MemberState, to_state: MemberState,
+           reason: str) -> Attestation:
+    """Produce an attestation for a proposed transition."""
+    payload = transition_payload(member_id, from_state, to_state, reason)
+    return Attestation(attester_id=attester_id, signature=sign_hmac(secret, payload))
+
+
+@dataclass
+class MembershipTransition:
+    member_id: str
+    from_state: MemberState
+    to_state: MemberState
+    reason: str
+    attesters: List[str]
+    achievable_quorum: int
+    timestamp: float = 0.0
+
+    def __post_init__(self):
+        if self.timestamp == 0.0:
+            self.timestamp = time.time()
+
+    def to_record(self) -> dict:
+        return {
+            "member_id": self.member_id,
+            "from_state": self.from_state.value,
+            "to_state": self.to_state.value,
+            "reason": self.reason,
+            "attesters": list(self.attesters),
+            "achievable_quorum": self.achievable_quorum,
+            "timestamp": self.timestamp,
+        }
+
+
+@dataclass
+class QuorumShortfall:
+    achievable_quorum: int
+    threshold: int
+    trigger_member: str
+    timestamp: float = field(default_factory=time.time)
+
+
+class QuorumRoster:
+    """
+    Lifecycle-aware roster of quorum members with a backup bench.
+
+    INVARIANT: No transition without the required attestations.
+    INVARIANT: Terminal states (deceased, removed) are never left.
+    INVARIANT: Every transition is audited when a ledger is attached.
+    INVARIANT: Shortfall below threshold is always surfaced, never absorbed.
+    """
+
+    def __init__(
+        self,
+        members: Iterable[str],
+        threshold: int,
+        secret_resolver: Callable[[str], bytes],
+        backups: Iterable[str] = (),
+        attestations_required: int = 2,
+        ledger=None,
+        emit: Optional[Callable[[dict], object]] = None,
+    ):
+        self._states: Dict[str, MemberState] = {
+            m: MemberState.ACTIVE for m in members
+        }
+        if threshold < 1:
+            raise QuorumError("Quorum threshold must be at least 1")
+        if threshold > len(self._states):
+            raise QuorumError(
+                f"Quorum threshold {threshold} exceeds roster size {len(self._states)}"
+            )
+        self._threshold = threshold
+        self._backups: List[str] = [b for b in backups if b not in self._states]
+        for b in self._backups:
+            self._states[b] = MemberState.BACKUP
+        self._secret_resolver = secret_resolver
+        self._attestations_required = attestations_required
+        self._ledger = ledger
+        self._emit = emit
+        self._history: List[MembershipTransition] = []
+        self._shortfalls: List[QuorumShortfall] = []
+        # Achievable quorum at the last alert of the current deficit; None
+        # once quorum is achievable again.
+        self._deficit_low: Optional[int] = None
+
+    @classmethod
+    def from_archetype(cls, compiled, members: Iterable[str],
+                       secret_resolver: Callable[[str], bytes],
+                       **kwargs) -> "QuorumRoster":
+        """Build a roster using the threshold of a compiled quorum archetype."""
+        if compiled.steward_mode != "quorum":
+            raise QuorumError(
+                f"Archetype '{compiled.name}' does not use quorum steward mode"
+            )
+        threshold = compiled.routing_overrides["quorum_threshold"]
+        return cls(members, threshold, secret_resolver, **kwargs)
+
+    # ──────────────────────────────────────────────
+    # LIFECYCLE
+    # ──────────────────────────────────────────────
+
+    def transition(self, member_id: str, to_state: MemberState,
+                   attestations: Iterable[Attestation],
+                   reason: str = "") -> MembershipTransition:
+        """
+        Apply an attested lifecycle transition.
+
+        Attesters must be distinct active members other than the subject:
+        a member cannot declare themselves dead, and nobody on sabbatical
+        can vouch for anyone.
+        """
+        if member_id not in self._states:
+            raise QuorumError(f"Unknown member: {member_id}")
+
+        from_state = self._states[member_id]
+        if to_state not in ALLOWED_TRANSITIONS[from_state]:
+            raise QuorumError(
+                f"Illegal transition for {member_id}: {from_state.value} → {to_state.value}"
+            )
+
+        attesters = self._verify_attestations(
+            member_id, from_state, to_state, reason, attestations
+        )
+
+        self._states[member_id] = to_state
+        if from_state == MemberState.BACKUP:
+            self._backups.remove(member_id)
+        event = MembershipTransition(
+            member_id=member_id,
+            from_state=from_state,
+            to_state=to_state,
+            reason=reason,
+            attesters=attesters,
+            achievable_quorum=self.achievable_quorum,
+        )
+        self._record(event)
+
+        if from_state != MemberState.BACKUP and to_state in (
+                MemberState.INCAPACITATED, MemberState.DECEASED, MemberState.REMOVED):
+            self._promote_backup(trigger=member_id)
+
+        self._recompute(trigger=member_id)
+        return event
+
+    def _verify_attestations(self, member_id, from_state, to_state, reason,
+                             attestations) -> List[str]:
+        payload = transition_payload(member_id, from_state, to_state, reason)
+        valid: List[str] = []
+        for a in attestations:
+            if a.attester_id == member_id or a.attester_id in valid:
+                continue
+            if self._states.get(a.attester_id) != MemberState.ACTIVE:
+                continue
+            if verify_hmac(self._secret_resolver(a.attester_id), payload, a.signature):
+                valid.append(a.attester_id)
+
+        if len(valid) < self._attestations_required:
+            raise QuorumError(
+                f"Transition of {member_id} to {to_state.value} has "
+                f"{len(valid)}/{self._attestations_required} valid attestations"
+            )
+        return valid
+
+    def _promote_backup(self, trigger: str):
+        """Fill a permanent vacancy from the backup bench, in bench order."""
+        if not self._backups:
+            return
+        backup = self._backups.pop(0)
+        self._states[backup] = MemberState.ACTIVE
+        self._record(MembershipTransition(
+            member_id=backup,
+            from_state=MemberState.BACKUP,
+            to_state=MemberState.ACTIVE,
+            reason=f"backup_promotion:{trigger}",
+            attesters=[],
+            achievable_quorum=self.achievable_quorum,
+        ))
+
+    def _recompute(self, trigger: str):
+        achievable = self.achievable_quorum
+        if achievable >= self._threshold:
+            self._deficit_low = None
+            return
+        # Within one deficit, only surface a new shortfall when the picture
+        # got worse, so a stable deficit does not flood the escalation lane.
+        if self._deficit_low is not None and self._deficit_low <= achievable:
+            return
+        self._deficit_low = achievable
+        shortfall = QuorumShortfall(
+            achievable_quorum=achievable,
+            threshold=self._threshold,
+            trigger_member=trigger,
+        )
+        self._shortfalls.append(shortfall)
+        if self._emit:
+            self._emit({
+                "type": "alert",
+                "domain": "emergency",
+                "authority": "system",
+                "source": "quorum_roster",
+                "payload": {
+                    "event": "quorum_shortfall",
+                    "achievable_quorum": achievable,
+                    "threshold": self._threshold,
+                    "trigger_member": trigger,
+                },
+            })
+
+    def _record(self, event: MembershipTransition):
+        self._history.append(event)
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "quorum_roster",
+                "handler": "quorum_roster",
+                "outcome": f"member_{event.to_state.value}",
+                "signal_id": event.member_id,
+                "signal_domain": "governance",
+                "timestamp": event.timestamp,
+                "extra": event.reason,
+            })
+
+    # ──────────────────────────────────────────────
+    # ACCESSORS
+    # ──────────────────────────────────────────────
+
+    @property
+    def achievable_quorum(self) -> int:
+        """Members who could vote today. Sabbaticals do not count."""
+        return sum(1 for s in self._states.values() if s == MemberState.ACTIVE)
+
+    @property
+    def quorum_achievable(self) -> bool:
+        return self.achievable_quorum >= self._threshold
+
+    @property
+    def threshold(self) -> int:
+        return self._threshold
+
+    def state_of(self, member_id: str) -> MemberState:
+        if member_id not in self._states:
+            raise QuorumError(f"Unknown member: {member_id}")
+        return self._states[member_id]
+
+    @property
+    def states(self) -> Dict[str, MemberState]:
+        return dict(self._states)
+
+    @property
+    def active_members(self) -> List[str]:
+        return [m for m, s in self._states.items() if s == MemberState.ACTIVE]
+
+    @property
+    def backups(self) -> List[str]:
+        return list(self._backups)
+
+    @property
+    def history(self) -> List[MembershipTransition]:
+        return list(self._history)
+
+    @property
+    def shortfalls(self) -> List[QuorumShortfall]:
+        return list(self._shortfalls)
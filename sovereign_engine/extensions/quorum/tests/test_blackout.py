@@ -0,0 +1,53 @@
+import unittest
+
+from sovereign_engine.extensions.quorum import BlackoutDetector, BlackoutKind, QuorumRoster
+
+MEMBERS = ["m1", "m2", "m3", "m4"]
+
+
+class TestBlackoutDetector(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.alerts = []
+        self.roster = QuorumRoster(MEMBERS, threshold=3, secret_resolver=lambda m: b"k")
+        self.detector = BlackoutDetector(self.roster, window_s=600, emit=self.alerts.append,
+                                         clock=lambda: self.now)
+
+    def test_undelivered_challenges_raise_emergency_blackout(self):
+        self.detector.record("m1", "c1", delivered=True, responded=True)
+        for m in ["m2", "m3", "m4"]:
+            self.detector.record(m, "c1", delivered=False, responded=False)
+        self.assertEqual(self.detector.assess().kind, BlackoutKind.BLACKOUT)
+        self.assertEqual(self.alerts[-1]["domain"], "emergency")
+        self.assertEqual(self.alerts[-1]["payload"]["event"], "comms_blackout")
+
+    def test_delivered_but_silent_is_unresponsive_not_blackout(self):
+        self.detector.record("m1", "c1", delivered=True, responded=True)
+        for m in ["m2", "m3"]:
+            self.detector.record(m, "c1", delivered=True, responded=False)
+        assessment = self.detector.assess()
+        self.assertEqual(assessment.kind, BlackoutKind.UNRESPONSIVE)
+        self.assertEqual(assessment.unchallenged, ["m4"])
+        self.assertEqual(self.alerts[-1]["domain"], "governance")
+
+    def test_enough_responses_is_clear_and_alerts_once(self):
+        for m in ["m1", "m2"]:
+            self.detector.record(m, "c1", delivered=False, responded=False)
+        self.detector.assess()
+        self.detector.assess()
+        self.assertEqual(len(self.alerts), 1)
+        for m in ["m1", "m2", "m3"]:
+            self.detector.record(m, "c2", delivered=True, responded=True)
+        self.assertEqual(self.detector.assess().kind, BlackoutKind.CLEAR)
+        self.assertEqual(len(self.alerts), 1)
+
+    def test_stale_outcomes_fall_out_of_window(self):
+        self.detector.record("m1", "c1", delivered=False, responded=False)
+        self.now += 601
+        assessment = self.detector.assess()
+        self.assertEqual(assessment.kind, BlackoutKind.CLEAR)
+        self.assertEqual(len(assessment.unchallenged), 4)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,79 @@
+import unittest
+
+from sovereign_engine.extensions.quorum import (
+    ContactError,
+    ContactTranscript,
+    QuorumRoster,
+    sign_attempt,
+    sign_response,
+)
+
+MEMBERS = ["m1", "m2", "m3"]
+SECRETS = {m: f"secret-{m}".encode() for m in MEMBERS}
+DAY = 86400.0
+
+
+class FakeClock:
+    def __init__(self):
+        self.now = 1_000_000.0
+
+    def __call__(self):
+        return self.now
+
+
+class TestContactTranscript(unittest.TestCase):
+    def setUp(self):
+        self.clock = FakeClock()
+        self.roster = QuorumRoster(MEMBERS, threshold=2, secret_resolver=SECRETS.get)
+        self.transcript = ContactTranscript(self.roster, SECRETS.get, clock=self.clock)
+
+    def _attempt(self, member="m3", by="m1", channel="phone"):
+        at = self.clock.now
+        sig = sign_attempt(SECRETS[by], member, channel, by, at)
+        return self.transcript.record_attempt(member, channel, by, at, sig)
+
+    def test_unanswered_attempts_build_no_show_streak(self):
+        self._attempt()
+        self.clock.now += DAY
+        self._attempt(channel="email")
+        self.clock.now += 2 * DAY
+        metrics = self.transcript.metrics("m3")
+        self.assertEqual(metrics.attempts, 2)
+        self.assertEqual(metrics.no_show_streak, 2)
+        self.assertAlmostEqual(metrics.days_absent, 3.0)
+        self.assertEqual(len(self.transcript.evidence("m3")), 2)
+        self.assertTrue(self.transcript.verify())
+
+    def test_response_resets_streak(self):
+        self._attempt()
+        self.clock.now += 2 * DAY
+        attempt = self._attempt()
+        self.clock.now += 3600
+        self.transcript.record_response(
+            attempt.hash, "available", sign_response(SECRETS["m3"], attempt.hash, "m3", "available")
+        )
+        metrics = self.transcript.metrics("m3")
+        self.assertEqual(metrics.no_show_streak, 0)
+        self.assertEqual(metrics.days_absent, 0.0)
+        self.assertEqual(metrics.responses, 1)
+
+    def test_forged_response_rejected(self):
+        attempt = self._attempt()
+        forged = sign_response(SECRETS["m1"], attempt.hash, "m3", "available")
+        with self.assertRaises(ContactError):
+            self.transcript.record_response(attempt.hash, "available", forged)
+
+    def test_backdated_attempt_rejected(self):
+        at = self.clock.now - 3 * DAY
+        sig = sign_attempt(SECRETS["m1"], "m3", "phone", "m1", at)
+        with self.assertRaises(ContactError):
+            self.transcript.record_attempt("m3", "phone", "m1", at, sig)
+        self.assertEqual(self.transcript.entries, [])
+
+    def test_self_contact_rejected(self):
+        with self.assertRaises(ContactError):
+            self._attempt(member="m1", by="m1")
+
+
+if __name__ == "__main__":
+    unittest.main()
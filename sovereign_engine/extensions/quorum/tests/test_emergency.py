@@ -0,0 +1,81 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.quorum import (
+    DecisionFinalizer,
+    EmergencyQuorumPolicy,
+    MemberState,
+    QuorumError,
+    QuorumRoster,
+    approve_decision,
+    attest,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+MEMBERS = ["m1", "m2", "m3", "m4", "m5", "m6", "m7"]
+SECRETS = {m: f"secret-{m}".encode() for m in MEMBERS}
+
+
+def approvals(members, decision_id, action, subject="engine"):
+    return [approve_decision(m, SECRETS[m], decision_id, action, subject) for m in members]
+
+
+class TestEmergencyQuorum(unittest.TestCase):
+    def setUp(self):
+        engine = SovereignEngine(CONFIG_PATH)
+        engine.boot()
+        self.ledger = engine.ledger
+        self.roster = QuorumRoster(MEMBERS, threshold=5, secret_resolver=SECRETS.get)
+        self.finalizer = DecisionFinalizer(
+            self.roster, SECRETS.get, EmergencyQuorumPolicy(4, frozenset({"halt"})),
+            ledger=self.ledger,
+        )
+
+    def _lose(self, *members):
+        for m in members:
+            witnesses = [w for w in MEMBERS if w != m and
+                         self.roster.state_of(w) == MemberState.ACTIVE][:2]
+            self.roster.transition(m, MemberState.INCAPACITATED, [
+                attest(w, SECRETS[w], m, MemberState.ACTIVE, MemberState.INCAPACITATED, "ill")
+                for w in witnesses], reason="ill")
+
+    def test_full_threshold_applies_while_quorum_is_achievable(self):
+        with self.assertRaises(QuorumError):
+            self.finalizer.finalize("d1", "halt", "engine", approvals(MEMBERS[:4], "d1", "halt"))
+        decision = self.finalizer.finalize("d1", "halt", "engine",
+                                           approvals(MEMBERS[:5], "d1", "halt"))
+        self.assertFalse(decision.emergency)
+
+    def test_shortfall_allows_reduced_quorum_only_for_scoped_actions(self):
+        self._lose("m5", "m6", "m7")
+        self.assertFalse(self.roster.quorum_achievable)
+        decision = self.finalizer.finalize("d1", "halt", "engine",
+                                           approvals(MEMBERS[:4], "d1", "halt"))
+        self.assertTrue(decision.emergency)
+        self.assertEqual(decision.threshold_applied, 4)
+        self.assertEqual(self.ledger.entries[-1].outcome, "emergency_decision_finalized")
+        with self.assertRaises(QuorumError):
+            self.finalizer.finalize("d2", "canon_update", "engine",
+                                    approvals(MEMBERS[:4], "d2", "canon_update"))
+
+    def test_policy_cannot_reduce_canon_or_undercut_nothing(self):
+        for policy in (EmergencyQuorumPolicy(4, frozenset({"canon_update"})),
+                       EmergencyQuorumPolicy(5, frozenset({"halt"}))):
+            with self.assertRaises(QuorumError):
+                DecisionFinalizer(self.roster, SECRETS.get, policy)
+
+    def test_inactive_or_forged_approvals_do_not_count(self):
+        self._lose("m5", "m6", "m7")
+        forged = approve_decision("m1", b"wrong", "d1", "halt", "engine")
+        inactive = approvals(["m5"], "d1", "halt")
+        with self.assertRaises(QuorumError):
+            self.finalizer.finalize("d1", "halt", "engine",
+                                    approvals(MEMBERS[1:4], "d1", "halt") + [forged] + inactive)
+
+
+if __name__ == "__main__":
+    unittest.main()
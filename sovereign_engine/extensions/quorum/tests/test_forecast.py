@@ -0,0 +1,74 @@
+import unittest
+
+from sovereign_engine.extensions.quorum import (
+    ContactTranscript,
+    ForecastError,
+    MemberState,
+    QuorumForecaster,
+    QuorumRoster,
+    at_least,
+    attest,
+    sign_attempt,
+    sign_response,
+)
+
+MEMBERS = ["m1", "m2", "m3", "m4"]
+SECRETS = {m: f"secret-{m}".encode() for m in MEMBERS}
+DAY = 86400.0
+
+
+class TestQuorumForecast(unittest.TestCase):
+    def setUp(self):
+        self.now = 1_000_000.0
+        self.roster = QuorumRoster(MEMBERS, threshold=3, secret_resolver=SECRETS.get,
+                                   attestations_required=1)
+
+    def test_without_history_members_forecast_at_the_prior(self):
+        forecast = QuorumForecaster(self.roster, prior=0.9).forecast()
+        self.assertAlmostEqual(forecast.probability, 0.9 ** 4 + 4 * 0.9 ** 3 * 0.1)
+        self.assertAlmostEqual(forecast.expected_available, 3.6)
+        self.assertAlmostEqual(at_least([0.5, 0.5], 1), 0.75)
+        self.assertEqual(at_least([1.0], 2), 0.0)
+
+    def test_sabbatical_and_silence_lower_the_forecast(self):
+        contacts = ContactTranscript(self.roster, SECRETS.get, clock=lambda: self.now)
+        for _ in range(3):
+            at = self.now
+            sig = sign_attempt(SECRETS["m1"], "m4", "phone", "m1", at)
+            contacts.record_attempt("m4", "phone", "m1", at, sig)
+            self.now += 2 * DAY
+        answered = contacts.record_attempt("m2", "phone", "m1", self.now,
+                                           sign_attempt(SECRETS["m1"], "m2", "phone", "m1", self.now))
+        contacts.record_response(answered.hash, "here",
+                                 sign_response(SECRETS["m2"], answered.hash, "m2", "here"))
+
+        forecaster = QuorumForecaster(self.roster, contacts)
+        self.assertGreater(forecaster.member_availability("m2"), 0.95)
+        self.assertLess(forecaster.member_availability("m4"), 0.15)
+        before = forecaster.forecast().probability
+
+        att = attest("m2", SECRETS["m2"], "m3", MemberState.ACTIVE, MemberState.SABBATICAL, "leave")
+        self.roster.transition("m3", MemberState.SABBATICAL, [att], "leave")
+        after = forecaster.forecast()
+        self.assertNotIn("m3", after.members)
+        self.assertLess(after.probability, before / 2)
+
+    def test_shared_regions_and_common_shocks_are_correlated(self):
+        independent = QuorumForecaster(self.roster, prior=1.0).forecast()
+        self.assertEqual(independent.probability, 1.0)
+        regions = {"m1": "eu", "m2": "eu", "m3": "us", "m4": "us"}
+        regional = QuorumForecaster(self.roster, regions=regions,
+                                    region_availability={"eu": 0.9, "us": 0.9},
+                                    prior=1.0).forecast()
+        # Three of four needs both regions up.
+        self.assertAlmostEqual(regional.probability, 0.81)
+        self.assertAlmostEqual(
+            QuorumForecaster(self.roster, prior=1.0).forecast(common_availability=0.5).probability,
+            0.5,
+        )
+        with self.assertRaises(ForecastError):
+            QuorumForecaster(self.roster, region_availability={"eu": 1.5})
+
+
+if __name__ == "__main__":
+    unittest.main()
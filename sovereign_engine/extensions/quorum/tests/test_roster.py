@@ -0,0 +1,143 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.quorum import (
+    MemberState,
+    QuorumError,
+    QuorumRoster,
+    attest,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+MEMBERS = ["m1", "m2", "m3", "m4", "m5", "m6", "m7"]
+SECRETS = {m: f"secret-{m}".encode() for m in MEMBERS + ["b1", "b2"]}
+
+
+def sign_all(member_id, from_state, to_state, reason, attesters):
+    return [
+        attest(a, SECRETS[a], member_id, from_state, to_state, reason)
+        for a in attesters
+    ]
+
+
+class TestQuorumRoster(unittest.TestCase):
+    def setUp(self):
+        self.emitted = []
+        self.roster = QuorumRoster(
+            MEMBERS,
+            threshold=5,
+            secret_resolver=lambda m: SECRETS[m],
+            emit=self.emitted.append,
+        )
+
+    def _transition(self, member_id, to_state, reason="", attesters=("m1", "m2")):
+        from_state = self.roster.state_of(member_id)
+        atts = sign_all(member_id, from_state, to_state, reason, attesters)
+        return self.roster.transition(member_id, to_state, atts, reason)
+
+    def test_attested_transition_recomputes_quorum(self):
+        event = self._transition("m7", MemberState.DECEASED, "death certificate")
+        self.assertEqual(event.attesters, ["m1", "m2"])
+        self.assertEqual(self.roster.achievable_quorum, 6)
+        self.assertEqual(self.roster.state_of("m7"), MemberState.DECEASED)
+
+    def test_insufficient_attestations_rejected(self):
+        with self.assertRaises(QuorumError):
+            self._transition("m7", MemberState.REMOVED, attesters=("m1",))
+        # Self-attestation never counts
+        with self.assertRaises(QuorumError):
+            self._transition("m7", MemberState.REMOVED, attesters=("m7", "m1"))
+        self.assertEqual(self.roster.state_of("m7"), MemberState.ACTIVE)
+
+    def test_forged_attestation_rejected(self):
+        forged = [
+            attest("m1", b"wrong", "m7", MemberState.ACTIVE, MemberState.REMOVED, ""),
+            attest("m2", SECRETS["m2"], "m7", MemberState.ACTIVE, MemberState.REMOVED, ""),
+        ]
+        with self.assertRaises(QuorumError):
+            self.roster.transition("m7", MemberState.REMOVED, forged)
+
+    def test_terminal_states_cannot_be_left(self):
+        self._transition("m7", MemberState.DECEASED)
+        with self.assertRaises(QuorumError):
+            self._transition("m7", MemberState.ACTIVE)
+
+    def test_sabbatical_is_reversible_and_not_counted(self):
+        self._transition("m6", MemberState.SABBATICAL, attesters=("m1", "m2"))
+        self.assertEqual(self.roster.achievable_quorum, 6)
+        self._transition("m6", MemberState.ACTIVE, attesters=("m1", "m2"))
+        self.assertEqual(self.roster.achievable_quorum, 7)
+
+    def test_shortfall_emits_emergency_alert_once_per_drop(self):
+        self._transition("m7", MemberState.DECEASED)
+        self._transition("m6", MemberState.REMOVED)
+        self.assertEqual(self.emitted, [])
+        self._transition("m5", MemberState.INCAPACITATED)
+        self.assertEqual(len(self.emitted), 1)
+        alert = self.emitted[0]
+        self.assertEqual(alert["domain"], "emergency")
+        self.assertEqual(alert["payload"]["achievable_quorum"], 4)
+        self.assertFalse(self.roster.quorum_achievable)
+
+    def test_shortfall_after_recovery_is_surfaced_again(self):
+        roster = QuorumRoster(["a", "b", "c", "d"], threshold=4,
+                              secret_resolver=lambda m: b"k", emit=self.emitted.append)
+        for member, to_state in [("d", MemberState.INCAPACITATED), ("d", MemberState.ACTIVE),
+                                 ("c", MemberState.SABBATICAL)]:
+            from_state = roster.state_of(member)
+            roster.transition(member, to_state, [
+                attest(a, b"k", member, from_state, to_state, "") for a in ("a", "b")])
+        self.assertFalse(roster.quorum_achievable)
+        self.assertEqual(len(self.emitted), 2)
+        self.assertEqual(self.emitted[-1]["payload"]["trigger_member"], "c")
+
+    def test_backup_promoted_on_permanent_vacancy(self):
+        roster = QuorumRoster(
+            MEMBERS, threshold=5, secret_resolver=lambda m: SECRETS[m],
+            backups=["b1", "b2"],
+        )
+        atts = sign_all("m7", MemberState.ACTIVE, MemberState.DECEASED, "", ["m1", "m2"])
+        roster.transition("m7", MemberState.DECEASED, atts)
+        self.assertEqual(roster.state_of("b1"), MemberState.ACTIVE)
+        self.assertEqual(roster.backups, ["b2"])
+        self.assertEqual(roster.achievable_quorum, 7)
+        self.assertEqual(roster.history[-1].reason, "backup_promotion:m7")
+
+    def test_transitions_are_audited_and_alert_routes_to_steward(self):
+        engine = SovereignEngine(CONFIG_PATH)
+        engine.boot()
+        results = []
+        roster = QuorumRoster(
+            MEMBERS[:3], threshold=3, secret_resolver=lambda m: SECRETS[m],
+            ledger=engine.ledger,
+            emit=lambda s: results.append(engine.submit_and_process(**s)),
+        )
+        atts = sign_all("m3", MemberState.ACTIVE, MemberState.REMOVED, "", ["m1", "m2"])
+        roster.transition("m3", MemberState.REMOVED, atts)
+
+        outcomes = [e.outcome for e in engine.ledger.entries]
+        self.assertIn("member_removed", outcomes)
+        self.assertEqual(results[0]["target"], "steward")
+        self.assertTrue(engine.ledger.verify()["valid"])
+
+    def test_from_archetype_requires_quorum_mode(self):
+        engine = SovereignEngine(CONFIG_PATH)
+        engine.boot()
+        federated = engine.configurator.compile("federated")
+        roster = QuorumRoster.from_archetype(
+            federated, MEMBERS, secret_resolver=lambda m: SECRETS[m]
+        )
+        self.assertEqual(roster.threshold, federated.routing_overrides["quorum_threshold"])
+        with self.assertRaises(QuorumError):
+            QuorumRoster.from_archetype(
+                engine.configurator.compile("managerial"), MEMBERS,
+                secret_resolver=lambda m: SECRETS[m],
+            )
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,39 @@
+from .halts import HaltLog
+from .playbook import (
+    CANONICAL_HALT_FIELDS,
+    EXTENSION_FAILURES,
+    KERNEL_HALT_FIELDS,
+    PLAYBOOKS,
+    ROUTING_HALT_ACTIONS,
+    HaltCausePersists,
+    PlaybookError,
+    PlaybookViolation,
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    RecoveryStep,
+    failed_component_check,
+    halt_id,
+    kernel_halt_fields,
+    sign_review,
+)
+
+__all__ = [
+    "CANONICAL_HALT_FIELDS",
+    "EXTENSION_FAILURES",
+    "KERNEL_HALT_FIELDS",
+    "PLAYBOOKS",
+    "ROUTING_HALT_ACTIONS",
+    "HaltCausePersists",
+    "HaltLog",
+    "PlaybookError",
+    "PlaybookViolation",
+    "RecoveryAction",
+    "RecoveryPlaybook",
+    "RecoverySession",
+    "RecoveryStep",
+    "failed_component_check",
+    "halt_id",
+    "kernel_halt_fields",
+    "sign_review",
+]
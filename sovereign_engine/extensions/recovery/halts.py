@@ -0,0 +1,176 @@
+"""
+Halt Log - One identity per halt, one ledger entry per identity.
+
+The kernel's halt record is `{reason, source, timestamp}`, and
+`halt_ctrl.halt` appends a fresh one every time it is called — a
+component that keeps failing while the engine is already halted for it
+fills the history with copies of one incident, each with its own
+timestamp and so its own `halt_id`. The log observes `halt_ctrl.halt`
+without changing what the kernel does:
+
+- Every halt reaches the kernel and gets its kernel record.
+- A halt for the same source and reason as the halt currently open is a
+  repeat. It shares the open halt's identity and is ledgered as
+  `halt_repeated` against it.
+- A new halt is stamped with the ledger height and head it fired at (the
+  inputs commitment) and written to the audit ledger as `halt_recorded`.
+  FailureMatrix halts are not otherwise ledgered by the kernel.
+
+Stamps live in the log, keyed by the kernel record's index; kernel
+records are never modified. `halt_history` returns stamped copies, one per
+distinct halt with its repeat count, in the same shape as the kernel
+controller's — pass the log wherever a halt history source is taken
+(recovery sessions, the museum, reports) and `halt_id` yields the
+canonical ID without each caller hashing something of its own. Attaching
+to a restored engine rebuilds the stamps from the ledger.
+
+Dependency: Phase 4 (audit), Phase 5 (halt)
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Dict, List, Optional
+
+from ..security.zero_trust_evidence import canonical_json
+from .playbook import halt_id
+
+
+HANDLER = "halt_log"
+
+
+class HaltLog:
+    """
+    INVARIANT: Every halt reaches the kernel unchanged.
+    INVARIANT: A repeat of the open halt never creates a second identity.
+    INVARIANT: Every distinct halt is ledgered under its canonical ID.
+    """
+
+    def __init__(self, engine):
+        self._engine = engine
+        # Kernel history index of a distinct halt → its {"height", "inputs"}.
+        self._stamps: Dict[int, Dict] = {}
+        # Kernel history index of a repeat → index of the halt it repeats.
+        self._repeat_of: Dict[int, int] = {}
+        self._installed = None
+
+    def attach(self) -> "HaltLog":
+        if self._installed is not None:
+            return self
+        engine = self._engine
+        ctrl = engine.halt_ctrl
+        halt = ctrl.halt
+        self._rebuild()
+
+        def logged_halt(reason, source="unknown"):
+            open_index = self._open_index()
+            height, inputs = engine.ledger.length, engine.ledger.last_hash
+            halt(reason, source)
+            history = ctrl.halt_history
+            index = len(history) - 1
+            opened = history[open_index] if open_index is not None else None
+            if opened is not None and (opened["source"], opened["reason"]) == (source, reason):
+                first = self._repeat_of.get(open_index, open_index)
+                self._repeat_of[index] = first
+                self._ledger("halt_repeated", self._stamped(first), index)
+                return
+            self._stamps[index] = {"height": height, "inputs": inputs}
+            self._ledger("halt_recorded", self._stamped(index), index)
+
+        self._installed = (logged_halt, vars(ctrl).get("halt"))
+        ctrl.halt = logged_halt
+        return self
+
+    def detach(self):
+        """Put back what attach replaced. Refuses if it has been wrapped since."""
+        if self._installed is None:
+            return
+        ctrl = self._engine.halt_ctrl
+        wrapper, previous = self._installed
+        if vars(ctrl).get("halt") is not wrapper:
+            raise RuntimeError(
+                "HaltController.halt was wrapped after the halt log attached; "
+                "detach that wrapper first"
+            )
+        if previous is None:
+            del ctrl.halt
+        else:
+            ctrl.halt = previous
+        self._installed = None
+
+    def _ledger(self, outcome: str, record: Dict, index: int):
+        self._engine.ledger.write({
+            "signal_type": "halt",
+            "route": "halt",
+            "handler": HANDLER,
+            "outcome": outcome,
+            "signal_id": halt_id(record),
+            "signal_domain": "constitutional",
+            "extra": canonical_json({
+                "index": index,
+                **{k: record[k] for k in ("height", "inputs") if k in record},
+                "source": record["source"],
+                "reason": record["reason"],
+            }),
+        })
+
+    def _rebuild(self):
+        # A restored engine carries the kernel history and the ledger, but
+        # not the stamps; both entry kinds record the kernel index.
+        first_by_id: Dict[str, int] = {}
+        for entry in self._engine.ledger.entries:
+            if entry.handler != HANDLER:
+                continue
+            data = json.loads(entry.extra)
+            index = data["index"]
+            if entry.outcome == "halt_recorded":
+                self._stamps[index] = {"height": data["height"], "inputs": data["inputs"]}
+                first_by_id[entry.signal_id] = index
+            elif entry.signal_id in first_by_id:
+                self._repeat_of[index] = first_by_id[entry.signal_id]
+
+    def _open_index(self) -> Optional[int]:
+        ctrl = self._engine.halt_ctrl
+        history = ctrl.halt_history
+        return len(history) - 1 if ctrl.is_halted and history else None
+
+    def _stamped(self, index: int, history: Optional[List[Dict]] = None) -> Dict:
+        history = self._engine.halt_ctrl.halt_history if history is None else history
+        return {**history[index], **self._stamps.get(index, {})}
+
+    # ──────────────────────────────────────────────
+    # HALT HISTORY SOURCE
+    # ──────────────────────────────────────────────
+
+    @property
+    def is_halted(self) -> bool:
+        return self._engine.halt_ctrl.is_halted
+
+    @property
+    def halt_history(self) -> List[Dict]:
+        """Distinct halts, stamped, each with the number of repeats folded in."""
+        repeats: Dict[int, int] = {}
+        for first in self._repeat_of.values():
+            repeats[first] = repeats.get(first, 0) + 1
+        kernel, history = self._engine.halt_ctrl.halt_history, []
+        for index in range(len(kernel)):
+            if index in self._repeat_of:
+                continue
+            record = self._stamped(index, kernel)
+            if index in self._stamps:
+                record["repeats"] = repeats.get(index, 0)
+            history.append(record)
+        return history
+
+    @property
+    def open_halt(self) -> Optional[Dict]:
+        history = self.halt_history
+        return history[-1] if self.is_halted and history else None
+
+    def find(self, ref: str) -> Optional[Dict]:
+        """The halt record with this ID, canonical or legacy."""
+        return next((h for h in self.halt_history if halt_id(h) == ref), None)
+
+    @property
+    def halts(self) -> List[Dict]:
+        return [{"halt_id": halt_id(h), **h} for h in self.halt_history]
@@ -0,0 +1,324 @@
+"""
+Recovery Playbook - Typed, enforced next steps for each kind of halt.
+S3-EXT-025: Halt recovery bolt-on.
+
+The constitution already names a recovery for every failure type
+(`failure_semantics[*].recovery`), but as free text nobody enforces. The
+playbook turns each recovery code into an ordered sequence of actions and
+a recovery session walks it for the current halt: an action out of order
+is refused, review steps need steward signatures, and resume is only
+reachable at the end. Every step is written to the audit ledger.
+
+Review adds evidence; it cannot make a fault go away. Before resuming,
+the session re-runs a check of the original cause (by default: the
+failed component must have reported healthy since) and refuses with
+`HaltCausePersists` if it still trips.
+
+A failure type whose recovery code has no playbook is a construction
+error, so a constitution amendment cannot silently leave a halt without a
+way back.
+
+Dependency: Phase 0 (failure semantics), Phase 5 (halt), Phase 6 (failure matrix)
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass, field
+from enum import Enum
+from typing import Callable, Dict, Iterable, List, Optional, Tuple
+
+from ..security.zero_trust_evidence import sha256_hex, sign_hmac, verify_hmac
+
+
+class PlaybookError(Exception):
+    pass
+
+
+class PlaybookViolation(PlaybookError):
+    """An action was attempted that the playbook does not allow now."""
+    pass
+
+
+class HaltCausePersists(PlaybookViolation):
+    """Resume refused: the condition that caused the halt still holds."""
+    pass
+
+
+class RecoveryAction(str, Enum):
+    CAPTURE_FORENSICS = "capture_forensics"
+    REDUCE_LOAD = "reduce_load"
+    STEWARD_REVIEW = "steward_review"
+    CONSTITUTIONAL_REVIEW = "constitutional_review"
+    VALIDATE_LEDGER = "validate_ledger"
+    RESUME = "resume"
+
+
+A = RecoveryAction
+
+# Recovery codes from `failure_semantics`, plus the two halt sources that
+# bypass the failure matrix (router ambiguity and explicit halt signals).
+PLAYBOOKS: Dict[str, Tuple[RecoveryAction, ...]] = {
+    "restart_with_ledger_validation": (A.CAPTURE_FORENSICS, A.VALIDATE_LEDGER, A.RESUME),
+    "steward_review": (A.CAPTURE_FORENSICS, A.STEWARD_REVIEW, A.VALIDATE_LEDGER, A.RESUME),
+    "no_resume_until_integrity_verified": (
+        A.CAPTURE_FORENSICS, A.VALIDATE_LEDGER, A.STEWARD_REVIEW, A.VALIDATE_LEDGER, A.RESUME,
+    ),
+    "reduce_load_or_halt": (A.REDUCE_LOAD, A.VALIDATE_LEDGER, A.RESUME),
+    "full_constitutional_review": (
+        A.CAPTURE_FORENSICS, A.CONSTITUTIONAL_REVIEW, A.VALIDATE_LEDGER, A.RESUME,
+    ),
+    "unknown_failure_constitutional_review": (
+        A.CAPTURE_FORENSICS, A.CONSTITUTIONAL_REVIEW, A.VALIDATE_LEDGER, A.RESUME,
+    ),
+    "routing_halt_review": (A.CAPTURE_FORENSICS, A.STEWARD_REVIEW, A.VALIDATE_LEDGER, A.RESUME),
+    "halt_signal_review": (A.STEWARD_REVIEW, A.VALIDATE_LEDGER, A.RESUME),
+    "split_brain_resolution": (
+        A.CAPTURE_FORENSICS, A.CONSTITUTIONAL_REVIEW, A.VALIDATE_LEDGER, A.RESUME,
+    ),
+}
+
+# Failure types raised by extensions. They are unknown to the constitution,
+# so they halt by doctrine; this only picks the playbook for the review.
+EXTENSION_FAILURES: Dict[str, str] = {
+    "split_brain": "split_brain_resolution",
+}
+
+# Signatures required per review step. Constitutional review is dual-key,
+# matching the kernel's steward_override_without_dual_key prohibition.
+REVIEW_SIGNATURES = {
+    RecoveryAction.STEWARD_REVIEW: 1,
+    RecoveryAction.CONSTITUTIONAL_REVIEW: 2,
+}
+
+
+# Fields that identify a halt once `HaltLog` has stamped it: where in the
+# ledger it fired, against which head, from which source, and why.
+CANONICAL_HALT_FIELDS = ("height", "inputs", "source", "reason")
+
+# Fields of the kernel's own halt record.
+KERNEL_HALT_FIELDS = ("reason", "source", "timestamp")
+
+# Routing outcomes after which the engine halts with source "router".
+ROUTING_HALT_ACTIONS = ("halt", "system_halt")
+
+
+def kernel_halt_fields(halt_record: dict) -> dict:
+    """The kernel's part of a halt record, stamped or not."""
+    return {f: halt_record.get(f) for f in KERNEL_HALT_FIELDS}
+
+
+def halt_id(halt_record: dict) -> str:
+    """Canonical ID for a stamped record; a hash of the whole record otherwise."""
+    if all(f in halt_record for f in CANONICAL_HALT_FIELDS):
+        return sha256_hex({f: halt_record[f] for f in CANONICAL_HALT_FIELDS})
+    return sha256_hex(halt_record)
+
+
+def review_payload(halt_ref: str, action: RecoveryAction, evidence: str) -> dict:
+    return {"halt_id": halt_ref, "action": action.value, "evidence": evidence}
+
+
+def sign_review(secret: bytes, halt_ref: str, action: RecoveryAction, evidence: str) -> str:
+    return sign_hmac(bytes(secret), review_payload(halt_ref, action, evidence))
+
+
+class RecoveryPlaybook:
+    """Maps each failure type to its ordered recovery actions."""
+
+    def __init__(self, failure_semantics: Dict[str, dict],
+                 playbooks: Optional[Dict[str, Tuple[RecoveryAction, ...]]] = None):
+        self._playbooks = dict(playbooks or PLAYBOOKS)
+        self._by_failure: Dict[str, str] = {}
+        missing = []
+        for failure_type, spec in failure_semantics.items():
+            code = spec["recovery"]
+            if code not in self._playbooks:
+                missing.append(f"{failure_type} → {code}")
+            self._by_failure[failure_type] = code
+        if missing:
+            raise PlaybookError(f"No playbook for recovery codes: {', '.join(missing)}")
+        for code, steps in self._playbooks.items():
+            if not steps or steps[-1] != RecoveryAction.RESUME:
+                raise PlaybookError(f"Playbook '{code}' must end in resume")
+
+    @classmethod
+    def from_constitution(cls, constitution) -> "RecoveryPlaybook":
+        return cls(constitution.get("failure_semantics"))
+
+    def recovery_code(self, failure_type: Optional[str],
+                      routing_action: Optional[str] = None) -> str:
+        """
+        Recovery code for a FailureMatrix halt, or for a routing halt given
+        the action of the routing decision that caused it.
+        """
+        if failure_type is not None:
+            if failure_type in self._by_failure:
+                return self._by_failure[failure_type]
+            return EXTENSION_FAILURES.get(failure_type, "unknown_failure_constitutional_review")
+        if routing_action == "system_halt":
+            return "halt_signal_review"
+        return "routing_halt_review"
+
+    def steps_for(self, code: str) -> Tuple[RecoveryAction, ...]:
+        return self._playbooks[code]
+
+
+@dataclass
+class RecoveryStep:
+    action: RecoveryAction
+    actors: List[str]
+    evidence: str
+    result: dict = field(default_factory=dict)
+
+
+def failed_component_check(engine, halt: dict) -> Optional[str]:
+    """Default cause check: the halting component must have reported healthy since."""
+    status = engine.health.statuses.get(halt["source"])
+    if status is not None and not status.healthy:
+        return f"{halt['source']} still reports {status.last_failure}"
+    return None
+
+
+class RecoverySession:
+    """
+    Walks the playbook for the engine's current halt.
+
+    INVARIANT: Actions are accepted only in playbook order.
+    INVARIANT: Resume is reachable only as the final step.
+    INVARIANT: Resume is refused while the halt's cause persists.
+    INVARIANT: Every accepted step, and every refused resume, is ledgered.
+    """
+
+    def __init__(self, engine, playbook: RecoveryPlaybook,
+                 stewards: Iterable[str], secret_resolver: Callable[[str], bytes],
+                 cause_check: Callable[[object, dict], Optional[str]] = failed_component_check,
+                 halts=None):
+        # `halts` is the halt history source; a HaltLog gives the canonical ID.
+        if not engine.is_halted:
+            raise PlaybookError("Engine is not halted — nothing to recover")
+        self._engine = engine
+        self._stewards = frozenset(stewards)
+        self._secret_resolver = secret_resolver
+        self._cause_check = cause_check
+        self.halt = (engine.halt_ctrl if halts is None else halts).halt_history[-1]
+        self.halt_id = halt_id(self.halt)
+        self.failure_type = self._failure_type()
+        self.recovery_code = playbook.recovery_code(
+            self.failure_type, None if self.failure_type else self._routing_action())
+        self.steps = playbook.steps_for(self.recovery_code)
+        self._done: List[RecoveryStep] = []
+
+    def _failure_type(self) -> Optional[str]:
+        # FailureMatrix halts carry "<type> in <component>: ..." as the reason.
+        for event in reversed(self._engine.failure_matrix.event_log):
+            if (event.component == self.halt["source"]
+                    and self.halt["reason"].startswith(f"{event.failure_type} in ")):
+                return event.failure_type
+        return None
+
+    def _routing_action(self) -> Optional[str]:
+        # The engine ledgers the routing decision, then halts on it, so the
+        # cause is the last halting decision written before the halt.
+        for entry in reversed(self._engine.ledger.entries):
+            if entry.outcome in ROUTING_HALT_ACTIONS and entry.timestamp <= self.halt["timestamp"]:
+                return entry.outcome
+        return None
+
+    @property
+    def next_action(self) -> Optional[RecoveryAction]:
+        if len(self._done) >= len(self.steps):
+            return None
+        return self.steps[len(self._done)]
+
+    @property
+    def completed(self) -> List[RecoveryStep]:
+        return list(self._done)
+
+    def allowed(self, action: RecoveryAction) -> bool:
+        return action == self.next_action
+
+    def perform(self, action: RecoveryAction, evidence: str = "",
+                signatures: Optional[Dict[str, str]] = None) -> RecoveryStep:
+        if not self.allowed(action):
+            raise PlaybookViolation(
+                f"{action.value} not allowed for {self.recovery_code}; "
+                f"next is {self.next_action.value if self.next_action else 'nothing'}"
+            )
+
+        actors = self._verify_reviews(action, evidence, signatures or {})
+        result: dict = {}
+        if action == RecoveryAction.CAPTURE_FORENSICS:
+            self._require_committed_bundle(evidence)
+        elif action == RecoveryAction.VALIDATE_LEDGER:
+            result = self._engine.ledger.verify()
+            if not result["valid"]:
+                raise PlaybookViolation("Ledger validation failed — cannot proceed")
+        elif action == RecoveryAction.RESUME:
+            result = self._resume()
+
+        step = RecoveryStep(action=action, actors=actors, evidence=evidence, result=result)
+        self._done.append(step)
+        self._record(f"recovery_{action.value}", ",".join(actors) or evidence)
+        return step
+
+    def _record(self, outcome: str, extra: str):
+        self._engine.ledger.write({
+            "signal_type": "system",
+            "route": "recovery",
+            "handler": "recovery_playbook",
+            "outcome": outcome,
+            "signal_id": self.halt_id,
+            "signal_domain": "constitutional",
+            "extra": extra,
+        })
+
+    def _require_committed_bundle(self, bundle_hash: str):
+        # Evidence is the hash of a forensics bundle already committed to
+        # the ledger, so the capture cannot be claimed after the fact. It
+        # must have been captured for this halt, after it opened, so a
+        # bundle from an earlier halt cannot be replayed.
+        expected = kernel_halt_fields(self.halt)
+        for entry in self._engine.ledger.entries:
+            if entry.outcome != "forensics_bundle_captured" or entry.signal_id != bundle_hash:
+                continue
+            if entry.timestamp < self.halt["timestamp"]:
+                raise PlaybookViolation("Forensics bundle was committed before this halt")
+            try:
+                captured_for = json.loads(entry.extra or "null")
+            except ValueError:
+                captured_for = None
+            if captured_for != expected:
+                raise PlaybookViolation("Forensics bundle was captured for a different halt")
+            return
+        raise PlaybookViolation("capture_forensics needs a ledger-committed bundle hash")
+
+    def _verify_reviews(self, action, evidence, signatures) -> List[str]:
+        required = REVIEW_SIGNATURES.get(action, 0)
+        if not required:
+            return []
+        payload = review_payload(self.halt_id, action, evidence)
+        valid = [sid for sid, sig in signatures.items()
+                 if sid in self._stewards
+                 and verify_hmac(self._secret_resolver(sid), payload, sig)]
+        if len(valid) < required:
+            raise PlaybookViolation(
+                f"{action.value} needs {required} steward signature(s), got {len(valid)}"
+            )
+        return sorted(valid)
+
+    def _resume(self) -> dict:
+        cause = self._cause_check(self._engine, self.halt)
+        if cause is not None:
+            self._record("recovery_halt_cause_persists", cause)
+            raise HaltCausePersists(cause)
+        ledger_valid = self._engine.ledger.verify()["valid"]
+        result = self._engine.halt_ctrl.resume(ledger_valid=ledger_valid)
+        if result["resumed"]:
+            if self._engine.router.is_halted:
+                self._engine.router.resume()
+            if self._engine.bus.is_halted:
+                self._engine.bus.resume()
+        else:
+            raise PlaybookViolation(result["reason"])
+        return result
@@ -0,0 +1,87 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.museum import Museum
+from sovereign_engine.extensions.recovery import (
+    HaltLog,
+    RecoveryPlaybook,
+    RecoverySession,
+    halt_id,
+)
+from sovereign_engine.extensions.storage import DurableState, MemoryStorage
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STORAGE_KEY = b"storage-key"
+STEWARDS = {"s1": b"steward-1", "s2": b"steward-2"}
+
+
+class TestHaltLog(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.log = HaltLog(self.engine).attach()
+
+    def _recorded(self):
+        return [e for e in self.engine.ledger.entries if e.outcome == "halt_recorded"]
+
+    def test_repeats_share_the_open_halts_identity(self):
+        for _ in range(3):
+            self.engine.health.report_failure("router", "router_failure", "crash")
+        self.assertEqual(len(self.engine.halt_ctrl.halt_history), 3)
+        [record] = self.log.halt_history
+        self.assertEqual(record["repeats"], 2)
+        [entry] = self._recorded()
+        self.assertEqual(entry.signal_id, halt_id(record))
+        repeated = [e for e in self.engine.ledger.entries if e.outcome == "halt_repeated"]
+        self.assertEqual({e.signal_id for e in repeated}, {entry.signal_id})
+        self.assertEqual(len(repeated), 2)
+
+        self.engine.health.report_failure("audit", "audit_failure", "chain break")
+        self.assertEqual(len(self.log.halt_history), 2)
+        self.assertEqual(len(self._recorded()), 2)
+
+    def test_canonical_id_is_shared_by_every_consumer(self):
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        record = self.log.open_halt
+        self.assertEqual(record["height"], self._recorded()[0].index)
+        self.assertNotIn("height", self.engine.halt_ctrl.halt_history[-1])
+        legacy = halt_id(self.engine.halt_ctrl.halt_history[-1])
+        session = RecoverySession(
+            self.engine, RecoveryPlaybook.from_constitution(self.engine.constitution),
+            STEWARDS, STEWARDS.get, halts=self.log,
+        )
+        museum_id = Museum(self.engine.ledger, self.log).halts().items[0]["halt_id"]
+        self.assertEqual(session.halt_id, museum_id)
+        self.assertEqual(self.log.find(session.halt_id), record)
+        # Wall-clock time is not part of the identity, and repeats do not
+        # change the kernel record's legacy ID.
+        self.assertEqual(halt_id({**record, "timestamp": 0.0, "repeats": 9}), session.halt_id)
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self.assertEqual(halt_id(self.engine.halt_ctrl.halt_history[0]), legacy)
+
+    def test_reattach_rebuilds_stamps_from_the_ledger(self):
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        before = self.log.halts
+        self.log.detach()
+        self.assertEqual(HaltLog(self.engine).attach().halts, before)
+
+    def test_detach_restores_kernel_behaviour(self):
+        self.log.detach()
+        self.assertNotIn("halt", vars(self.engine.halt_ctrl))
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        self.assertEqual(len(self.engine.halt_ctrl.halt_history), 2)
+        self.assertEqual(self._recorded(), [])
+
+    def test_detach_refuses_to_strip_a_later_wrapper(self):
+        DurableState(self.engine, MemoryStorage(), STORAGE_KEY).attach()
+        with self.assertRaises(RuntimeError):
+            self.log.detach()
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,103 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.forensics import ForensicsBundle
+from sovereign_engine.extensions.recovery import (
+    HaltCausePersists,
+    PlaybookError,
+    PlaybookViolation,
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    sign_review,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STEWARDS = {"s1": b"steward-1", "s2": b"steward-2"}
+
+
+class TestRecoveryPlaybook(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.playbook = RecoveryPlaybook.from_constitution(self.engine.constitution)
+
+    def _session(self):
+        return RecoverySession(self.engine, self.playbook, STEWARDS, STEWARDS.get)
+
+    def test_every_constitutional_recovery_has_a_playbook(self):
+        with self.assertRaises(PlaybookError):
+            RecoveryPlaybook({"new_failure": {"action": "halt", "recovery": "pray"}})
+
+    def test_audit_failure_walks_integrity_playbook_to_resume(self):
+        self.engine.health.report_failure("audit", "audit_failure", "chain break")
+        session = self._session()
+        self.assertEqual(session.failure_type, "audit_failure")
+        self.assertEqual(session.recovery_code, "no_resume_until_integrity_verified")
+
+        with self.assertRaises(PlaybookViolation):
+            session.perform(RecoveryAction.RESUME)
+
+        bundle = ForensicsBundle.capture(self.engine)
+        session.perform(RecoveryAction.CAPTURE_FORENSICS, bundle.bundle_hash)
+        session.perform(RecoveryAction.VALIDATE_LEDGER)
+        with self.assertRaises(PlaybookViolation):
+            session.perform(RecoveryAction.STEWARD_REVIEW, "ok", {"s1": "forged"})
+        sig = sign_review(STEWARDS["s1"], session.halt_id, RecoveryAction.STEWARD_REVIEW, "ok")
+        session.perform(RecoveryAction.STEWARD_REVIEW, "ok", {"s1": sig})
+        session.perform(RecoveryAction.VALIDATE_LEDGER)
+        with self.assertRaises(HaltCausePersists):
+            session.perform(RecoveryAction.RESUME)
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "recovery_halt_cause_persists")
+        self.engine.health.report_healthy("audit")
+        session.perform(RecoveryAction.RESUME)
+
+        self.assertFalse(self.engine.is_halted)
+        self.assertIsNone(session.next_action)
+        outcomes = [e.outcome for e in self.engine.ledger.entries]
+        self.assertIn("recovery_resume", outcomes)
+
+    def test_halt_signal_needs_review_not_forensics(self):
+        self.engine.submit_and_process("halt", "constitutional", "steward", {}, source="ops")
+        session = self._session()
+        self.assertEqual(session.recovery_code, "halt_signal_review")
+        self.assertFalse(session.allowed(RecoveryAction.CAPTURE_FORENSICS))
+        sig = sign_review(STEWARDS["s2"], session.halt_id, RecoveryAction.STEWARD_REVIEW, "")
+        session.perform(RecoveryAction.STEWARD_REVIEW, "", {"s2": sig})
+        session.perform(RecoveryAction.VALIDATE_LEDGER)
+        session.perform(RecoveryAction.RESUME)
+        self.assertFalse(self.engine.router.is_halted)
+
+    def test_constitutional_review_is_dual_key(self):
+        self.engine.health.report_failure("router", "authority_breach", "bypass")
+        session = self._session()
+        bundle = ForensicsBundle.capture(self.engine)
+        session.perform(RecoveryAction.CAPTURE_FORENSICS, bundle.bundle_hash)
+        action = RecoveryAction.CONSTITUTIONAL_REVIEW
+        one = {"s1": sign_review(STEWARDS["s1"], session.halt_id, action, "")}
+        with self.assertRaises(PlaybookViolation):
+            session.perform(action, "", one)
+        both = dict(one, s2=sign_review(STEWARDS["s2"], session.halt_id, action, ""))
+        self.assertEqual(session.perform(action, "", both).actors, ["s1", "s2"])
+
+    def test_bundle_from_an_earlier_halt_is_refused(self):
+        self.engine.health.report_failure("router", "authority_breach", "bypass")
+        old = ForensicsBundle.capture(self.engine)
+        self.engine.halt_ctrl.resume(ledger_valid=True)
+        self.engine.health.report_failure("audit", "audit_failure", "chain break")
+        session = self._session()
+        with self.assertRaises(PlaybookViolation):
+            session.perform(RecoveryAction.CAPTURE_FORENSICS, old.bundle_hash)
+        session.perform(RecoveryAction.CAPTURE_FORENSICS,
+                        ForensicsBundle.capture(self.engine).bundle_hash)
+
+    def test_routing_halt_code_comes_from_the_routing_decision(self):
+        self.engine.halt_ctrl.halt("Halt signal received", source="router")
+        self.assertEqual(self._session().recovery_code, "routing_halt_review")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,19 @@
+from .policy import (
+    ACTIONS,
+    DEFAULT_ACTIONS,
+    DEFAULT_FIELDS,
+    MASK,
+    SENSITIVITIES,
+    RedactionError,
+    RedactionPolicy,
+)
+
+__all__ = [
+    "ACTIONS",
+    "DEFAULT_ACTIONS",
+    "DEFAULT_FIELDS",
+    "MASK",
+    "SENSITIVITIES",
+    "RedactionError",
+    "RedactionPolicy",
+]
@@ -0,0 +1,129 @@
+"""
+Redaction - What leaves the system says less than what it keeps.
+S3-EXT-038: Export redaction bolt-on.
+
+The audit ledger is sealed and complete. Everything that crosses the
+boundary — notifications to pagers and chat rooms, webhook payloads,
+dashboards — is read by people and systems this deployment does not
+control, and a stream of "member m3 missed attestation" events is a map
+of who to lean on. A RedactionPolicy is applied at the export boundary
+only; the ledger keeps the unredacted record.
+
+Each field name maps to a sensitivity level, and each level to an action:
+
+- keep:   the value passes unchanged
+- pseudo: replaced by a keyed pseudonym, stable across exports so an
+          operator can still correlate events without learning identities
+- mask:   replaced by a fixed marker
+- drop:   removed from the record
+
+Field rules match at any depth of nested dicts and apply element-wise to
+lists. Anchoring already exports only digests and needs no policy.
+
+Ledger signal IDs name the subject of an event ("m3", "operator:alice")
+and halt reasons describe it in prose, so both are sensitive by default.
+Summaries stay internal: exporters that build one from an event (the
+notifier, the status snapshot) keep identities out of it and put them in
+fields the policy can see.
+
+Dependency: none
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import Any, Dict, Mapping
+
+from ..security.zero_trust_evidence import sign_hmac
+
+
+SENSITIVITIES = ("public", "internal", "sensitive", "secret")
+ACTIONS = ("keep", "pseudo", "mask", "drop")
+MASK = "[redacted]"
+
+DEFAULT_ACTIONS: Dict[str, str] = {
+    "public": "keep",
+    "internal": "keep",
+    "sensitive": "pseudo",
+    "secret": "drop",
+}
+
+# Field names known to identify people or expose internals.
+DEFAULT_FIELDS: Dict[str, str] = {
+    "member_id": "sensitive",
+    "operator_id": "sensitive",
+    "attester_id": "sensitive",
+    "approvers": "sensitive",
+    "requester": "sensitive",
+    "signer_id": "sensitive",
+    "signal_id": "sensitive",
+    "reason": "sensitive",
+    "summary": "internal",
+    "source": "internal",
+    "error": "internal",
+    "rationale": "secret",
+    "signature": "secret",
+    "secret": "secret",
+}
+
+
+class RedactionError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class RedactionPolicy:
+    """
+    INVARIANT: A field without a rule is treated at `default_sensitivity`.
+    INVARIANT: Pseudonyms are keyed; without the key they do not reverse by guessing.
+    """
+
+    key: bytes
+    fields: Mapping[str, str] = field(default_factory=lambda: dict(DEFAULT_FIELDS))
+    actions: Mapping[str, str] = field(default_factory=lambda: dict(DEFAULT_ACTIONS))
+    default_sensitivity: str = "public"
+
+    def __post_init__(self):
+        problems = []
+        for name, level in list(self.fields.items()) + [("(default)", self.default_sensitivity)]:
+            if level not in SENSITIVITIES:
+                problems.append(f"{name}: unknown sensitivity {level!r}")
+        for level in SENSITIVITIES:
+            if self.actions.get(level) not in ACTIONS:
+                problems.append(f"{level}: action must be one of {ACTIONS}")
+        if problems:
+            raise RedactionError("; ".join(problems))
+
+    def action_for(self, name: str) -> str:
+        return self.actions[self.fields.get(name, self.default_sensitivity)]
+
+    def pseudonym(self, value: Any) -> str:
+        return "anon-" + sign_hmac(self.key, {"v": value})[:12]
+
+    def redact(self, record: Mapping[str, Any]) -> Dict[str, Any]:
+        out: Dict[str, Any] = {}
+        for name, value in record.items():
+            action = self.action_for(name)
+            if action == "drop":
+                continue
+            out[name] = self._apply(action, value)
+        return out
+
+    def redact_field(self, name: str, value: Any) -> Any:
+        """Redact a lone value as if it were field `name`. Dropped values become None."""
+        action = self.action_for(name)
+        return None if action == "drop" else self._apply(action, value)
+
+    def _apply(self, action: str, value: Any) -> Any:
+        if isinstance(value, Mapping):
+            return self.redact(value) if action == "keep" else self._apply_leaf(action, value)
+        if isinstance(value, (list, tuple)):
+            return [self._apply(action, v) for v in value]
+        return self._apply_leaf(action, value)
+
+    def _apply_leaf(self, action: str, value: Any) -> Any:
+        if action == "keep":
+            return value
+        if action == "mask":
+            return MASK
+        return self.pseudonym(value)
@@ -0,0 +1,43 @@
+import unittest
+
+from sovereign_engine.extensions.redaction import MASK, RedactionError, RedactionPolicy
+
+
+class TestRedactionPolicy(unittest.TestCase):
+    def setUp(self):
+        self.policy = RedactionPolicy(key=b"export-key")
+
+    def test_default_policy_pseudonymises_people_and_drops_secrets(self):
+        out = self.policy.redact({
+            "member_id": "m1",
+            "approvers": ["m1", "m2"],
+            "signature": "ab" * 32,
+            "detail": {"requester": "m2", "count": 3},
+            "action": "halt",
+        })
+        self.assertEqual(out["member_id"], out["approvers"][0])
+        self.assertNotEqual(out["approvers"][0], out["approvers"][1])
+        self.assertTrue(out["member_id"].startswith("anon-"))
+        self.assertEqual(out["detail"], {"requester": out["approvers"][1], "count": 3})
+        self.assertNotIn("signature", out)
+        self.assertEqual(out["action"], "halt")
+
+    def test_pseudonyms_depend_on_the_key(self):
+        other = RedactionPolicy(key=b"another-key")
+        self.assertNotEqual(self.policy.pseudonym("m1"), other.pseudonym("m1"))
+        self.assertEqual(self.policy.pseudonym("m1"), RedactionPolicy(b"export-key").pseudonym("m1"))
+
+    def test_policy_is_configurable_and_validated(self):
+        strict = RedactionPolicy(key=b"k", fields={"region": "sensitive"},
+                                 actions={"public": "mask", "internal": "mask",
+                                          "sensitive": "drop", "secret": "drop"})
+        self.assertEqual(strict.redact({"region": "eu", "node": "n1"}), {"node": MASK})
+        self.assertIsNone(strict.redact_field("region", "eu"))
+        with self.assertRaises(RedactionError):
+            RedactionPolicy(key=b"k", fields={"x": "classified"})
+        with self.assertRaises(RedactionError):
+            RedactionPolicy(key=b"k", actions={"public": "shred"})
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,21 @@
+from .rebuild import (
+    AdvisoryProjection,
+    ConsistencyChecker,
+    ConsistencyReport,
+    Drift,
+    HaltProjection,
+    Projection,
+    RecoveryProjection,
+    RosterProjection,
+)
+
+__all__ = [
+    "AdvisoryProjection",
+    "ConsistencyChecker",
+    "ConsistencyReport",
+    "Drift",
+    "HaltProjection",
+    "Projection",
+    "RecoveryProjection",
+    "RosterProjection",
+]
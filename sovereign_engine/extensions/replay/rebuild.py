@@ -0,0 +1,166 @@
+"""
+Replay - Rebuild module state from the audit ledger and diff it against live state.
+S3-EXT-034: Event-sourced audit bolt-on.
+
+If the ledger is the source of truth, then any state a module reports
+must be recomputable from the ledger alone. Each projection here folds
+ledger entries into the state one module keeps, using only the chained
+fields of each entry (`extra` is not hashed, so nothing is rebuilt from
+it). The consistency checker runs every projection and compares the
+result with what the live object reports. Any difference is drift: either
+a module changed state without writing it down, or the ledger was
+written without the change happening.
+
+Covered:
+- quorum roster member states (`quorum_roster` entries)
+- advisory desk open warnings (`advisory_desk` entries)
+- recovery progress per halt ID (`recovery` entries)
+- routing halts (`halt` / `system_halt` routing decisions)
+
+FailureMatrix halts are not ledgered by the kernel, so the halt
+projection compares routing halts only.
+
+Dependency: Phase 4 (audit), S3-EXT-003, S3-EXT-025, S3-EXT-032
+"""
+
+from __future__ import annotations
+
+from abc import ABC, abstractmethod
+from dataclasses import dataclass
+from typing import Any, Dict, Iterable, List, Tuple
+
+from ..security.zero_trust_evidence import canonical_json
+
+
+class Projection(ABC):
+    """Folds ledger entries into one module's state."""
+
+    name: str = "abstract"
+
+    @abstractmethod
+    def rebuild(self, entries: Iterable) -> Dict[str, Any]:
+        """State derived from the ledger alone."""
+
+    @abstractmethod
+    def live(self, subject) -> Dict[str, Any]:
+        """The same state as the live module reports it."""
+
+
+class RosterProjection(Projection):
+    name = "quorum_roster"
+
+    def rebuild(self, entries):
+        states: Dict[str, str] = {}
+        for e in entries:
+            if e.route == "quorum_roster" and e.outcome.startswith("member_"):
+                states[e.signal_id] = e.outcome[len("member_"):]
+        return states
+
+    def live(self, roster):
+        # Members that never transitioned have no ledger record to rebuild from.
+        touched = {t.member_id for t in roster.history}
+        return {m: s.value for m, s in roster.states.items() if m in touched}
+
+
+class AdvisoryProjection(Projection):
+    name = "advisory_desk"
+
+    def rebuild(self, entries):
+        state: Dict[str, str] = {}
+        for e in entries:
+            if e.route != "advisory_desk":
+                continue
+            if e.outcome == "warning_raised":
+                state[e.signal_id] = "open"
+            elif e.outcome == "warning_acknowledged":
+                state[e.signal_id] = "acknowledged"
+        return state
+
+    def live(self, desk):
+        return {a.warning_id: "open" if a.is_open else "acknowledged" for a in desk.advisories}
+
+
+class RecoveryProjection(Projection):
+    name = "recovery"
+
+    def rebuild(self, entries):
+        steps: Dict[str, List[str]] = {}
+        for e in entries:
+            if e.route == "recovery" and e.outcome.startswith("recovery_"):
+                steps.setdefault(e.signal_id, []).append(e.outcome[len("recovery_"):])
+        return steps
+
+    def live(self, sessions):
+        return {s.halt_id: [step.action.value for step in s.completed]
+                for s in sessions if s.completed}
+
+
+class HaltProjection(Projection):
+    name = "routing_halts"
+
+    def rebuild(self, entries):
+        count = sum(1 for e in entries if e.outcome in ("halt", "system_halt"))
+        return {"routing_halts": count}
+
+    def live(self, halt_ctrl):
+        return {"routing_halts": sum(1 for h in halt_ctrl.halt_history if h["source"] == "router")}
+
+
+@dataclass(frozen=True)
+class Drift:
+    projection: str
+    key: str
+    from_ledger: Any
+    live: Any
+
+
+@dataclass(frozen=True)
+class ConsistencyReport:
+    entries_replayed: int
+    projections: List[str]
+    drift: List[Drift]
+
+    @property
+    def consistent(self) -> bool:
+        return not self.drift
+
+
+class ConsistencyChecker:
+    """
+    INVARIANT: Rebuilt state depends only on chained ledger fields.
+    INVARIANT: Every drift found is reported; none is reconciled here.
+    """
+
+    def __init__(self, ledger):
+        self._ledger = ledger
+        self._subjects: List[Tuple[Projection, Any]] = []
+
+    def add(self, projection: Projection, subject) -> "ConsistencyChecker":
+        self._subjects.append((projection, subject))
+        return self
+
+    def check(self, record: bool = False) -> ConsistencyReport:
+        entries = self._ledger.entries
+        drift: List[Drift] = []
+        for projection, subject in self._subjects:
+            rebuilt = projection.rebuild(entries)
+            live = projection.live(subject)
+            for key in sorted(set(rebuilt) | set(live)):
+                if rebuilt.get(key) != live.get(key):
+                    drift.append(Drift(projection.name, key, rebuilt.get(key), live.get(key)))
+        report = ConsistencyReport(
+            entries_replayed=len(entries),
+            projections=[p.name for p, _ in self._subjects],
+            drift=drift,
+        )
+        if record:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "replay",
+                "handler": "consistency_checker",
+                "outcome": "state_consistent" if report.consistent else "state_drift",
+                "signal_id": self._ledger.last_hash,
+                "signal_domain": "governance",
+                "extra": canonical_json([d.__dict__ for d in drift]),
+            })
+        return report
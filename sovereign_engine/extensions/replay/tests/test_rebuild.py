@@ -0,0 +1,78 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.advisories import AdvisoryDesk
+from sovereign_engine.extensions.quorum import MemberState, QuorumRoster, attest
+from sovereign_engine.extensions.recovery import (
+    RecoveryAction,
+    RecoveryPlaybook,
+    RecoverySession,
+    sign_review,
+)
+from sovereign_engine.extensions.replay import (
+    AdvisoryProjection,
+    ConsistencyChecker,
+    HaltProjection,
+    RecoveryProjection,
+    RosterProjection,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+SECRETS = {m: f"secret-{m}".encode() for m in ["m1", "m2", "m3", "op", "s1"]}
+
+
+class TestConsistencyChecker(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        ledger = self.engine.ledger
+        self.roster = QuorumRoster(["m1", "m2", "m3"], threshold=2,
+                                   secret_resolver=SECRETS.get, ledger=ledger)
+        self.desk = AdvisoryDesk(SECRETS.get, ledger=ledger)
+        self.checker = (ConsistencyChecker(ledger)
+                        .add(RosterProjection(), self.roster)
+                        .add(AdvisoryProjection(), self.desk)
+                        .add(HaltProjection(), self.engine.halt_ctrl))
+
+    def test_live_state_matches_rebuild(self):
+        self.roster.transition("m3", MemberState.SABBATICAL, [
+            attest(m, SECRETS[m], "m3", MemberState.ACTIVE, MemberState.SABBATICAL, "leave")
+            for m in ["m1", "m2"]], reason="leave")
+        self.desk.raise_warning("observatory", "anomalous")
+        self.engine.submit_and_process("halt", "emergency", "steward", {}, source="steward")
+        session = RecoverySession(self.engine, RecoveryPlaybook.from_constitution(
+            self.engine.constitution), ["s1"], SECRETS.get)
+        session.perform(RecoveryAction.STEWARD_REVIEW, "reviewed", {
+            "s1": sign_review(SECRETS["s1"], session.halt_id, RecoveryAction.STEWARD_REVIEW,
+                              "reviewed")})
+        self.checker.add(RecoveryProjection(), [session])
+        report = self.checker.check(record=True)
+        self.assertTrue(report.consistent, report.drift)
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "state_consistent")
+
+    def test_unledgered_change_is_drift(self):
+        warning = self.desk.raise_warning("observatory", "anomalous")
+        warning.acknowledged_by = "op"          # bypasses the signed, ledgered path
+        report = self.checker.check()
+        self.assertEqual([(d.projection, d.from_ledger, d.live) for d in report.drift],
+                         [("advisory_desk", "open", "acknowledged")])
+
+    def test_ledger_entry_without_state_change_is_drift(self):
+        warning = self.desk.raise_warning("observatory", "anomalous")
+        self.engine.ledger.write({
+            "signal_type": "audit", "route": "advisory_desk", "handler": "advisory_desk",
+            "outcome": "warning_acknowledged", "signal_id": warning.warning_id,
+            "signal_domain": "governance",
+        })
+        report = self.checker.check(record=True)
+        self.assertEqual([(d.key, d.from_ledger, d.live) for d in report.drift],
+                         [(warning.warning_id, "acknowledged", "open")])
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "state_drift")
+
+
+if __name__ == "__main__":
+    unittest.main()
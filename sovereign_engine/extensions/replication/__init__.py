@@ -0,0 +1,15 @@
+from .split_brain import (
+    SPLIT_BRAIN_FAILURE,
+    ForkEvidence,
+    SignedHead,
+    SplitBrainDetector,
+    SplitBrainError,
+)
+
+__all__ = [
+    "SPLIT_BRAIN_FAILURE",
+    "ForkEvidence",
+    "SignedHead",
+    "SplitBrainDetector",
+    "SplitBrainError",
+]
@@ -0,0 +1,156 @@
+"""
+Split-Brain Detector - Redundant nodes must agree on one history.
+S3-EXT-035: Replication bolt-on.
+
+Replicas of one deployment (same genesis) are supposed to hold the same
+audit chain, one possibly behind the other. If two replicas both accepted
+writes on their own, their chains fork and both will go on issuing
+decisions the other never saw. Nodes therefore exchange signed ledger
+heads. Whichever node holds the longer chain checks the shorter head
+against its own entry at that length. A mismatch is a fork: the node
+halts through the failure doctrine with failure type `split_brain`, and
+produces signed evidence its peer can verify and halt on too. Neither
+node can argue its way out of the halt; the evidence pins both chains.
+
+Recovery follows the `split_brain_resolution` playbook, which requires
+dual-key constitutional review before either replica resumes.
+
+Dependency: Phase 4 (audit), Phase 6 (failure), S3-EXT-025 (recovery)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass
+from typing import Callable, Dict, List, Optional
+
+from ..security.zero_trust_evidence import canonical_json, sign_hmac, verify_hmac
+
+
+SPLIT_BRAIN_FAILURE = "split_brain"
+
+
+class SplitBrainError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class SignedHead:
+    node_id: str
+    genesis_hash: str
+    length: int
+    head: str
+    at: float
+    signature: str = ""
+
+    def payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        return data
+
+    def verify(self, secret: bytes) -> bool:
+        return verify_hmac(secret, self.payload(), self.signature)
+
+
+@dataclass(frozen=True)
+class ForkEvidence:
+    """Two signed heads that cannot both be prefixes of one chain."""
+    theirs: SignedHead
+    ours_at_their_length: SignedHead
+
+    def verify(self, secret_resolver: Callable[[str], bytes]) -> bool:
+        a, b = self.theirs, self.ours_at_their_length
+        return (a.genesis_hash == b.genesis_hash and a.length == b.length
+                and a.head != b.head and a.node_id != b.node_id
+                and a.verify(secret_resolver(a.node_id))
+                and b.verify(secret_resolver(b.node_id)))
+
+    def to_dict(self) -> dict:
+        return {"theirs": asdict(self.theirs), "ours": asdict(self.ours_at_their_length)}
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "ForkEvidence":
+        return cls(SignedHead(**data["theirs"]), SignedHead(**data["ours"]))
+
+
+class SplitBrainDetector:
+    """
+    INVARIANT: Only heads signed by a known peer of the same genesis are compared.
+    INVARIANT: A detected fork halts this node and yields evidence that halts the peer.
+    """
+
+    def __init__(self, node_id: str, engine, genesis_hash: str, secret: bytes,
+                 secret_resolver: Callable[[str], bytes], peers: List[str],
+                 clock: Callable[[], float] = time.time):
+        self.node_id = node_id
+        self._engine = engine
+        self._genesis_hash = genesis_hash
+        self._secret = secret
+        self._secret_resolver = secret_resolver
+        self._peers = frozenset(peers) - {node_id}
+        self._clock = clock
+        self._latest: Dict[str, SignedHead] = {}
+        self._forks: List[ForkEvidence] = []
+
+    def _sign(self, length: int, head: str) -> SignedHead:
+        unsigned = SignedHead(self.node_id, self._genesis_hash, length, head, self._clock())
+        return SignedHead(**{**asdict(unsigned),
+                             "signature": sign_hmac(self._secret, unsigned.payload())})
+
+    def announce(self) -> SignedHead:
+        ledger = self._engine.ledger
+        return self._sign(ledger.length, ledger.last_hash)
+
+    def receive(self, head: SignedHead) -> Optional[ForkEvidence]:
+        """Compare a peer's head with the local chain. Returns evidence on a fork."""
+        if head.node_id not in self._peers:
+            raise SplitBrainError(f"{head.node_id} is not a peer")
+        if head.genesis_hash != self._genesis_hash:
+            raise SplitBrainError(f"{head.node_id} runs a different genesis")
+        if not head.verify(self._secret_resolver(head.node_id)):
+            raise SplitBrainError(f"Bad head signature from {head.node_id}")
+        self._latest[head.node_id] = head
+
+        entries = self._engine.ledger.entries
+        if head.length == 0 or head.length > len(entries):
+            return None     # the peer is ahead; it checks our head
+        ours = entries[head.length - 1].hash
+        if ours == head.head:
+            return None
+        evidence = ForkEvidence(theirs=head, ours_at_their_length=self._sign(head.length, ours))
+        self._halt(evidence)
+        return evidence
+
+    def receive_evidence(self, evidence: ForkEvidence) -> bool:
+        """A peer found a fork involving this node. Halt if the evidence holds."""
+        involved = {evidence.theirs.node_id, evidence.ours_at_their_length.node_id}
+        if self.node_id not in involved or not evidence.verify(self._secret_resolver):
+            return False
+        self._halt(evidence)
+        return True
+
+    def _halt(self, evidence: ForkEvidence):
+        self._forks.append(evidence)
+        a, b = evidence.theirs, evidence.ours_at_their_length
+        self._engine.ledger.write({
+            "signal_type": "system",
+            "route": "replication",
+            "handler": "split_brain_detector",
+            "outcome": "split_brain_detected",
+            "signal_id": f"{a.head}:{b.head}",
+            "signal_domain": "constitutional",
+            "extra": canonical_json(evidence.to_dict()),
+        })
+        if not self._engine.is_halted:
+            self._engine.health.report_failure(
+                "split_brain_detector", SPLIT_BRAIN_FAILURE,
+                f"{a.node_id} and {b.node_id} diverge at length {a.length}",
+            )
+
+    @property
+    def forks(self) -> List[ForkEvidence]:
+        return list(self._forks)
+
+    @property
+    def latest_heads(self) -> Dict[str, SignedHead]:
+        return dict(self._latest)
@@ -35,7 +35,8 @@ def _iter_files(root: Path, paths: Iterable[str]) -> List[Path]:
             out.append(p)
         elif p.is_dir():
             for f in sorted(p.rglob("*")):
-                if f.is_file():
+                # Bytecode caches differ by interpreter, not by kernel.
+                if f.is_file() and "__pycache__" not in f.parts:
                     out.append(f)
     return sorted(out)
 
@@ -6,7 +6,7 @@ from hashlib import sha256
 import hmac
 import json
 from pathlib import Path
-from typing import Any, Callable, Dict, Iterable, List, Tuple
+from typing import Any, Callable, Dict, Iterable, List, Optional, Tuple
 
 
 class SecurityError(RuntimeError):
@@ -88,7 +88,10 @@ def sign_hmac(secret: bytes, payload: Dict[str, Any]) -> str:
     return hmac.new(secret, msg, digestmod="sha256").hexdigest()
 
 
-def verify_hmac(secret: bytes, payload: Dict[str, Any], signature_hex: str) -> bool:
+def verify_hmac(secret: Optional[bytes], payload: Dict[str, Any], signature_hex: str) -> bool:
+    # Resolvers return None for unknown or revoked keys; that never verifies.
+    if secret is None:
+        return False
     computed = sign_hmac(secret, payload)
     return hmac.compare_digest(computed, signature_hex)
 
@@ -262,7 +265,7 @@ class ZeroTrustEvidenceChain:
 
                 unsigned = self._unsigned_payload(rec)
                 secret = self.secret_resolver(rec["key_id"])
-                if not verify_hmac(bytes(secret), unsigned, rec["signature"]):
+                if not verify_hmac(secret, unsigned, rec["signature"]):
                     errors.append(f"L{idx}: invalid signature")
 
                 signed_for_hash = dict(unsigned)
@@ -0,0 +1,17 @@
+from .audit import (
+    ADVISORY_CHECKS,
+    CHECK_FAILURE_TYPES,
+    AuditCheck,
+    SelfAuditError,
+    SelfAuditReport,
+    self_audit,
+)
+
+__all__ = [
+    "ADVISORY_CHECKS",
+    "CHECK_FAILURE_TYPES",
+    "AuditCheck",
+    "SelfAuditError",
+    "SelfAuditReport",
+    "self_audit",
+]
@@ -0,0 +1,224 @@
+"""
+Self Audit - Runtime internal consistency check.
+S3-EXT-020: Self-audit bolt-on.
+
+Boot validation runs once. A long-lived engine can drift afterwards: a
+ledger entry rewritten in memory, a kernel file edited under a running
+process, a router halted without the halt controller knowing. `self_audit`
+re-checks those properties on demand and produces a signed report, meant
+to be run at startup and then on a schedule.
+
+Failed checks are not just reported — they are handed to the failure
+matrix, so an audit failure halts exactly as the constitution requires.
+Advisory checks are the exception: the watchdog check reads heartbeats
+that no component in this tree sends yet, so it is reported but never
+enforced.
+
+Dependency: Phase 0, Phase 4, Phase 5, Phase 6, Phase 8
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Callable, List, Optional
+
+from ..genesis import constitution_hash
+from ..security.constitutional_enforcer import compute_kernel_fingerprint
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+
+
+class SelfAuditError(Exception):
+    pass
+
+
+# Failure type handed to the failure matrix per check. Anything not listed
+# is unknown to the constitution and therefore halts by doctrine.
+CHECK_FAILURE_TYPES = {
+    "ledger_chain": "audit_failure",
+    "constitution_invariants": "authority_breach",
+}
+
+# Reported in the signed report, never handed to the failure matrix.
+ADVISORY_CHECKS = frozenset({"watchdog_state"})
+
+
+@dataclass
+class AuditCheck:
+    name: str
+    passed: bool
+    detail: str = ""
+    advisory: bool = False
+
+
+@dataclass
+class SelfAuditReport:
+    checks: List[AuditCheck]
+    ledger_length: int
+    ledger_head: str
+    signer_id: str
+    timestamp: float = field(default_factory=time.time)
+    signature: str = ""
+
+    @property
+    def passed(self) -> bool:
+        return not self.failed_checks
+
+    @property
+    def failed_checks(self) -> List[str]:
+        return [c.name for c in self.checks if not c.passed and not c.advisory]
+
+    @property
+    def advisories(self) -> List[str]:
+        return [c.name for c in self.checks if not c.passed and c.advisory]
+
+    def unsigned_payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        return data
+
+    @property
+    def report_hash(self) -> str:
+        return sha256_hex(canonical_json(self.unsigned_payload()))
+
+    def verify(self, secret: bytes) -> bool:
+        return verify_hmac(secret, self.unsigned_payload(), self.signature)
+
+
+def _check_constitution(engine) -> AuditCheck:
+    report = engine.constitution.run_invariant_tests()
+    return AuditCheck(
+        "constitution_invariants",
+        report.all_passed,
+        f"{report.passed}/{report.total} invariant tests passed",
+    )
+
+
+def _check_ledger(engine) -> AuditCheck:
+    v = engine.ledger.verify()
+    if v["valid"]:
+        return AuditCheck("ledger_chain", True, f"{v['total_entries']} entries verified")
+    first = v["corruptions"][0]
+    return AuditCheck(
+        "ledger_chain", False,
+        f"corruption at index {first['index']}: {first['reason']}",
+    )
+
+
+def _check_halt_consistency(engine) -> AuditCheck:
+    # The router and bus can each be halted directly; the halt controller
+    # must know about it, or resume logic will reopen a halted path.
+    problems = []
+    if engine.router.is_halted and not engine.halt_ctrl.is_halted:
+        problems.append("router halted without halt controller")
+    if engine.bus.is_halted and not engine.halt_ctrl.is_halted:
+        problems.append("bus halted without halt controller")
+    if engine.halt_ctrl.is_halted and not engine.halt_ctrl.halt_history:
+        problems.append("halted with empty halt history")
+    return AuditCheck("halt_consistency", not problems, "; ".join(problems) or "consistent")
+
+
+def _check_watchdog(engine, now: float) -> AuditCheck:
+    # Read-only: Watchdog.check() would flip alive flags as a side effect.
+    interval_s = engine.constitution.get_timing("watchdog_interval_ms") / 1000.0
+    stale, future = [], []
+    for name, state in engine.watchdog.states.items():
+        if state.last_heartbeat > now:
+            future.append(name)
+        elif now - state.last_heartbeat > interval_s:
+            stale.append(name)
+    problems = []
+    if future:
+        problems.append(f"heartbeats from the future: {sorted(future)}")
+    if stale:
+        problems.append(f"missed heartbeat: {sorted(stale)}")
+    return AuditCheck(
+        "watchdog_state", not problems, "; ".join(problems) or "all components live",
+        advisory=True,
+    )
+
+
+def _check_kernel(repo_root: str, expected: str) -> AuditCheck:
+    current = compute_kernel_fingerprint(repo_root)
+    return AuditCheck(
+        "kernel_fingerprint", current == expected,
+        "matches" if current == expected else f"expected {expected[:12]}…, got {current[:12]}…",
+    )
+
+
+def _check_genesis(engine, genesis) -> AuditCheck:
+    problems = []
+    if constitution_hash(genesis.constitution_path) != genesis.constitution_hash:
+        problems.append("constitution drifted from genesis")
+    if not any(
+        e.outcome == "genesis_bound" and e.signal_id == genesis.genesis_hash
+        for e in engine.ledger.entries
+    ):
+        problems.append("ledger not bound to genesis record")
+    return AuditCheck("genesis_binding", not problems, "; ".join(problems) or "bound")
+
+
+def self_audit(
+    engine,
+    signer_id: str,
+    secret: bytes,
+    expected_kernel_fingerprint: Optional[str] = None,
+    repo_root: str = ".",
+    genesis=None,
+    enforce: bool = True,
+    clock: Callable[[], float] = time.time,
+) -> SelfAuditReport:
+    """
+    Check engine consistency and return a signed report.
+
+    The report hash is written to the audit ledger. With `enforce`, each
+    failed non-advisory check is reported to the health monitor, which
+    applies the constitutional failure response.
+    """
+    if not engine.is_booted:
+        raise SelfAuditError("Engine not booted")
+
+    now = clock()
+    checks = [
+        _check_constitution(engine),
+        _check_ledger(engine),
+        _check_halt_consistency(engine),
+        _check_watchdog(engine, now),
+    ]
+    if expected_kernel_fingerprint:
+        checks.append(_check_kernel(repo_root, expected_kernel_fingerprint))
+    if genesis is not None:
+        checks.append(_check_genesis(engine, genesis))
+
+    report = SelfAuditReport(
+        checks=checks,
+        ledger_length=engine.ledger.length,
+        ledger_head=engine.ledger.last_hash,
+        signer_id=signer_id,
+        timestamp=now,
+    )
+    report.signature = sign_hmac(bytes(secret), report.unsigned_payload())
+
+    engine.ledger.write({
+        "signal_type": "audit",
+        "route": "self_audit",
+        "handler": "self_audit",
+        "outcome": "self_audit_pass" if report.passed else "self_audit_fail",
+        "signal_id": report.report_hash,
+        "signal_domain": "constitutional",
+        "extra": canonical_json({
+            "failed": report.failed_checks,
+            "advisory": report.advisories,
+        }),
+    })
+
+    if enforce:
+        for check in checks:
+            if not check.passed and not check.advisory:
+                engine.health.report_failure(
+                    "self_audit",
+                    CHECK_FAILURE_TYPES.get(check.name, f"self_audit_{check.name}"),
+                    check.detail,
+                )
+
+    return report
@@ -0,0 +1,60 @@
+import dataclasses
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.self_audit import self_audit
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+NODE_KEY = b"node-secret"
+
+
+def booted_engine():
+    engine = SovereignEngine(CONFIG_PATH)
+    engine.boot()
+    for i in range(3):
+        engine.submit_and_process("query", "operational", "operator", {"n": i})
+    return engine
+
+
+class TestSelfAudit(unittest.TestCase):
+    def test_clean_engine_passes_with_signed_report(self):
+        engine = booted_engine()
+        report = self_audit(engine, "node-1", NODE_KEY)
+        self.assertTrue(report.passed, report.checks)
+        self.assertTrue(report.verify(NODE_KEY))
+        self.assertEqual(engine.ledger.entries[-1].outcome, "self_audit_pass")
+        self.assertEqual(engine.ledger.entries[-1].signal_id, report.report_hash)
+        self.assertFalse(engine.is_halted)
+
+    def test_ledger_tamper_fails_and_halts(self):
+        engine = booted_engine()
+        entries = engine.ledger._entries
+        entries[1] = dataclasses.replace(entries[1], outcome="forged")
+
+        report = self_audit(engine, "node-1", NODE_KEY)
+        self.assertIn("ledger_chain", report.failed_checks)
+        self.assertTrue(engine.is_halted)
+        self.assertIn("audit_failure", engine.halt_ctrl.halt_reason)
+
+    def test_split_halt_state_detected(self):
+        engine = booted_engine()
+        engine.router.halt("direct")
+        report = self_audit(engine, "node-1", NODE_KEY, enforce=False)
+        self.assertEqual(report.failed_checks, ["halt_consistency"])
+        self.assertFalse(engine.is_halted)
+
+    def test_stale_watchdog_is_advisory(self):
+        engine = booted_engine()
+        report = self_audit(engine, "node-1", NODE_KEY,
+                            clock=lambda: engine.watchdog.states["router"].last_heartbeat + 60)
+        self.assertEqual(report.advisories, ["watchdog_state"])
+        self.assertTrue(report.passed)
+        self.assertFalse(engine.is_halted)
+        self.assertTrue(engine.submit_and_process("query", "operational", "operator", {})["processed"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,34 @@
+from .collect import SovereignStatus
+from .onboarding import (
+    OnboardingError,
+    OnboardingPackage,
+    build_onboarding_package,
+    verify_onboarding_package,
+)
+from .probes import LIVENESS_PATH, READINESS_PATH, HealthEndpointAdapter, ProbeResult
+from .report import (
+    REPORT_EVIDENCE_TYPE,
+    ReportError,
+    StateReport,
+    build_state_report,
+    render_text,
+    verify_state_report,
+)
+
+__all__ = [
+    "LIVENESS_PATH",
+    "READINESS_PATH",
+    "REPORT_EVIDENCE_TYPE",
+    "HealthEndpointAdapter",
+    "OnboardingError",
+    "OnboardingPackage",
+    "ProbeResult",
+    "ReportError",
+    "SovereignStatus",
+    "StateReport",
+    "build_onboarding_package",
+    "build_state_report",
+    "render_text",
+    "verify_onboarding_package",
+    "verify_state_report",
+]
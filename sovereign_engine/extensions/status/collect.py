@@ -0,0 +1,132 @@
+"""
+Sovereign Status - One aggregated, read-only snapshot for dashboards.
+S3-EXT-026: Governance dashboard bolt-on.
+
+A dashboard should make one call per refresh and get one consistent
+structure back, rather than poking at a dozen components and racing
+their updates. `SovereignStatus.collect` reads the engine and whichever
+bolt-ons are wired (quorum roster, recovery session, anchor queue, epoch
+ceremony) and never writes — collecting status leaves no ledger entry.
+Pass a RedactionPolicy to `to_dict`/`to_json` when the snapshot leaves the
+deployment; the last halt's reason and source are governed by it.
+
+Dependency: Phase 8 (engine), S3-EXT-003, S3-EXT-019, S3-EXT-021, S3-EXT-025
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass, field
+from typing import Any, Dict, List, Optional
+
+from ..security.zero_trust_evidence import canonical_json
+
+
+@dataclass(frozen=True)
+class SovereignStatus:
+    lifecycle: str                    # unbooted | running | halted | recovering
+    collected_at: float
+    engine: Dict[str, Any] = field(default_factory=dict)
+    ledger: Dict[str, Any] = field(default_factory=dict)
+    last_halt: Optional[Dict[str, Any]] = None
+    health: Dict[str, Any] = field(default_factory=dict)
+    watchdog: Dict[str, bool] = field(default_factory=dict)
+    timing_breaches: int = 0
+    containment_events: int = 0
+    quorum: Optional[Dict[str, Any]] = None
+    recovery: Optional[Dict[str, Any]] = None
+    anchoring: Optional[Dict[str, Any]] = None
+    epoch: Optional[Dict[str, Any]] = None
+
+    @classmethod
+    def collect(cls, engine, roster=None, recovery=None, anchors=None,
+                epochs=None) -> "SovereignStatus":
+        if not engine.is_booted:
+            return cls(lifecycle="unbooted", collected_at=time.time())
+
+        if recovery is not None and recovery.next_action is not None:
+            lifecycle = "recovering"
+        elif engine.is_halted:
+            lifecycle = "halted"
+        else:
+            lifecycle = "running"
+
+        history = engine.halt_ctrl.halt_history
+        return cls(
+            lifecycle=lifecycle,
+            collected_at=time.time(),
+            engine=engine.engine_stats,
+            ledger={"length": engine.ledger.length, "head": engine.ledger.last_hash},
+            last_halt=dict(history[-1]) if history else None,
+            health={
+                "all_healthy": engine.health.all_healthy,
+                "unhealthy": engine.health.get_unhealthy(),
+            },
+            watchdog={name: s.alive for name, s in engine.watchdog.states.items()},
+            timing_breaches=engine.timing.breach_count,
+            containment_events=len(engine.gate.containment_log),
+            quorum=_quorum(roster),
+            recovery=_recovery(recovery),
+            anchoring=_anchoring(anchors),
+            epoch=_epoch(epochs),
+        )
+
+    def to_dict(self, redaction=None) -> Dict[str, Any]:
+        data = asdict(self)
+        return data if redaction is None else redaction.redact(data)
+
+    def to_json(self, redaction=None) -> str:
+        return canonical_json(self.to_dict(redaction))
+
+
+def _quorum(roster) -> Optional[Dict[str, Any]]:
+    if roster is None:
+        return None
+    counts: Dict[str, int] = {}
+    for state in roster.states.values():
+        counts[state.value] = counts.get(state.value, 0) + 1
+    shortfalls = roster.shortfalls
+    return {
+        "threshold": roster.threshold,
+        "achievable": roster.achievable_quorum,
+        "quorum_achievable": roster.quorum_achievable,
+        "states": counts,
+        "backups": len(roster.backups),
+        "last_shortfall_at": shortfalls[-1].timestamp if shortfalls else None,
+    }
+
+
+def _recovery(session) -> Optional[Dict[str, Any]]:
+    if session is None:
+        return None
+    nxt = session.next_action
+    return {
+        "halt_id": session.halt_id,
+        "recovery_code": session.recovery_code,
+        "completed": [s.action.value for s in session.completed],
+        "next_action": nxt.value if nxt else None,
+    }
+
+
+def _anchoring(queue) -> Optional[Dict[str, Any]]:
+    if queue is None:
+        return None
+    receipts: List = queue.receipts
+    return {
+        "pending": len(queue.pending),
+        "abandoned": len(queue.abandoned),
+        "anchored": queue.stats.anchored,
+        "last_anchored_at": receipts[-1].anchored_at if receipts else None,
+    }
+
+
+def _epoch(ceremony) -> Optional[Dict[str, Any]]:
+    if ceremony is None or not ceremony.history:
+        return None
+    seed = ceremony.current
+    return {
+        "epoch": seed.epoch,
+        "beacon_round": seed.beacon.round,
+        "beacon_source": seed.beacon.source,
+        "seed": seed.seed,
+    }
@@ -0,0 +1,148 @@
+"""
+Onboarding Package - What a newly activated member needs to act responsibly.
+
+A backup promoted into the quorum joins mid-story. The package gives
+them the minimum verified history in one signed document: the status
+snapshot (including watchdog state), the open halt if any, recent halts,
+recent recovery steps and quorum decisions, the canon root with the leaf
+set behind it, and the constitutional profile they are now bound by.
+
+The package is signed by a steward key whose commitment is in the
+genesis record, so the member's own tooling can check it against genesis
+without trusting the runtime that handed it over. Issuing a package is
+ledgered.
+
+Dependency: S3-EXT-018 (genesis), S3-EXT-025 (recovery), S3-EXT-029 (museum)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass
+from typing import Any, Callable, Dict, List, Optional
+
+from ..genesis import key_commitment
+from ..museum import CanonProof, Museum, verify_canon
+from ..recovery import halt_id
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+from .collect import SovereignStatus
+
+
+# Ledger routes whose entries count as adjudication history.
+ADJUDICATION_ROUTES = ("recovery", "quorum_decision")
+
+
+class OnboardingError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class OnboardingPackage:
+    member_id: str
+    issued_at: float
+    genesis_hash: str
+    profile: Dict[str, Any]
+    status: Dict[str, Any]
+    open_halt: Optional[Dict[str, Any]]
+    recent_halts: List[Dict[str, Any]]
+    recent_adjudications: List[Dict[str, Any]]
+    canon: Optional[Dict[str, Any]]
+    signer_id: str
+    signature: str = ""
+    package_hash: str = ""
+
+    def unsigned_payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        data.pop("package_hash")
+        return data
+
+    def to_json(self) -> str:
+        return canonical_json(asdict(self))
+
+
+def build_onboarding_package(member_id: str, engine, genesis, signer_id: str,
+                             signer_secret: bytes, roster=None, vault=None,
+                             recent: int = 20, halts=None,
+                             clock: Callable[[], float] = time.time) -> OnboardingPackage:
+    """
+    Assemble, sign and ledger a package for `member_id`.
+    `halts` is the halt history source; pass a HaltLog for canonical IDs.
+    """
+    if genesis.stewards.get(signer_id) != key_commitment(signer_secret):
+        raise OnboardingError(f"Signer {signer_id} is not a steward with a matching key")
+    if roster is not None and member_id not in roster.states:
+        raise OnboardingError(f"{member_id} is not on the roster")
+
+    source = engine.halt_ctrl if halts is None else halts
+    museum = Museum(engine.ledger, source, vault)
+    history = source.halt_history
+    open_halt = None
+    if engine.is_halted and history:
+        open_halt = {"halt_id": halt_id(history[-1]), **history[-1]}
+    halts = museum.halts(cursor=max(0, len(history) - recent), limit=max(1, recent)).items
+
+    adjudications = [asdict(e) for e in engine.ledger.entries if e.route in ADJUDICATION_ROUTES]
+    canon = None
+    if vault is not None:
+        ids = vault.active_evidence()
+        canon = {
+            "root": vault.canon_root(ids),
+            "leaves": vault.canon_leaves(ids),
+            "evidence_ids": ids,
+        }
+
+    unsigned = OnboardingPackage(
+        member_id=member_id,
+        issued_at=clock(),
+        genesis_hash=genesis.genesis_hash,
+        profile={
+            "archetype": genesis.archetype,
+            "constitution_hash": genesis.constitution_hash,
+            "quorum_threshold": genesis.quorum_threshold,
+            "emergency_policy": genesis.emergency_policy,
+        },
+        status=SovereignStatus.collect(engine, roster=roster).to_dict(),
+        open_halt=open_halt,
+        recent_halts=halts,
+        recent_adjudications=adjudications[-recent:] if recent else [],
+        canon=canon,
+        signer_id=signer_id,
+    )
+    payload = unsigned.unsigned_payload()
+    package = OnboardingPackage(**{
+        **asdict(unsigned),
+        "signature": sign_hmac(bytes(signer_secret), payload),
+        "package_hash": sha256_hex(canonical_json(payload)),
+    })
+    engine.ledger.write({
+        "signal_type": "audit",
+        "route": "onboarding",
+        "handler": "status",
+        "outcome": "onboarding_package_issued",
+        "signal_id": package.package_hash,
+        "signal_domain": "governance",
+        "extra": member_id,
+    })
+    return package
+
+
+def verify_onboarding_package(package: OnboardingPackage, genesis,
+                              secret_resolver: Callable[[str], bytes]) -> List[str]:
+    """Member-side checks. Empty means the package can be relied on."""
+    problems: List[str] = []
+    payload = package.unsigned_payload()
+    if package.package_hash != sha256_hex(canonical_json(payload)):
+        problems.append("Package hash does not match contents")
+    if package.genesis_hash != genesis.genesis_hash:
+        problems.append("Package was issued under a different genesis")
+    secret = secret_resolver(package.signer_id)
+    if secret is None or key_commitment(secret) != genesis.stewards.get(package.signer_id):
+        problems.append(f"Signer {package.signer_id} is not a genesis steward")
+    elif not verify_hmac(secret, payload, package.signature):
+        problems.append("Signature does not verify")
+    canon = package.canon
+    if canon is not None and canon["leaves"]:
+        if not verify_canon(CanonProof(canon["leaves"][0], canon["leaves"], canon["root"])):
+            problems.append("Canon leaves do not hash to the canon root")
+    return problems
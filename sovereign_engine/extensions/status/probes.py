@@ -0,0 +1,123 @@
+"""
+Health Probes - Liveness and readiness that mean what the constitution means.
+
+An orchestrator restarts a container that fails its liveness probe. For
+this engine that is exactly wrong after a halt: the halt is deliberate,
+its record lives in memory until recovery completes, and a restart would
+bring a fresh engine up as if nothing had happened. So the probes are
+mapped from lifecycle, not from process state:
+
+    lifecycle    live   ready   read_only
+    unbooted     yes*   no      -
+    running      yes    yes     no
+    paused       yes    yes     yes
+    recovering   yes    no      -
+    halted       yes    no      -
+
+* An engine that has not booted within `boot_grace_s` of the adapter's
+  creation is reported not live; that boot is stuck and a restart helps.
+
+Readiness takes the engine out of load balancing without killing it. A
+governance pause (S3-EXT-037) still answers reads, so it stays ready and
+says so in the body.
+
+Dependency: Phase 8 (engine), S3-EXT-025 (recovery), S3-EXT-037 (pause)
+"""
+
+from __future__ import annotations
+
+import json
+import time
+from dataclasses import asdict, dataclass
+from http.server import BaseHTTPRequestHandler
+from typing import Callable, Dict, Optional, Tuple
+
+from .collect import SovereignStatus
+
+
+LIVENESS_PATH = "/livez"
+READINESS_PATH = "/readyz"
+
+
+@dataclass(frozen=True)
+class ProbeResult:
+    live: bool
+    ready: bool
+    read_only: bool
+    lifecycle: str
+    reason: str
+
+    @property
+    def live_status(self) -> int:
+        return 200 if self.live else 503
+
+    @property
+    def ready_status(self) -> int:
+        return 200 if self.ready else 503
+
+
+class HealthEndpointAdapter:
+    """
+    INVARIANT: A halted or recovering engine is live; orchestrators never restart it.
+    INVARIANT: Probing never writes to the ledger.
+    """
+
+    def __init__(self, engine, recovery=None, pauses=None, boot_grace_s: float = 120.0,
+                 clock: Callable[[], float] = time.time):
+        self._engine = engine
+        self._recovery = recovery
+        self._pauses = pauses
+        self._boot_grace_s = boot_grace_s
+        self._clock = clock
+        self._created_at = clock()
+
+    def attach_recovery(self, session):
+        """Point the probes at the session opened for the current halt."""
+        self._recovery = session
+
+    def probe(self) -> ProbeResult:
+        lifecycle = SovereignStatus.collect(self._engine, recovery=self._recovery).lifecycle
+        if lifecycle == "unbooted":
+            waited = self._clock() - self._created_at
+            stuck = waited > self._boot_grace_s
+            return ProbeResult(not stuck, False, False, lifecycle,
+                               f"not booted after {waited:.0f}s" if stuck else "booting")
+        if lifecycle == "halted":
+            reason = self._engine.halt_ctrl.halt_history[-1]["reason"]
+            return ProbeResult(True, False, False, lifecycle, f"halted: {reason}")
+        if lifecycle == "recovering":
+            return ProbeResult(True, False, False, lifecycle,
+                               f"recovering: next {self._recovery.next_action.value}")
+        pause = self._pauses.current if self._pauses is not None else None
+        if pause is not None:
+            return ProbeResult(True, True, True, "paused", f"paused: {pause.reason}")
+        return ProbeResult(True, True, False, lifecycle, "ok")
+
+    def respond(self, path: str) -> Tuple[int, Dict]:
+        """Status code and JSON body for a probe path."""
+        result = self.probe()
+        body = asdict(result)
+        if path == LIVENESS_PATH:
+            return result.live_status, body
+        if path == READINESS_PATH:
+            return result.ready_status, body
+        return 404, {"error": f"unknown probe {path}"}
+
+    def handler(self) -> type:
+        """A request handler class for `http.server`, bound to this adapter."""
+        adapter = self
+
+        class ProbeHandler(BaseHTTPRequestHandler):
+            def do_GET(self):
+                status, body = adapter.respond(self.path.split("?", 1)[0])
+                data = json.dumps(body, sort_keys=True).encode("utf-8")
+                self.send_response(status)
+                self.send_header("Content-Type", "application/json")
+                self.send_header("Content-Length", str(len(data)))
+                self.end_headers()
+                self.wfile.write(data)
+
+            def log_message(self, format, *args):
+                pass
+
+        return ProbeHandler
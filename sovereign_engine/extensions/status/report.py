@@ -0,0 +1,231 @@
+"""
+State Report - The periodic, signed account of how the constitution held.
+
+A status snapshot answers "what is true now". A report answers "what
+happened this period": how long the system ran between halts, which
+halts occurred and why, what was adjudicated, how many warnings were
+raised and of what severity, and what was anchored externally. It is
+built from the ledger and halt history alone (plus the warning ledger
+and anchor queue when wired), signed by a genesis steward, ledgered, and
+stored in the evidence vault so it becomes part of the permanent record
+the museum serves.
+
+`render_text` prints the report in the same banner format as the test
+harness, for terminals and mailing lists; the JSON form is canonical.
+
+The signed payload includes a version stamp, and readers check it against
+the genesis constitution hash. Reports issued before stamping read as
+format 0 and verify as they always did.
+
+Dependency: S3-EXT-018 (genesis), S3-EXT-025 (recovery), S3-EXT-032 (advisories)
+"""
+
+from __future__ import annotations
+
+import time
+from dataclasses import asdict, dataclass
+from typing import Any, Callable, Dict, List, Optional
+
+from ..genesis import key_commitment
+from ..recovery import halt_id
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+from ..storage.versioning import VersionStamp, check_stamp, current_stamp, profile_hash
+from .onboarding import ADJUDICATION_ROUTES
+
+
+REPORT_EVIDENCE_TYPE = "state_report"
+ARTIFACT = "state_report"
+
+
+class ReportError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class StateReport:
+    period_start: float
+    period_end: float
+    issued_at: float
+    genesis_hash: str
+    ledger: Dict[str, Any]
+    outcomes: Dict[str, int]
+    streak: Dict[str, float]
+    halts: List[Dict[str, Any]]
+    adjudications: List[Dict[str, Any]]
+    warnings: Optional[Dict[str, Any]]
+    anchors: Optional[Dict[str, Any]]
+    signer_id: str
+    stamp: Optional[Dict[str, Any]] = None
+    signature: str = ""
+    report_hash: str = ""
+
+    @property
+    def version_stamp(self) -> Optional[VersionStamp]:
+        return VersionStamp.from_dict(self.stamp) if self.stamp else None
+
+    def unsigned_payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        data.pop("report_hash")
+        if data["stamp"] is None:
+            data.pop("stamp")
+        return data
+
+    def to_json(self) -> str:
+        return canonical_json(asdict(self))
+
+
+def _streak(halts: List[dict], start: float, end: float, halted_now: bool) -> Dict[str, float]:
+    """Halt-free intervals inside the period, in seconds."""
+    marks = [start] + [h["timestamp"] for h in halts] + [end]
+    gaps = [b - a for a, b in zip(marks, marks[1:])]
+    return {
+        "longest_halt_free_s": max(gaps),
+        "current_halt_free_s": 0.0 if halted_now else gaps[-1],
+        "halt_count": float(len(halts)),
+    }
+
+
+def build_state_report(engine, genesis, period_start: float, period_end: float,
+                       signer_id: str, signer_secret: bytes, warnings=None,
+                       anchors=None, vault=None, halts=None,
+                       clock: Callable[[], float] = time.time) -> StateReport:
+    """
+    Assemble, sign, ledger and (with a vault) archive the report for a period.
+    `halts` is the halt history source; pass a HaltLog for canonical IDs.
+    """
+    if period_end <= period_start:
+        raise ReportError("Report period must end after it starts")
+    if genesis.stewards.get(signer_id) != key_commitment(signer_secret):
+        raise ReportError(f"Signer {signer_id} is not a steward with a matching key")
+
+    def in_period(ts: float) -> bool:
+        return period_start <= ts < period_end
+
+    entries = [e for e in engine.ledger.entries if in_period(e.timestamp)]
+    outcomes: Dict[str, int] = {}
+    for e in entries:
+        outcomes[e.outcome] = outcomes.get(e.outcome, 0) + 1
+    source = engine.halt_ctrl if halts is None else halts
+    halts = [h for h in source.halt_history if in_period(h["timestamp"])]
+
+    warning_summary = None
+    if warnings is not None:
+        raised = warnings.query(since=period_start, until=period_end)
+        by_severity: Dict[str, int] = {}
+        for w in raised:
+            by_severity[w.severity] = by_severity.get(w.severity, 0) + 1
+        warning_summary = {
+            "raised": by_severity,
+            "unresolved": len([w for w in raised if w.status != "resolved"]),
+            "daily": [[start, counts] for start, counts in
+                      warnings.trend(86400.0, since=period_start, until=period_end)],
+        }
+
+    anchor_summary = None
+    if anchors is not None:
+        receipts = [r for r in anchors.receipts if in_period(r.anchored_at)]
+        anchor_summary = {
+            "anchored": len(receipts),
+            "pending": len(anchors.pending),
+            "abandoned": len(anchors.abandoned),
+            "digests": [r.digest for r in receipts],
+        }
+
+    unsigned = StateReport(
+        period_start=period_start,
+        period_end=period_end,
+        issued_at=clock(),
+        genesis_hash=genesis.genesis_hash,
+        ledger={
+            "length": engine.ledger.length,
+            "head": engine.ledger.last_hash,
+            "entries_in_period": len(entries),
+            "verified": engine.ledger.verify()["valid"],
+        },
+        outcomes=dict(sorted(outcomes.items())),
+        streak=_streak(halts, period_start, period_end, engine.is_halted),
+        halts=[{"halt_id": halt_id(h), **h} for h in halts],
+        adjudications=[
+            {"index": e.index, "route": e.route, "outcome": e.outcome, "signal_id": e.signal_id}
+            for e in entries if e.route in ADJUDICATION_ROUTES
+        ],
+        warnings=warning_summary,
+        anchors=anchor_summary,
+        signer_id=signer_id,
+        stamp=current_stamp(ARTIFACT, profile_hash(engine.constitution)).to_dict(),
+    )
+    payload = unsigned.unsigned_payload()
+    report = StateReport(**{
+        **asdict(unsigned),
+        "signature": sign_hmac(bytes(signer_secret), payload),
+        "report_hash": sha256_hex(canonical_json(payload)),
+    })
+    engine.ledger.write({
+        "signal_type": "audit",
+        "route": "state_report",
+        "handler": "status",
+        "outcome": "state_report_issued",
+        "signal_id": report.report_hash,
+        "signal_domain": "governance",
+        "extra": canonical_json({"period_start": period_start, "period_end": period_end}),
+    })
+    if vault is not None:
+        vault.store_evidence(REPORT_EVIDENCE_TYPE, asdict(report), {"source": "status_report"})
+    return report
+
+
+def verify_state_report(report: StateReport, genesis,
+                        secret_resolver: Callable[[str], bytes],
+                        accept_profile_change: bool = False) -> List[str]:
+    """Reader-side checks. Empty means the report is authentic."""
+    problems: List[str] = check_stamp(report.version_stamp, ARTIFACT,
+                                      genesis.constitution_hash, accept_profile_change)
+    payload = report.unsigned_payload()
+    if report.report_hash != sha256_hex(canonical_json(payload)):
+        problems.append("Report hash does not match contents")
+    if report.genesis_hash != genesis.genesis_hash:
+        problems.append("Report was issued under a different genesis")
+    secret = secret_resolver(report.signer_id)
+    if secret is None or key_commitment(secret) != genesis.stewards.get(report.signer_id):
+        problems.append(f"Signer {report.signer_id} is not a genesis steward")
+    elif not verify_hmac(secret, payload, report.signature):
+        problems.append("Signature does not verify")
+    return problems
+
+
+def _utc(ts: float) -> str:
+    return time.strftime("%Y-%m-%d %H:%M:%SZ", time.gmtime(ts))
+
+
+def render_text(report: StateReport) -> str:
+    """Banner rendering in the harness style. Informational; JSON is canonical."""
+    bar = "=" * 70
+    ok = report.ledger["verified"] and not report.halts
+    lines = [
+        bar,
+        "  SOVEREIGN RECURSION ENGINE — State Report",
+        f"  {_utc(report.period_start)} → {_utc(report.period_end)}",
+        bar,
+        f"  Ledger: {report.ledger['length']} entries, "
+        f"{report.ledger['entries_in_period']} this period, head {report.ledger['head'][:16]}",
+        f"  {'✓' if report.ledger['verified'] else '✗'} chain verified",
+        f"  Longest halt-free: {report.streak['longest_halt_free_s']:.0f}s, "
+        f"current: {report.streak['current_halt_free_s']:.0f}s",
+        f"  Halts: {len(report.halts)}",
+    ]
+    lines += [f"    ✗ {_utc(h['timestamp'])} {h['source']}: {h['reason']}" for h in report.halts]
+    lines.append(f"  Adjudications: {len(report.adjudications)}")
+    if report.warnings is not None:
+        raised = ", ".join(f"{k} {v}" for k, v in sorted(report.warnings["raised"].items()))
+        lines.append(f"  Warnings: {raised or 'none'} ({report.warnings['unresolved']} unresolved)")
+    if report.anchors is not None:
+        lines.append(f"  Anchored: {report.anchors['anchored']}, pending {report.anchors['pending']}, "
+                     f"abandoned {report.anchors['abandoned']}")
+    lines += [
+        bar,
+        f"  {'PERIOD CLEAN' if ok else 'PERIOD HAD HALTS OR INTEGRITY FAILURES'}",
+        f"  Signed by {report.signer_id} | {report.report_hash[:16]}",
+        bar,
+    ]
+    return "\n".join(lines)
@@ -0,0 +1,65 @@
+import json
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.beacon import EpochCeremony, LocalBeacon
+from sovereign_engine.extensions.quorum import QuorumRoster
+from sovereign_engine.extensions.recovery import RecoveryPlaybook, RecoverySession
+from sovereign_engine.extensions.redaction import RedactionPolicy
+from sovereign_engine.extensions.status import SovereignStatus
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class TestSovereignStatus(unittest.TestCase):
+    def setUp(self):
+        self.engine = SovereignEngine(CONFIG_PATH)
+
+    def test_unbooted(self):
+        self.assertEqual(SovereignStatus.collect(self.engine).lifecycle, "unbooted")
+
+    def test_running_snapshot_is_read_only_and_serialisable(self):
+        self.engine.boot()
+        roster = QuorumRoster(["a", "b", "c"], threshold=2, secret_resolver=lambda m: b"k")
+        beacon = LocalBeacon(b"seed")
+        epochs = EpochCeremony(beacon, self.engine.ledger)
+        epochs.commit(1, beacon_round=1)
+        beacon.publish_through(1)
+        epochs.run(1)
+        length = self.engine.ledger.length
+
+        status = SovereignStatus.collect(self.engine, roster=roster, epochs=epochs)
+        self.assertEqual(status.lifecycle, "running")
+        self.assertEqual(status.quorum["achievable"], 3)
+        self.assertEqual(status.epoch["epoch"], 1)
+        self.assertEqual(self.engine.ledger.length, length)
+        self.assertEqual(json.loads(status.to_json())["ledger"]["length"], length)
+
+    def test_exported_snapshot_is_redacted(self):
+        self.engine.boot()
+        self.engine.halt_ctrl.halt("member m3 missed attestation", "quorum")
+        policy = RedactionPolicy(key=b"export-key")
+        exported = json.loads(SovereignStatus.collect(self.engine).to_json(policy))
+        self.assertEqual(exported["last_halt"]["reason"],
+                         policy.pseudonym("member m3 missed attestation"))
+        self.assertEqual(exported["lifecycle"], "halted")
+
+    def test_halt_and_recovery_reported(self):
+        self.engine.boot()
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        status = SovereignStatus.collect(self.engine)
+        self.assertEqual(status.lifecycle, "halted")
+        self.assertIn("router_failure", status.last_halt["reason"])
+
+        session = RecoverySession(self.engine, RecoveryPlaybook.from_constitution(
+            self.engine.constitution), [], lambda s: b"")
+        status = SovereignStatus.collect(self.engine, recovery=session)
+        self.assertEqual(status.lifecycle, "recovering")
+        self.assertEqual(status.recovery["next_action"], "capture_forensics")
+
+
+if __name__ == "__main__":
+    unittest.main()
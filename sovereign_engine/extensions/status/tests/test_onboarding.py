@@ -0,0 +1,64 @@
+import tempfile
+import unittest
+from dataclasses import replace
+
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.genesis import REPO_ROOT, GenesisBuilder, key_commitment
+from sovereign_engine.extensions.security import compute_kernel_fingerprint
+from sovereign_engine.extensions.status import (
+    OnboardingError,
+    build_onboarding_package,
+    verify_onboarding_package,
+)
+
+SECRETS = {k: f"secret-{k}".encode() for k in ["m1", "m2", "m3", "b1", "s1", "s2"]}
+KERNEL = compute_kernel_fingerprint(REPO_ROOT)
+
+
+class TestOnboardingPackage(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.genesis = (
+            GenesisBuilder()
+            .with_archetype("federated")
+            .with_quorum_members({m: key_commitment(SECRETS[m]) for m in ["m1", "m2", "m3"]})
+            .with_stewards({s: key_commitment(SECRETS[s]) for s in ["s1", "s2"]})
+            .with_kernel_fingerprint(KERNEL)
+            .build("s1", SECRETS["s1"])
+        )
+        self.engine = self.genesis.boot_engine(SECRETS.get)
+        self.roster = self.genesis.build_roster(SECRETS.get, backups=["b1"])
+        self.vault = EvidenceVault(self._tmp.name)
+        self.vault.store_evidence("report", {"v": 1})
+        self.engine.health.report_failure("router", "router_failure", "crash")
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def _package(self):
+        return build_onboarding_package("b1", self.engine, self.genesis, "s1", SECRETS["s1"],
+                                        roster=self.roster, vault=self.vault)
+
+    def test_package_verifies_and_carries_open_halt(self):
+        package = self._package()
+        self.assertEqual(verify_onboarding_package(package, self.genesis, SECRETS.get), [])
+        self.assertEqual(package.open_halt["source"], "router")
+        self.assertEqual(package.status["lifecycle"], "halted")
+        self.assertEqual(package.canon["root"], self.vault.canon_root())
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "onboarding_package_issued")
+
+    def test_tampered_package_is_rejected(self):
+        package = replace(self._package(), open_halt=None)
+        problems = verify_onboarding_package(package, self.genesis, SECRETS.get)
+        self.assertIn("Package hash does not match contents", problems)
+
+    def test_only_genesis_stewards_may_issue(self):
+        with self.assertRaises(OnboardingError):
+            build_onboarding_package("b1", self.engine, self.genesis, "m1", SECRETS["m1"])
+        with self.assertRaises(OnboardingError):
+            build_onboarding_package("nobody", self.engine, self.genesis, "s1", SECRETS["s1"],
+                                     roster=self.roster)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,83 @@
+import json
+import os
+import threading
+import unittest
+import urllib.error
+import urllib.request
+from http.server import ThreadingHTTPServer
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.pause import PauseController, sign_pause
+from sovereign_engine.extensions.recovery import RecoveryPlaybook, RecoverySession
+from sovereign_engine.extensions.status import (
+    LIVENESS_PATH,
+    READINESS_PATH,
+    HealthEndpointAdapter,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class TestHealthEndpointAdapter(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.engine = SovereignEngine(CONFIG_PATH)
+
+    def _adapter(self, **kwargs):
+        return HealthEndpointAdapter(self.engine, boot_grace_s=60, clock=lambda: self.now,
+                                     **kwargs)
+
+    def test_halted_and_recovering_engines_are_live_but_not_ready(self):
+        adapter = self._adapter()
+        self.assertEqual(adapter.respond(LIVENESS_PATH)[0], 200)
+        self.now += 61
+        self.assertEqual(adapter.respond(LIVENESS_PATH)[0], 503)
+
+        self.engine.boot()
+        self.assertEqual(adapter.respond(READINESS_PATH)[0], 200)
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        length = self.engine.ledger.length
+        self.assertEqual(adapter.respond(LIVENESS_PATH)[0], 200)
+        status, body = adapter.respond(READINESS_PATH)
+        self.assertEqual((status, body["lifecycle"]), (503, "halted"))
+
+        adapter.attach_recovery(RecoverySession(self.engine, RecoveryPlaybook.from_constitution(
+            self.engine.constitution), [], lambda s: b""))
+        status, body = adapter.respond(READINESS_PATH)
+        self.assertEqual((status, body["lifecycle"]), (503, "recovering"))
+        self.assertEqual(adapter.respond(LIVENESS_PATH)[0], 200)
+        self.assertEqual(self.engine.ledger.length, length)
+
+    def test_paused_engine_is_ready_read_only(self):
+        self.engine.boot()
+        pauses = PauseController(self.engine, {"op1": b"k"}.get, clock=lambda: self.now)
+        adapter = self._adapter(pauses=pauses)
+        pauses.pause("op1", "migration", 600, sign_pause(b"k", "op1", 0, "migration", 600))
+        status, body = adapter.respond(READINESS_PATH)
+        self.assertEqual(status, 200)
+        self.assertTrue(body["read_only"])
+        self.assertEqual(body["lifecycle"], "paused")
+        self.assertEqual(adapter.respond("/metrics")[0], 404)
+
+    def test_handler_serves_probes_over_http(self):
+        self.engine.boot()
+        self.engine.health.report_failure("router", "router_failure", "crash")
+        server = ThreadingHTTPServer(("127.0.0.1", 0), self._adapter().handler())
+        threading.Thread(target=server.serve_forever, daemon=True).start()
+        base = f"http://127.0.0.1:{server.server_address[1]}"
+        try:
+            with urllib.request.urlopen(base + LIVENESS_PATH, timeout=5) as resp:
+                self.assertEqual(resp.status, 200)
+            with self.assertRaises(urllib.error.HTTPError) as ctx:
+                urllib.request.urlopen(base + READINESS_PATH, timeout=5)
+            self.assertEqual(ctx.exception.code, 503)
+            self.assertFalse(json.loads(ctx.exception.read())["ready"])
+        finally:
+            server.shutdown()
+            server.server_close()
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,92 @@
+import tempfile
+import time
+import unittest
+from dataclasses import replace
+
+from sovereign_engine.extensions.advisories import WarningLedger
+from sovereign_engine.extensions.evidence_vault import EvidenceVault
+from sovereign_engine.extensions.genesis import REPO_ROOT, GenesisBuilder, key_commitment
+from sovereign_engine.extensions.security import compute_kernel_fingerprint
+from sovereign_engine.extensions.status import (
+    REPORT_EVIDENCE_TYPE,
+    ReportError,
+    build_state_report,
+    render_text,
+    verify_state_report,
+)
+
+SECRETS = {k: f"secret-{k}".encode() for k in ["m1", "m2", "m3", "s1", "s2"]}
+KERNEL = compute_kernel_fingerprint(REPO_ROOT)
+
+
+class TestStateReport(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.genesis = (
+            GenesisBuilder()
+            .with_archetype("federated")
+            .with_quorum_members({m: key_commitment(SECRETS[m]) for m in ["m1", "m2", "m3"]})
+            .with_stewards({s: key_commitment(SECRETS[s]) for s in ["s1", "s2"]})
+            .with_kernel_fingerprint(KERNEL)
+            .build("s1", SECRETS["s1"])
+        )
+        self.engine = self.genesis.boot_engine(SECRETS.get)
+        self.start = time.time() - 1
+        self.warnings = WarningLedger()
+        self.warnings.record("health", "amber", "warning")
+        self.warnings.record("observatory", "anomalous", "critical")
+        self.engine.submit_and_process("query", "operational", "operator", {"n": 1})
+        self.engine.health.report_failure("router", "router_failure", "crash")
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def _report(self, **kwargs):
+        return build_state_report(self.engine, self.genesis, self.start, time.time() + 1,
+                                  "s1", SECRETS["s1"], warnings=self.warnings, **kwargs)
+
+    def test_report_summarises_period_and_verifies(self):
+        report = self._report()
+        self.assertEqual(verify_state_report(report, self.genesis, SECRETS.get), [])
+        self.assertEqual([h["source"] for h in report.halts], ["router"])
+        self.assertEqual(report.streak["current_halt_free_s"], 0.0)
+        self.assertEqual(report.warnings["raised"], {"warning": 1, "critical": 1})
+        self.assertTrue(report.ledger["verified"])
+        self.assertEqual(self.engine.ledger.entries[-1].signal_id, report.report_hash)
+
+        text = render_text(report)
+        self.assertIn("State Report", text)
+        self.assertIn("✗", text)
+        self.assertIn("PERIOD HAD HALTS", text)
+
+    def test_report_is_archived_and_tamper_evident(self):
+        vault = EvidenceVault(self._tmp.name)
+        report = self._report(vault=vault)
+        [stored] = vault.active_evidence()
+        record = vault.retrieve_evidence(stored)["record"]
+        self.assertEqual(record["type"], REPORT_EVIDENCE_TYPE)
+        self.assertEqual(record["data"]["report_hash"], report.report_hash)
+
+        tampered = replace(report, halts=[])
+        self.assertIn("Report hash does not match contents",
+                      verify_state_report(tampered, self.genesis, SECRETS.get))
+
+    def test_report_stamp_is_checked_against_genesis_profile(self):
+        report = self._report()
+        self.assertEqual(report.version_stamp.constitution_hash,
+                         self.genesis.constitution_hash)
+        amended = replace(self.genesis, constitution_hash="0" * 64)
+        self.assertTrue(any("constitutional profile" in p for p in
+                            verify_state_report(report, amended, SECRETS.get)))
+        self.assertFalse(any("constitutional profile" in p for p in verify_state_report(
+            report, amended, SECRETS.get, accept_profile_change=True)))
+
+    def test_only_genesis_stewards_issue_reports(self):
+        with self.assertRaises(ReportError):
+            build_state_report(self.engine, self.genesis, 0, 1, "m1", SECRETS["m1"])
+        with self.assertRaises(ReportError):
+            build_state_report(self.engine, self.genesis, 5, 5, "s1", SECRETS["s1"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,32 @@
+from .durable import DurableState
+from .store import FileStorage, MemoryStorage, Storage, StorageError
+from .versioning import (
+    ENGINE_VERSION,
+    FORMAT_VERSIONS,
+    MIGRATIONS,
+    UNBOUND_PROFILE,
+    Migrations,
+    VersionError,
+    VersionStamp,
+    check_stamp,
+    current_stamp,
+    profile_hash,
+)
+
+__all__ = [
+    "ENGINE_VERSION",
+    "FORMAT_VERSIONS",
+    "MIGRATIONS",
+    "UNBOUND_PROFILE",
+    "DurableState",
+    "FileStorage",
+    "MemoryStorage",
+    "Migrations",
+    "Storage",
+    "StorageError",
+    "VersionError",
+    "VersionStamp",
+    "check_stamp",
+    "current_stamp",
+    "profile_hash",
+]
@@ -0,0 +1,237 @@
+"""
+Durable Engine State - The audit ledger and halt state survive a restart.
+
+`AuditLedger` and `HaltController` live in memory, so a fresh boot starts
+with an empty chain and no halt — a halted system could be "recovered"
+by restarting it. DurableState mirrors both into a Storage as they
+change and restores them into a freshly booted engine:
+
+- Ledger entries are replayed through `AuditLedger.write` with their
+  original fields and timestamps, and every recomputed hash must equal
+  the stored one. The kernel hash is unkeyed and does not cover `extra`,
+  so anyone who can write the store could rebuild a consistent chain;
+  each stored entry therefore also carries an HMAC over all of its
+  fields under a storage key the store never holds. An entry that was
+  edited, or a chain that was rebuilt, fails the restore.
+- Every halt state change is checkpointed in the ledger with a digest of
+  the state. Restore refuses a stored halt state that does not match the
+  last checkpoint, then puts history and the halted flag back exactly,
+  so halt IDs and recovery sessions keyed on them stay valid. A system
+  that was halted comes back halted.
+
+The MACs authenticate entries, not how many there are. Cutting entries
+off the tail (and putting back an older halt state that matches the
+checkpoint left at the new tail) is undetectable from the store alone;
+compare the restored head against an anchored one to catch it.
+
+Restore before building anything else that holds a ledger reference: the
+engine gets a new ledger object. The entries written by the new boot are
+re-chained after the restored head, followed by a record of the restart.
+
+The store carries a version stamp. Restore checks it first: a newer
+format or a different constitutional profile is refused, and an older
+format is migrated and written back before mirroring resumes.
+"""
+
+from __future__ import annotations
+
+from dataclasses import asdict
+from typing import Optional
+
+from ...core.phase4_audit import AuditEntry, AuditError, AuditLedger
+from ..security.zero_trust_evidence import canonical_json, sha256_hex, sign_hmac, verify_hmac
+from .store import Storage, StorageError
+from .versioning import (
+    FORMAT_VERSIONS,
+    MIGRATIONS,
+    Migrations,
+    VersionStamp,
+    check_stamp,
+    current_stamp,
+    profile_hash,
+)
+
+
+LEDGER_NS = "ledger"
+HALT_NS = "halt"
+HALT_KEY = "state"
+META_NS = "meta"
+STAMP_KEY = "stamp"
+ARTIFACT = "durable_state"
+HALT_CHECKPOINT = "halt_state_saved"
+
+_ENTRY_FIELDS = ("signal_type", "route", "handler", "outcome", "signal_id",
+                 "signal_domain", "timestamp", "extra")
+
+
+def _entry_key(index: int) -> str:
+    return f"{index:012d}"
+
+
+def halt_state_digest(state: dict) -> str:
+    return sha256_hex(canonical_json(state))
+
+
+def _stored(key: bytes, entry: AuditEntry) -> dict:
+    data = asdict(entry)
+    return {**data, "mac": sign_hmac(key, data)}
+
+
+class DurableState:
+    """
+    INVARIANT: Every ledger entry is stored before write() returns.
+    INVARIANT: Restore either reproduces the stored chain exactly or fails.
+    INVARIANT: Restored halt state matches the last ledgered checkpoint.
+    """
+
+    def __init__(self, engine, storage: Storage, key: bytes):
+        self._engine = engine
+        self._storage = storage
+        self._key = bytes(key)
+        self._attached = False
+
+    # ──────────────────────────────────────────────
+    # MIRRORING
+    # ──────────────────────────────────────────────
+
+    def attach(self) -> "DurableState":
+        """Mirror every ledger write and halt change from now on."""
+        if self._attached:
+            return self
+        engine, storage, key = self._engine, self._storage, self._key
+        ledger, halt_ctrl = engine.ledger, engine.halt_ctrl
+
+        for entry in ledger.entries:
+            if storage.get(LEDGER_NS, _entry_key(entry.index)) is None:
+                storage.put(LEDGER_NS, _entry_key(entry.index), _stored(key, entry))
+        storage.put(META_NS, STAMP_KEY,
+                    current_stamp(ARTIFACT, profile_hash(engine.constitution)).to_dict())
+
+        write, halt, resume = ledger.write, halt_ctrl.halt, halt_ctrl.resume
+
+        def durable_write(record):
+            entry = write(record)
+            storage.put(LEDGER_NS, _entry_key(entry.index), _stored(key, entry))
+            return entry
+
+        def durable_halt(*args, **kwargs):
+            halt(*args, **kwargs)
+            self._save_halt()
+
+        def durable_resume(*args, **kwargs):
+            result = resume(*args, **kwargs)
+            self._save_halt()
+            return result
+
+        ledger.write = durable_write
+        halt_ctrl.halt = durable_halt
+        halt_ctrl.resume = durable_resume
+        self._attached = True
+        self._save_halt()
+        return self
+
+    def _save_halt(self):
+        engine = self._engine
+        state = {
+            "halted": engine.halt_ctrl.is_halted,
+            "reason": engine.halt_ctrl.halt_reason,
+            "history": engine.halt_ctrl.halt_history,
+            "router_halted": engine.router.is_halted,
+            "bus_halted": engine.bus.is_halted,
+        }
+        try:
+            engine.ledger.write({
+                "signal_type": "system",
+                "route": "storage",
+                "handler": ARTIFACT,
+                "outcome": HALT_CHECKPOINT,
+                "signal_id": halt_state_digest(state),
+                "signal_domain": "constitutional",
+            })
+        except AuditError:
+            # A halt must complete even on a sealed ledger. The stored state
+            # then has no matching checkpoint, so restore refuses it.
+            pass
+        self._storage.put(HALT_NS, HALT_KEY, state)
+
+    # ──────────────────────────────────────────────
+    # RESTORE
+    # ──────────────────────────────────────────────
+
+    @classmethod
+    def restore(cls, engine, storage: Storage, key: bytes,
+                migrations: Migrations = MIGRATIONS,
+                accept_profile_change: bool = False) -> "DurableState":
+        """
+        Load stored state into a booted engine and keep mirroring.
+        An empty store just starts mirroring the current boot.
+        """
+        stored = [value for _, value in storage.iterate(LEDGER_NS)]
+        if stored:
+            raw_stamp = storage.get(META_NS, STAMP_KEY)
+            stamp = VersionStamp.from_dict(raw_stamp) if raw_stamp else None
+            problems = check_stamp(stamp, ARTIFACT, profile_hash(engine.constitution),
+                                   accept_profile_change)
+            if problems:
+                raise StorageError("; ".join(problems))
+            state_data = migrations.upgrade(ARTIFACT, stamp, {
+                "ledger": stored, "halt": storage.get(HALT_NS, HALT_KEY)})
+            stored = state_data["ledger"]
+
+            boot_entries = engine.ledger.entries
+            ledger = AuditLedger(engine.constitution)
+            checkpoint = None
+            for i, data in enumerate(stored):
+                if data["index"] != i:
+                    raise StorageError(f"Stored ledger has a gap at index {i}")
+                entry = ledger.write({k: data[k] for k in _ENTRY_FIELDS})
+                if entry.hash != data["hash"] or entry.previous_hash != data["previous_hash"]:
+                    raise StorageError(f"Stored ledger entry {i} does not reproduce its hash")
+                if not verify_hmac(bytes(key), asdict(entry), data.get("mac", "")):
+                    raise StorageError(f"Stored ledger entry {i} fails authentication")
+                if entry.handler == ARTIFACT and entry.outcome == HALT_CHECKPOINT:
+                    checkpoint = entry
+            halt_state = state_data["halt"]
+            if (checkpoint is None) != (halt_state is None) or (
+                    checkpoint is not None
+                    and checkpoint.signal_id != halt_state_digest(halt_state)):
+                raise StorageError("Stored halt state does not match the ledgered checkpoint")
+            for entry in boot_entries:
+                ledger.write({k: getattr(entry, k) for k in _ENTRY_FIELDS})
+            engine.ledger = ledger
+            state = cls(engine, storage, key)
+            state._restore_halt(halt_state)
+            if stamp is None or stamp.format_version != FORMAT_VERSIONS[ARTIFACT]:
+                for entry in ledger.entries:
+                    storage.put(LEDGER_NS, _entry_key(entry.index), _stored(key, entry))
+            state.attach()
+            ledger.write({
+                "signal_type": "system",
+                "route": "storage",
+                "handler": "engine",
+                "outcome": "ledger_restored",
+                "signal_id": stored[-1]["hash"],
+                "signal_domain": "constitutional",
+                "extra": f"restored {len(stored)} entries; "
+                         f"re-chained {len(boot_entries)} boot entries; "
+                         f"store format {stamp.format_version if stamp else 0} "
+                         f"from engine {stamp.engine_version if stamp else 'unknown'}",
+            })
+            return state
+        return cls(engine, storage, key).attach()
+
+    def _restore_halt(self, state: Optional[dict]):
+        if not state:
+            return
+        # HaltController has no restore API and the kernel is locked; the
+        # history is put back verbatim so halt IDs do not change.
+        ctrl = self._engine.halt_ctrl
+        ctrl._halt_history = list(state["history"])
+        if state["halted"]:
+            ctrl._halted = True
+            ctrl._halt_reason = state["reason"]
+            ctrl._halt_time = state["history"][-1]["timestamp"] if state["history"] else 0.0
+        if state.get("router_halted"):
+            self._engine.router.halt(state["reason"] or "restored halt")
+        if state.get("bus_halted"):
+            self._engine.bus.halt(state["reason"] or "restored halt")
@@ -0,0 +1,123 @@
+"""
+Storage - Namespaced key/value persistence for stateful extensions.
+S3-EXT-033: Durability bolt-on.
+
+Every stateful part of this tree keeps its state in memory, and the few
+that persist (the evidence vault, the store-and-forward spool) each
+invented their own file layout. A Storage is the one interface they can
+share: get, put and ordered iteration within a namespace, with values
+restricted to JSON so a store can be inspected by hand. Deployments pick
+durability by picking the backend; the modules above do not change.
+
+There is deliberately no delete. The records worth persisting here are
+audit history, and audit history is append-only.
+
+Dependency: none
+"""
+
+from __future__ import annotations
+
+import json
+import os
+import re
+import tempfile
+from abc import ABC, abstractmethod
+from pathlib import Path
+from typing import Any, Dict, Iterator, List, Optional, Tuple
+
+
+_NAME = re.compile(r"^[A-Za-z0-9_.:-]+$")
+
+
+class StorageError(Exception):
+    pass
+
+
+def _check_name(kind: str, name: str):
+    if not _NAME.match(name) or name in (".", ".."):
+        raise StorageError(f"Invalid {kind}: {name!r}")
+
+
+class Storage(ABC):
+    """Namespaced JSON key/value store. Iteration is in key order."""
+
+    @abstractmethod
+    def get(self, namespace: str, key: str) -> Optional[Any]:
+        """Stored value, or None if the key is absent."""
+
+    @abstractmethod
+    def put(self, namespace: str, key: str, value: Any):
+        """Store a JSON-serialisable value, replacing any previous one."""
+
+    @abstractmethod
+    def keys(self, namespace: str) -> List[str]:
+        """Keys in the namespace, sorted."""
+
+    def iterate(self, namespace: str) -> Iterator[Tuple[str, Any]]:
+        for key in self.keys(namespace):
+            yield key, self.get(namespace, key)
+
+
+class MemoryStorage(Storage):
+    """Process-local store. Values round-trip through JSON like a file would."""
+
+    def __init__(self):
+        self._data: Dict[str, Dict[str, str]] = {}
+
+    def get(self, namespace: str, key: str) -> Optional[Any]:
+        raw = self._data.get(namespace, {}).get(key)
+        return None if raw is None else json.loads(raw)
+
+    def put(self, namespace: str, key: str, value: Any):
+        _check_name("namespace", namespace)
+        _check_name("key", key)
+        self._data.setdefault(namespace, {})[key] = json.dumps(value, sort_keys=True)
+
+    def keys(self, namespace: str) -> List[str]:
+        return sorted(self._data.get(namespace, {}))
+
+
+class FileStorage(Storage):
+    """
+    One directory per namespace, one JSON file per key. Writes go to a
+    temporary file first and are renamed into place, so a crash leaves
+    either the old value or the new one, never half of each.
+    """
+
+    def __init__(self, root: str | Path):
+        self.root = Path(root)
+        self.root.mkdir(parents=True, exist_ok=True)
+
+    def _path(self, namespace: str, key: str) -> Path:
+        _check_name("namespace", namespace)
+        _check_name("key", key)
+        return self.root / namespace / f"{key}.json"
+
+    def get(self, namespace: str, key: str) -> Optional[Any]:
+        path = self._path(namespace, key)
+        if not path.exists():
+            return None
+        with path.open("r", encoding="utf-8") as f:
+            return json.load(f)
+
+    def put(self, namespace: str, key: str, value: Any):
+        path = self._path(namespace, key)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        fd, tmp = tempfile.mkstemp(dir=path.parent, suffix=".tmp")
+        try:
+            with os.fdopen(fd, "w", encoding="utf-8") as f:
+                json.dump(value, f, sort_keys=True)
+                f.flush()
+                os.fsync(f.fileno())
+            os.replace(tmp, path)
+        except BaseException:
+            if os.path.exists(tmp):
+                os.unlink(tmp)
+            raise
+
+    def keys(self, namespace: str) -> List[str]:
+        _check_name("namespace", namespace)
+        directory = self.root / namespace
+        if not directory.is_dir():
+            return []
+        return sorted(p.name[:-len(".json")] for p in directory.glob("*.json"))
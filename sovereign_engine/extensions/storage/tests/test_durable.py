@@ -0,0 +1,135 @@
+import json
+import os
+import tempfile
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.recovery import halt_id
+from sovereign_engine.extensions.security.zero_trust_evidence import sign_hmac
+from sovereign_engine.extensions.storage import (
+    DurableState,
+    FileStorage,
+    MemoryStorage,
+    StorageError,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STORAGE_KEY = b"storage-key"
+
+
+def booted():
+    engine = SovereignEngine(CONFIG_PATH)
+    engine.boot()
+    return engine
+
+
+class TestStorage(unittest.TestCase):
+    def test_backends_share_semantics(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            for store in (MemoryStorage(), FileStorage(tmp)):
+                store.put("ns", "b", {"v": 2})
+                store.put("ns", "a", [1, "x"])
+                store.put("ns", "a", [1, "y"])
+                self.assertEqual(list(store.iterate("ns")), [("a", [1, "y"]), ("b", {"v": 2})])
+                self.assertIsNone(store.get("ns", "missing"))
+                self.assertEqual(store.keys("other"), [])
+                with self.assertRaises(StorageError):
+                    store.put("ns", "../escape", 1)
+
+
+class TestDurableState(unittest.TestCase):
+    def setUp(self):
+        self._tmp = tempfile.TemporaryDirectory()
+        self.storage = FileStorage(self._tmp.name)
+
+    def tearDown(self):
+        self._tmp.cleanup()
+
+    def test_ledger_and_halt_survive_restart(self):
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        for i in range(3):
+            engine.submit_and_process("command", "operational", "operator", {"i": i})
+        engine.health.report_failure("router", "router_failure", "crash")
+        head, length = engine.ledger.last_hash, engine.ledger.length
+        original_halt = halt_id(engine.halt_ctrl.halt_history[-1])
+
+        restarted = booted()
+        DurableState.restore(restarted, self.storage, STORAGE_KEY)
+        self.assertTrue(restarted.is_halted)
+        self.assertEqual(halt_id(restarted.halt_ctrl.halt_history[-1]), original_halt)
+        self.assertEqual(restarted.ledger.entries[length - 1].hash, head)
+        self.assertEqual(restarted.ledger.entries[-1].outcome, "ledger_restored")
+        self.assertIn("boot_complete",
+                      [e.outcome for e in restarted.ledger.entries[length:]])
+        self.assertTrue(restarted.ledger.verify()["valid"])
+        self.assertEqual(len(self.storage.keys("ledger")), restarted.ledger.length)
+
+    def _edit(self, relpath, **changes):
+        path = os.path.join(self._tmp.name, relpath)
+        with open(path) as f:
+            data = json.load(f)
+        data.update(changes)
+        with open(path, "w") as f:
+            json.dump(data, f)
+
+    def test_edited_halt_state_fails_restore(self):
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        engine.health.report_failure("router", "router_failure", "crash")
+        self._edit(os.path.join("halt", "state.json"), halted=False, router_halted=False)
+        with self.assertRaises(StorageError):
+            DurableState.restore(booted(), self.storage, STORAGE_KEY)
+
+    def test_edited_extra_fails_restore(self):
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        engine.submit_and_process("command", "operational", "operator", {"i": 1})
+        self._edit(os.path.join("ledger", "000000000001.json"), extra="forged")
+        with self.assertRaises(StorageError):
+            DurableState.restore(booted(), self.storage, STORAGE_KEY)
+
+    def test_store_rewritten_without_the_key_fails_restore(self):
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        engine.submit_and_process("command", "operational", "operator", {"i": 1})
+        path = os.path.join("ledger", "000000000001.json")
+        with open(os.path.join(self._tmp.name, path)) as f:
+            data = json.load(f)
+        data.pop("mac")
+        data["extra"] = "forged"
+        self._edit(path, extra="forged", mac=sign_hmac(b"guessed-key", data))
+        with self.assertRaises(StorageError):
+            DurableState.restore(booted(), self.storage, STORAGE_KEY)
+
+    def test_wrong_key_fails_restore(self):
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        with self.assertRaises(StorageError):
+            DurableState.restore(booted(), self.storage, b"another-key")
+
+    def test_edited_store_fails_restore(self):
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        engine.submit_and_process("command", "operational", "operator", {"i": 1})
+        path = os.path.join(self._tmp.name, "ledger", "000000000001.json")
+        with open(path) as f:
+            data = json.load(f)
+        data["outcome"] = "forged"
+        with open(path, "w") as f:
+            json.dump(data, f)
+        with self.assertRaises(StorageError):
+            DurableState.restore(booted(), self.storage, STORAGE_KEY)
+
+    def test_empty_store_mirrors_fresh_boot(self):
+        engine = booted()
+        DurableState.restore(engine, MemoryStorage(), STORAGE_KEY)
+        self.assertEqual([e.outcome for e in engine.ledger.entries[-2:]],
+                         ["boot_complete", "halt_state_saved"])
+        self.assertFalse(engine.is_halted)
+
+
+if __name__ == "__main__":
+    unittest.main()
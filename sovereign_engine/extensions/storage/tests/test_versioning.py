@@ -0,0 +1,83 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.storage import (
+    ENGINE_VERSION,
+    FORMAT_VERSIONS,
+    DurableState,
+    MemoryStorage,
+    Migrations,
+    StorageError,
+    VersionError,
+    VersionStamp,
+    profile_hash,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+STORAGE_KEY = b"storage-key"
+
+
+def booted():
+    engine = SovereignEngine(CONFIG_PATH)
+    engine.boot()
+    return engine
+
+
+class TestVersionStamps(unittest.TestCase):
+    def setUp(self):
+        self.storage = MemoryStorage()
+        engine = booted()
+        DurableState(engine, self.storage, STORAGE_KEY).attach()
+        engine.submit_and_process("command", "operational", "operator", {"i": 1})
+        self.profile = profile_hash(engine.constitution)
+
+    def _stamp(self, **changes):
+        data = {"artifact": "durable_state", "format_version": FORMAT_VERSIONS["durable_state"],
+                "engine_version": ENGINE_VERSION, "constitution_hash": self.profile, **changes}
+        self.storage.put("meta", "stamp", data)
+
+    def test_store_is_stamped_and_restore_records_the_stamp(self):
+        stamp = VersionStamp.from_dict(self.storage.get("meta", "stamp"))
+        self.assertEqual((stamp.engine_version, stamp.constitution_hash),
+                         (ENGINE_VERSION, self.profile))
+        restarted = booted()
+        DurableState.restore(restarted, self.storage, STORAGE_KEY)
+        self.assertIn(f"from engine {ENGINE_VERSION}", restarted.ledger.entries[-1].extra)
+
+    def test_newer_format_and_foreign_profile_are_refused(self):
+        self._stamp(format_version=99, engine_version="9.0.0")
+        with self.assertRaises(StorageError) as ctx:
+            DurableState.restore(booted(), self.storage, STORAGE_KEY)
+        self.assertIn("newer than this reader", str(ctx.exception))
+
+        self._stamp(constitution_hash="0" * 64)
+        with self.assertRaises(StorageError):
+            DurableState.restore(booted(), self.storage, STORAGE_KEY)
+        restarted = booted()
+        DurableState.restore(restarted, self.storage, STORAGE_KEY, accept_profile_change=True)
+        self.assertEqual(self.storage.get("meta", "stamp")["constitution_hash"], self.profile)
+        self.assertTrue(restarted.ledger.verify()["valid"])
+
+    def test_unstamped_store_is_migrated_through_registered_steps(self):
+        self.storage.put("meta", "stamp", None)
+        seen = []
+        migrations = Migrations()
+        with self.assertRaises(VersionError):
+            DurableState.restore(booted(), self.storage, STORAGE_KEY, migrations=migrations)
+        migrations.register("durable_state", 0, lambda data: seen.append(0) or data)
+        with self.assertRaises(VersionError):
+            migrations.register("durable_state", 0, lambda data: data)
+
+        restarted = booted()
+        DurableState.restore(restarted, self.storage, STORAGE_KEY, migrations=migrations)
+        self.assertEqual(seen, [0])
+        self.assertIn("store format 0", restarted.ledger.entries[-1].extra)
+        self.assertEqual(self.storage.get("meta", "stamp")["format_version"],
+                         FORMAT_VERSIONS["durable_state"])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,163 @@
+"""
+Version Stamps - Every persisted artifact says what wrote it.
+
+A store written today may be read by an engine a decade from now, after
+formats and the constitution have both moved. Without a record of what
+produced it, a reader can only guess how to interpret it. Each persisted
+artifact therefore carries a VersionStamp: the artifact kind, its format
+version, the engine version, and the hash of the constitutional profile
+it was written under.
+
+On load the stamp is checked before anything is interpreted:
+
+- a format newer than this reader understands is refused outright
+- an older format is brought forward one step at a time through
+  migrations registered per artifact; a missing step is refused
+- a different constitutional profile is refused unless the caller
+  accepts it explicitly (after an amendment, say)
+
+Artifacts written before stamping existed read as format 0.
+
+Stores that are not tied to a running engine (the evidence vault, the
+anchor file, sealed rationale) may be opened without a profile. Their
+stamps are then unbound: the profile hash is empty, and the profile check
+is skipped whenever either side has none. The format check always runs.
+
+Ledger entries are kernel-shaped and their hashes cover a fixed set of
+fields, so they are not stamped one by one; the stamp on the store that
+holds them covers them.
+
+Dependency: Phase 0 (constitution)
+"""
+
+from __future__ import annotations
+
+from dataclasses import asdict, dataclass
+from typing import Any, Callable, Dict, List, Optional, Tuple
+
+from ..security.zero_trust_evidence import sha256_hex
+from .store import StorageError
+
+
+# Keep in step with pyproject.toml.
+ENGINE_VERSION = "0.1.0"
+
+# Current format version of each stamped artifact.
+FORMAT_VERSIONS: Dict[str, int] = {
+    "anchor_file": 1,
+    "durable_state": 1,
+    "evidence_vault": 1,
+    "forensics_bundle": 1,
+    "sealed_rationale": 1,
+    "state_report": 1,
+    "succession_export": 1,
+}
+
+# Profile hash of a stamp written without a constitution to hand.
+UNBOUND_PROFILE = ""
+
+
+class VersionError(StorageError):
+    pass
+
+
+def profile_hash(constitution) -> str:
+    """
+    Hash of the constitution content the engine is running under. The one
+    constitution hash: genesis records, amendment receipts and stamps all
+    use it.
+    """
+    # The kernel is locked and offers no accessor for the whole document.
+    return sha256_hex(constitution._raw)
+
+
+@dataclass(frozen=True)
+class VersionStamp:
+    artifact: str
+    format_version: int
+    engine_version: str
+    constitution_hash: str
+
+    def to_dict(self) -> Dict[str, Any]:
+        return asdict(self)
+
+    @classmethod
+    def from_dict(cls, data: Dict[str, Any]) -> "VersionStamp":
+        return cls(data["artifact"], int(data["format_version"]),
+                   data["engine_version"], data["constitution_hash"])
+
+
+def current_stamp(artifact: str, constitution_hash: str) -> VersionStamp:
+    if artifact not in FORMAT_VERSIONS:
+        raise VersionError(f"Unknown artifact kind {artifact!r}")
+    return VersionStamp(artifact, FORMAT_VERSIONS[artifact], ENGINE_VERSION, constitution_hash)
+
+
+def check_stamp(stamp: Optional[VersionStamp], artifact: str, constitution_hash: str,
+                accept_profile_change: bool = False) -> List[str]:
+    """Reasons this reader must not load the artifact. Empty means compatible."""
+    if stamp is None:
+        return []
+    problems: List[str] = []
+    if stamp.artifact != artifact:
+        problems.append(f"Stamp is for {stamp.artifact!r}, not {artifact!r}")
+    current = FORMAT_VERSIONS.get(artifact, 0)
+    if stamp.format_version > current:
+        problems.append(
+            f"{artifact} format {stamp.format_version} (engine {stamp.engine_version}) is "
+            f"newer than this reader's format {current} (engine {ENGINE_VERSION})"
+        )
+    bound = stamp.constitution_hash != UNBOUND_PROFILE and constitution_hash != UNBOUND_PROFILE
+    if bound and stamp.constitution_hash != constitution_hash and not accept_profile_change:
+        problems.append(
+            f"{artifact} was written under constitutional profile "
+            f"{stamp.constitution_hash[:12]}…, not the current {constitution_hash[:12]}…"
+        )
+    return problems
+
+
+class Migrations:
+    """
+    Registry of one-step format upgrades per artifact.
+
+    INVARIANT: Upgrades run in order, one format version at a time.
+    INVARIANT: A missing step refuses the load; nothing is skipped.
+    """
+
+    def __init__(self):
+        self._steps: Dict[Tuple[str, int], Callable[[Any], Any]] = {}
+
+    def register(self, artifact: str, from_version: int,
+                 step: Callable[[Any], Any]) -> Callable[[Any], Any]:
+        """Register `step`, which turns format `from_version` into `from_version + 1`."""
+        key = (artifact, from_version)
+        if key in self._steps:
+            raise VersionError(f"Migration for {artifact} v{from_version} already registered")
+        self._steps[key] = step
+        return step
+
+    def upgrade(self, artifact: str, stamp: Optional[VersionStamp], data: Any) -> Any:
+        version = stamp.format_version if stamp is not None else 0
+        current = FORMAT_VERSIONS[artifact]
+        if version > current:
+            raise VersionError(f"{artifact} format {version} is newer than {current}")
+        while version < current:
+            step = self._steps.get((artifact, version))
+            if step is None:
+                raise VersionError(f"No migration for {artifact} from format {version}")
+            data = step(data)
+            version += 1
+        return data
+
+
+MIGRATIONS = Migrations()
+
+# Artifacts written before stamping share the format-1 layout, except
+# sealed rationale, which was stored as a bare hex envelope.
+MIGRATIONS.register("anchor_file", 0, lambda data: data)
+MIGRATIONS.register("durable_state", 0, lambda data: data)
+MIGRATIONS.register("evidence_vault", 0, lambda data: data)
+MIGRATIONS.register("forensics_bundle", 0, lambda data: data)
+MIGRATIONS.register("sealed_rationale", 0, lambda envelope: {"envelope": envelope})
+MIGRATIONS.register("state_report", 0, lambda data: data)
+MIGRATIONS.register("succession_export", 0, lambda data: data)
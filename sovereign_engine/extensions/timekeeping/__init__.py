@@ -0,0 +1,27 @@
+from .attestation import (
+    DEGRADATION_EVENT,
+    AttestedTimeSource,
+    HttpDateSource,
+    LocalClockSource,
+    MultiSourceClock,
+    TimeAttestation,
+    TimeAttestationError,
+    TimeReading,
+    TimeSource,
+    sign_time,
+    time_payload,
+)
+
+__all__ = [
+    "DEGRADATION_EVENT",
+    "AttestedTimeSource",
+    "HttpDateSource",
+    "LocalClockSource",
+    "MultiSourceClock",
+    "TimeAttestation",
+    "TimeAttestationError",
+    "TimeReading",
+    "TimeSource",
+    "sign_time",
+    "time_payload",
+]
@@ -0,0 +1,235 @@
+"""
+Time Attestation - No single clock decides how long anything took.
+S3-EXT-039: Multi-source time bolt-on.
+
+Every deadline in the extensions — pause windows, contact lapses, report
+periods, retry backoff — reads a `clock` callable, and by default that is
+the local `time.time`. Whoever controls the host controls the clock. A
+MultiSourceClock reads several independent sources, takes the median
+offset from the local clock, and rejects sources that disagree with it
+by more than `max_spread_s`. It is itself a clock callable, so it drops
+into any `clock=` parameter.
+
+Sources are pluggable:
+
+- LocalClockSource: the host clock, useful as one vote among several
+- HttpDateSource: the `Date` header of an HTTPS server (1 s resolution)
+- AttestedTimeSource: a time authority that signs (nonce, time) with a
+  key we hold, so a reading cannot be replayed or forged in transit
+
+Any rejected source, a failed read, or too few agreeing sources is
+infrastructure degradation: it is ledgered and raised as an operational
+alert. Too few agreeing sources also refuses the attestation outright,
+and the clock keeps its last agreed offset.
+
+Dependency: Phase 4 (audit)
+"""
+
+from __future__ import annotations
+
+import email.utils
+import os
+import statistics
+import time
+import urllib.request
+from abc import ABC, abstractmethod
+from dataclasses import dataclass, field
+from typing import Callable, List, Optional, Sequence
+
+from ..security.zero_trust_evidence import canonical_json, sign_hmac, verify_hmac
+
+
+DEGRADATION_EVENT = "infrastructure_degradation"
+
+
+class TimeAttestationError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class TimeReading:
+    source: str
+    at: float              # the source's time
+    local_at: float        # local time midway through the read
+
+    @property
+    def offset(self) -> float:
+        return self.at - self.local_at
+
+
+class TimeSource(ABC):
+    name: str = "abstract"
+
+    @abstractmethod
+    def read(self) -> float:
+        """Current time per this source. Raises TimeAttestationError."""
+
+
+class LocalClockSource(TimeSource):
+    name = "local"
+
+    def __init__(self, clock: Callable[[], float] = time.time):
+        self._clock = clock
+
+    def read(self) -> float:
+        return self._clock()
+
+
+class HttpDateSource(TimeSource):
+    """Reads the `Date` response header of a HEAD request."""
+
+    def __init__(self, url: str, timeout_s: float = 5.0):
+        self.url = url
+        self.name = f"http:{url}"
+        self.timeout_s = timeout_s
+
+    def read(self) -> float:
+        req = urllib.request.Request(self.url, method="HEAD")
+        try:
+            with urllib.request.urlopen(req, timeout=self.timeout_s) as resp:
+                header = resp.headers.get("Date")
+        except Exception as e:
+            raise TimeAttestationError(f"{self.url}: {e}") from e
+        if not header:
+            raise TimeAttestationError(f"{self.url}: no Date header")
+        return email.utils.parsedate_to_datetime(header).timestamp()
+
+
+def time_payload(authority: str, nonce: str, at: float) -> dict:
+    return {"authority": authority, "nonce": nonce, "at": at}
+
+
+def sign_time(secret: bytes, authority: str, nonce: str, at: float) -> str:
+    return sign_hmac(secret, time_payload(authority, nonce, at))
+
+
+class AttestedTimeSource(TimeSource):
+    """
+    A time authority reached through `request(nonce) -> (at, signature)`.
+
+    The fresh nonce binds each answer to this read, so an old signed time
+    cannot be replayed as a current one.
+    """
+
+    def __init__(self, authority: str, secret: bytes,
+                 request: Callable[[str], Sequence]):
+        self.name = f"attested:{authority}"
+        self._authority = authority
+        self._secret = secret
+        self._request = request
+
+    def read(self) -> float:
+        nonce = os.urandom(16).hex()
+        try:
+            at, signature = self._request(nonce)
+        except Exception as e:
+            raise TimeAttestationError(f"{self._authority}: {e}") from e
+        if not verify_hmac(self._secret, time_payload(self._authority, nonce, at), signature):
+            raise TimeAttestationError(f"{self._authority}: bad time signature")
+        return float(at)
+
+
+@dataclass(frozen=True)
+class TimeAttestation:
+    at: float
+    offset: float
+    accepted: List[TimeReading]
+    rejected: List[TimeReading] = field(default_factory=list)
+    failed: List[str] = field(default_factory=list)
+
+    @property
+    def degraded(self) -> bool:
+        return bool(self.rejected or self.failed)
+
+
+class MultiSourceClock:
+    """
+    INVARIANT: The agreed time is the median of agreeing sources, never one source's word.
+    INVARIANT: Every disagreement or failed source is ledgered and alerted.
+    INVARIANT: Without `min_sources` in agreement, the offset is not moved.
+    """
+
+    def __init__(self, sources: Sequence[TimeSource], max_spread_s: float = 2.0,
+                 min_sources: int = 2, ledger=None,
+                 emit: Optional[Callable[[dict], object]] = None,
+                 local: Callable[[], float] = time.time):
+        if min_sources < 1 or min_sources > len(sources):
+            raise TimeAttestationError(
+                f"min_sources {min_sources} must be between 1 and {len(sources)}")
+        self._sources = list(sources)
+        self._max_spread_s = max_spread_s
+        self._min_sources = min_sources
+        self._ledger = ledger
+        self._emit = emit
+        self._local = local
+        self._offset = 0.0
+        self._last: Optional[TimeAttestation] = None
+
+    def __call__(self) -> float:
+        return self._local() + self._offset
+
+    def attest(self) -> TimeAttestation:
+        readings: List[TimeReading] = []
+        failed: List[str] = []
+        for source in self._sources:
+            before = self._local()
+            try:
+                at = source.read()
+            except TimeAttestationError as e:
+                failed.append(f"{source.name}: {e}")
+                continue
+            readings.append(TimeReading(source.name, at, (before + self._local()) / 2))
+
+        accepted: List[TimeReading] = []
+        rejected: List[TimeReading] = []
+        if readings:
+            median = statistics.median(r.offset for r in readings)
+            for r in readings:
+                (accepted if abs(r.offset - median) <= self._max_spread_s else rejected).append(r)
+
+        if len(accepted) < self._min_sources:
+            self._degraded(f"{len(accepted)}/{self._min_sources} sources agree",
+                           rejected, failed)
+            raise TimeAttestationError(
+                f"Only {len(accepted)} time sources agree; {self._min_sources} required")
+
+        offset = statistics.median(r.offset for r in accepted)
+        attestation = TimeAttestation(self._local() + offset, offset, accepted, rejected, failed)
+        if attestation.degraded:
+            self._degraded("sources disagree or failed", rejected, failed)
+        self._offset = offset
+        self._last = attestation
+        return attestation
+
+    def _degraded(self, summary: str, rejected: List[TimeReading], failed: List[str]):
+        detail = {
+            "summary": summary,
+            "rejected": {r.source: round(r.offset, 3) for r in rejected},
+            "failed": failed,
+        }
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "timekeeping",
+                "handler": "multi_source_clock",
+                "outcome": "time_sources_degraded",
+                "signal_id": "time",
+                "signal_domain": "operational",
+                "extra": canonical_json(detail),
+            })
+        if self._emit:
+            self._emit({
+                "type": "alert",
+                "domain": "operational",
+                "authority": "system",
+                "source": "multi_source_clock",
+                "payload": {"event": DEGRADATION_EVENT, **detail},
+            })
+
+    @property
+    def offset(self) -> float:
+        return self._offset
+
+    @property
+    def last(self) -> Optional[TimeAttestation]:
+        return self._last
@@ -0,0 +1,92 @@
+import os
+import unittest
+
+from sovereign_engine.core.phase8_engine import SovereignEngine
+from sovereign_engine.extensions.timekeeping import (
+    AttestedTimeSource,
+    LocalClockSource,
+    MultiSourceClock,
+    TimeAttestationError,
+    TimeSource,
+    sign_time,
+)
+
+CONFIG_PATH = os.path.join(
+    os.path.dirname(__file__), "..", "..", "..", "configs", "constitution.json"
+)
+
+
+class FixedSource(TimeSource):
+    def __init__(self, name, skew, local, failing=False):
+        self.name = name
+        self.skew = skew
+        self.local = local
+        self.failing = failing
+
+    def read(self):
+        if self.failing:
+            raise TimeAttestationError("unreachable")
+        return self.local() + self.skew
+
+
+class TestMultiSourceClock(unittest.TestCase):
+    def setUp(self):
+        self.now = 1000.0
+        self.engine = SovereignEngine(CONFIG_PATH)
+        self.engine.boot()
+        self.alerts = []
+        self.local = lambda: self.now
+
+    def _clock(self, sources, **kwargs):
+        return MultiSourceClock(sources, max_spread_s=2.0, ledger=self.engine.ledger,
+                                emit=self.alerts.append, local=self.local, **kwargs)
+
+    def test_median_with_outlier_rejected_and_alerted(self):
+        clock = self._clock([FixedSource("a", 30.0, self.local), FixedSource("b", 31.0, self.local),
+                             FixedSource("c", 30.5, self.local),
+                             FixedSource("liar", 3600.0, self.local)])
+        attestation = clock.attest()
+        self.assertEqual([r.source for r in attestation.rejected], ["liar"])
+        self.assertAlmostEqual(attestation.offset, 30.5)
+        self.assertAlmostEqual(clock(), 1030.5)
+        self.now += 10
+        self.assertAlmostEqual(clock(), 1040.5)
+        self.assertEqual(self.alerts[-1]["payload"]["event"], "infrastructure_degradation")
+        self.assertIn("liar", self.alerts[-1]["payload"]["rejected"])
+        self.assertEqual(self.engine.ledger.entries[-1].outcome, "time_sources_degraded")
+
+    def test_too_few_agreeing_sources_keeps_the_last_offset(self):
+        sources = [FixedSource("a", 5.0, self.local), FixedSource("b", 5.0, self.local),
+                   FixedSource("c", 5.0, self.local)]
+        clock = self._clock(sources, min_sources=2)
+        self.assertEqual(clock.attest().degraded, False)
+        self.assertEqual(self.alerts, [])
+        sources[0].failing = sources[1].failing = True
+        with self.assertRaises(TimeAttestationError):
+            clock.attest()
+        self.assertAlmostEqual(clock.offset, 5.0)
+        self.assertEqual(len(self.alerts), 1)
+        with self.assertRaises(TimeAttestationError):
+            MultiSourceClock(sources, min_sources=4)
+
+    def test_attested_source_verifies_signature_over_fresh_nonce(self):
+        secret = b"authority-key"
+        answers = []
+
+        def honest(nonce):
+            answers.append((self.now + 1, sign_time(secret, "tsa", nonce, self.now + 1)))
+            return answers[-1]
+
+        source = AttestedTimeSource("tsa", secret, honest)
+        self.assertEqual(source.read(), 1001.0)
+        with self.assertRaises(TimeAttestationError):
+            AttestedTimeSource("tsa", secret, lambda nonce: answers[0]).read()
+        with self.assertRaises(TimeAttestationError):
+            AttestedTimeSource("tsa", b"wrong", honest).read()
+
+        clock = self._clock([source, LocalClockSource(self.local)])
+        self.assertAlmostEqual(clock.attest().offset, 0.5)
+
+
+if __name__ == "__main__":
+    unittest.main()
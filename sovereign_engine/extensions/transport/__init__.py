@@ -0,0 +1,23 @@
+from .messaging import (
+    MESSAGE_KINDS,
+    Dispatch,
+    GovernanceChannel,
+    GovernanceMessage,
+    HttpTransport,
+    LocalQueueTransport,
+    StoreAndForwardTransport,
+    Transport,
+    TransportError,
+)
+
+__all__ = [
+    "MESSAGE_KINDS",
+    "Dispatch",
+    "GovernanceChannel",
+    "GovernanceMessage",
+    "HttpTransport",
+    "LocalQueueTransport",
+    "StoreAndForwardTransport",
+    "Transport",
+    "TransportError",
+]
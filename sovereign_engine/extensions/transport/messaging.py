@@ -0,0 +1,302 @@
+"""
+Governance Transport - Moving signed messages to and from quorum members.
+S3-EXT-028: Messaging bolt-on.
+
+Attestations, liveness challenges and directives have so far assumed some
+out-of-band channel. This gives them one: a `Transport` moves opaque
+signed envelopes, and a `GovernanceChannel` on each side signs outgoing
+messages and verifies, de-duplicates and audits incoming ones. Backends
+are interchangeable — an in-process queue for tests and single-host
+deployments, HTTP for a relay, and store-and-forward around either for
+links that drop.
+
+A transport acknowledgement only means the envelope left this node; it is
+the `delivered` input to the blackout detector, not proof of receipt.
+
+Replay protection is per channel instance: the set of seen message ids is
+held in memory. A deployment that restarts a channel must hand it the ids
+it had already accepted (`seen`, read back from `seen_ids`) or a message
+delivered before the restart can be delivered again.
+
+Dependency: Phase 4 (audit), S3-EXT-003 (quorum)
+"""
+
+from __future__ import annotations
+
+import json
+import time
+import urllib.parse
+import urllib.request
+import uuid
+from abc import ABC, abstractmethod
+from collections import defaultdict, deque
+from dataclasses import asdict, dataclass
+from pathlib import Path
+from typing import Callable, Deque, Dict, Iterable, List, Optional, Set
+
+from ..security.zero_trust_evidence import canonical_json, sign_hmac, verify_hmac
+
+
+MESSAGE_KINDS = ("attestation", "challenge", "challenge_response", "directive", "notice")
+
+
+class TransportError(Exception):
+    pass
+
+
+@dataclass(frozen=True)
+class GovernanceMessage:
+    msg_id: str
+    kind: str
+    sender: str
+    recipient: str
+    body: dict
+    sent_at: float
+    signature: str = ""
+
+    def __post_init__(self):
+        # Decoded envelopes are untrusted: a non-string id would otherwise
+        # surface as a TypeError from the replay set, mid-drain.
+        for name in ("msg_id", "kind", "sender", "recipient", "signature"):
+            if not isinstance(getattr(self, name), str):
+                raise TypeError(f"GovernanceMessage.{name} must be a string")
+        if not self.msg_id:
+            raise ValueError("GovernanceMessage.msg_id must not be empty")
+        if not isinstance(self.body, dict):
+            raise TypeError("GovernanceMessage.body must be an object")
+        if isinstance(self.sent_at, bool) or not isinstance(self.sent_at, (int, float)):
+            raise TypeError("GovernanceMessage.sent_at must be a number")
+
+    def signing_payload(self) -> dict:
+        data = asdict(self)
+        data.pop("signature")
+        return data
+
+    def to_json(self) -> str:
+        return canonical_json(asdict(self))
+
+    @classmethod
+    def from_json(cls, data: str) -> "GovernanceMessage":
+        return cls(**json.loads(data))
+
+
+@dataclass(frozen=True)
+class Dispatch:
+    message: GovernanceMessage
+    delivered: bool          # transport acknowledgement, not proof of receipt
+
+
+# ──────────────────────────────────────────────
+# BACKENDS
+# ──────────────────────────────────────────────
+
+class Transport(ABC):
+    """Moves serialised envelopes. Knows nothing about signatures."""
+
+    name: str = "abstract"
+
+    @abstractmethod
+    def send(self, recipient: str, envelope: str) -> bool:
+        """Hand an envelope to the transport. True means it left this node."""
+
+    @abstractmethod
+    def receive(self, recipient: str) -> List[str]:
+        """Drain envelopes waiting for a recipient."""
+
+
+class LocalQueueTransport(Transport):
+    """In-process mailboxes. Shared by every channel in one process."""
+
+    name = "local"
+
+    def __init__(self):
+        self._boxes: Dict[str, Deque[str]] = defaultdict(deque)
+        self._reachable: Dict[str, bool] = {}
+
+    def set_reachable(self, recipient: str, reachable: bool):
+        """Simulate a link going down for one recipient."""
+        self._reachable[recipient] = reachable
+
+    def send(self, recipient: str, envelope: str) -> bool:
+        if not self._reachable.get(recipient, True):
+            return False
+        self._boxes[recipient].append(envelope)
+        return True
+
+    def receive(self, recipient: str) -> List[str]:
+        box = self._boxes[recipient]
+        out = list(box)
+        box.clear()
+        return out
+
+
+class HttpTransport(Transport):
+    """
+    Relay over HTTP: POST {recipient, envelope} to `<base>/messages`, and
+    GET `<base>/messages?recipient=...` returning a JSON list of envelopes.
+    """
+
+    name = "http"
+
+    def __init__(self, base_url: str, timeout_s: float = 10.0):
+        self.base_url = base_url.rstrip("/")
+        self.timeout_s = timeout_s
+
+    def send(self, recipient: str, envelope: str) -> bool:
+        body = json.dumps({"recipient": recipient, "envelope": envelope}).encode("utf-8")
+        req = urllib.request.Request(
+            f"{self.base_url}/messages", data=body,
+            headers={"Content-Type": "application/json"}, method="POST",
+        )
+        try:
+            with urllib.request.urlopen(req, timeout=self.timeout_s) as resp:
+                return 200 <= resp.status < 300
+        except Exception:
+            return False
+
+    def receive(self, recipient: str) -> List[str]:
+        query = urllib.parse.urlencode({"recipient": recipient})
+        try:
+            with urllib.request.urlopen(f"{self.base_url}/messages?{query}",
+                                        timeout=self.timeout_s) as resp:
+                return list(json.loads(resp.read().decode("utf-8") or "[]"))
+        except Exception as e:
+            raise TransportError(f"{self.base_url}: {e}") from e
+
+
+class StoreAndForwardTransport(Transport):
+    """
+    Wraps another transport. Envelopes the inner transport refuses are
+    spooled to an append-only file and retried by `forward()`, so nothing
+    is lost across a dropped link or a restart.
+    """
+
+    name = "store_and_forward"
+
+    def __init__(self, inner: Transport, spool_path: str | Path):
+        self.inner = inner
+        self.spool_path = Path(spool_path)
+        self.spool_path.parent.mkdir(parents=True, exist_ok=True)
+        self.spool_path.touch(exist_ok=True)
+
+    def send(self, recipient: str, envelope: str) -> bool:
+        if self.inner.send(recipient, envelope):
+            return True
+        with self.spool_path.open("a", encoding="utf-8") as f:
+            f.write(json.dumps({"recipient": recipient, "envelope": envelope}) + "\n")
+        return False
+
+    def forward(self) -> int:
+        """Retry spooled envelopes. Returns how many were handed over."""
+        lines = self.spool_path.read_text(encoding="utf-8").splitlines()
+        remaining, sent = [], 0
+        for line in lines:
+            item = json.loads(line)
+            if self.inner.send(item["recipient"], item["envelope"]):
+                sent += 1
+            else:
+                remaining.append(line)
+        self.spool_path.write_text("".join(l + "\n" for l in remaining), encoding="utf-8")
+        return sent
+
+    @property
+    def spooled(self) -> int:
+        return len(self.spool_path.read_text(encoding="utf-8").splitlines())
+
+    def receive(self, recipient: str) -> List[str]:
+        return self.inner.receive(recipient)
+
+
+# ──────────────────────────────────────────────
+# CHANNEL
+# ──────────────────────────────────────────────
+
+class GovernanceChannel:
+    """
+    One member's endpoint on a transport.
+
+    INVARIANT: Every outgoing message is signed by this member.
+    INVARIANT: Incoming messages with a bad signature or a replayed id
+               are dropped and audited, never delivered.
+    """
+
+    def __init__(self, member_id: str, secret: bytes, transport: Transport,
+                 secret_resolver: Callable[[str], bytes], ledger=None,
+                 clock: Callable[[], float] = time.time, seen: Iterable[str] = ()):
+        self.member_id = member_id
+        self._secret = bytes(secret)
+        self._transport = transport
+        self._secret_resolver = secret_resolver
+        self._ledger = ledger
+        self._clock = clock
+        self._seen: Set[str] = set(seen)
+        self._rejected: List[dict] = []
+
+    def send(self, recipient: str, kind: str, body: dict) -> Dispatch:
+        if kind not in MESSAGE_KINDS:
+            raise TransportError(f"Unknown message kind: {kind}")
+        unsigned = GovernanceMessage(
+            msg_id=str(uuid.uuid4()), kind=kind, sender=self.member_id,
+            recipient=recipient, body=dict(body), sent_at=self._clock(),
+        )
+        message = GovernanceMessage(
+            **{**asdict(unsigned), "signature": sign_hmac(self._secret, unsigned.signing_payload())}
+        )
+        return Dispatch(message, self._transport.send(recipient, message.to_json()))
+
+    def receive(self) -> List[GovernanceMessage]:
+        accepted: List[GovernanceMessage] = []
+        for envelope in self._transport.receive(self.member_id):
+            reason = None
+            try:
+                message = GovernanceMessage.from_json(envelope)
+            except (TypeError, ValueError):
+                message, reason = None, "malformed envelope"
+            if message is not None:
+                reason = self._check(message)
+            if reason:
+                self._reject(message, reason)
+                continue
+            self._seen.add(message.msg_id)
+            accepted.append(message)
+        return accepted
+
+    def _check(self, message: GovernanceMessage) -> Optional[str]:
+        if message.recipient != self.member_id:
+            return "misaddressed"
+        if message.msg_id in self._seen:
+            return "replayed"
+        try:
+            secret = self._secret_resolver(message.sender)
+        except Exception:
+            secret = None
+        if not secret or not verify_hmac(secret, message.signing_payload(), message.signature):
+            return "bad signature"
+        return None
+
+    def _reject(self, message: Optional[GovernanceMessage], reason: str):
+        record = {
+            "msg_id": message.msg_id if message else "",
+            "sender": message.sender if message else "",
+            "reason": reason,
+        }
+        self._rejected.append(record)
+        if self._ledger is not None:
+            self._ledger.write({
+                "signal_type": "audit",
+                "route": "transport",
+                "handler": self.member_id,
+                "outcome": "message_rejected",
+                "signal_id": record["msg_id"] or "malformed",
+                "signal_domain": "governance",
+                "extra": f"{record['sender']}: {reason}",
+            })
+
+    @property
+    def rejected(self) -> List[dict]:
+        return list(self._rejected)
+
+    @property
+    def seen_ids(self) -> List[str]:
+        """Accepted message ids, to carry replay protection across a restart."""
+        return sorted(self._seen)
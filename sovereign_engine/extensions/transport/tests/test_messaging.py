@@ -0,0 +1,83 @@
+import json
+import os
+import tempfile
+import unittest
+
+from sovereign_engine.extensions.transport import (
+    GovernanceChannel,
+    GovernanceMessage,
+    LocalQueueTransport,
+    StoreAndForwardTransport,
+)
+
+SECRETS = {"m1": b"secret-1", "m2": b"secret-2", "m3": b"secret-3"}
+
+
+class TestGovernanceTransport(unittest.TestCase):
+    def setUp(self):
+        self.transport = LocalQueueTransport()
+        self.channels = {
+            m: GovernanceChannel(m, SECRETS[m], self.transport, SECRETS.get)
+            for m in SECRETS
+        }
+
+    def test_signed_message_round_trip(self):
+        dispatch = self.channels["m1"].send("m2", "challenge", {"nonce": "abc"})
+        self.assertTrue(dispatch.delivered)
+        received = self.channels["m2"].receive()
+        self.assertEqual([m.body for m in received], [{"nonce": "abc"}])
+        self.assertEqual(received[0].sender, "m1")
+
+    def test_forged_and_replayed_messages_dropped(self):
+        dispatch = self.channels["m1"].send("m2", "directive", {"do": "pause"})
+        self.channels["m2"].receive()
+        envelope = dispatch.message.to_json()
+        self.transport.send("m2", envelope)
+        forged = GovernanceMessage(**{**dispatch.message.__dict__,
+                                      "msg_id": "x", "body": {"do": "resume"}})
+        self.transport.send("m2", forged.to_json())
+        self.transport.send("m2", "not json")
+        self.assertEqual(self.channels["m2"].receive(), [])
+        reasons = [r["reason"] for r in self.channels["m2"].rejected]
+        self.assertEqual(reasons, ["replayed", "bad signature", "malformed envelope"])
+
+    def test_malformed_fields_rejected_without_stopping_the_drain(self):
+        dispatch = self.channels["m1"].send("m2", "notice", {"n": 1})
+        self.channels["m2"].receive()
+        bad = json.loads(dispatch.message.to_json())
+        bad["msg_id"] = ["unhashable"]
+        self.transport.send("m2", json.dumps(bad))
+        self.channels["m1"].send("m2", "notice", {"n": 2})
+
+        self.assertEqual([m.body for m in self.channels["m2"].receive()], [{"n": 2}])
+        self.assertEqual(self.channels["m2"].rejected[0]["reason"], "malformed envelope")
+
+    def test_replay_protection_carried_across_restart(self):
+        dispatch = self.channels["m1"].send("m2", "directive", {"do": "pause"})
+        self.channels["m2"].receive()
+        restarted = GovernanceChannel("m2", SECRETS["m2"], self.transport, SECRETS.get,
+                                      seen=self.channels["m2"].seen_ids)
+        self.transport.send("m2", dispatch.message.to_json())
+        self.assertEqual(restarted.receive(), [])
+        self.assertEqual(restarted.rejected[0]["reason"], "replayed")
+
+    def test_unreachable_recipient_reports_undelivered(self):
+        self.transport.set_reachable("m3", False)
+        self.assertFalse(self.channels["m1"].send("m3", "challenge", {}).delivered)
+
+    def test_store_and_forward_spools_until_link_returns(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            saf = StoreAndForwardTransport(self.transport, os.path.join(tmp, "spool.jsonl"))
+            sender = GovernanceChannel("m1", SECRETS["m1"], saf, SECRETS.get)
+            self.transport.set_reachable("m3", False)
+            self.assertFalse(sender.send("m3", "notice", {"n": 1}).delivered)
+            self.assertEqual(saf.spooled, 1)
+            self.assertEqual(saf.forward(), 0)
+            self.transport.set_reachable("m3", True)
+            self.assertEqual(saf.forward(), 1)
+            self.assertEqual(saf.spooled, 0)
+            self.assertEqual([m.body for m in self.channels["m3"].receive()], [{"n": 1}])
+
+
+if __name__ == "__main__":
+    unittest.main()